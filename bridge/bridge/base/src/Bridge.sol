@@ -110,6 +110,9 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
     /// @notice Thrown when the borsch-encoded message to bridge is too large to fit in a Solana account
     error SerializedMessageTooBig();
 
+    /// @notice Thrown when the relayer fee of a transfer exceeds the amount received on Solana.
+    error RelayerFeeExceedsAmount();
+
     //////////////////////////////////////////////////////////////
     ///                       Modifiers                        ///
     //////////////////////////////////////////////////////////////
@@ -173,6 +176,16 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
         MessageStorageLib.sendMessage({sender: msg.sender, data: data});
     }
 
+    /// @notice Bridges a call to the Solana bridge whose instructions may be executed over several relay
+    ///         transactions on Solana instead of atomically.
+    ///
+    /// @param ixs The instructions to execute on Solana.
+    function bridgeResumableCall(Ix[] calldata ixs) external nonReentrant whenNotPaused isValidIxs(ixs) {
+        bytes memory data = SVMBridgeLib.serializeResumableCall(ixs);
+        require(data.length <= SVMLib.MAX_SOLANA_DATA_LENGTH, SerializedMessageTooBig());
+        MessageStorageLib.sendMessage({sender: msg.sender, data: data});
+    }
+
     /// @notice Bridges a transfer with an optional list of instructions to the Solana bridge.
     ///
     /// @dev If `localToken` is a wrapped version of a Solana asset, `remoteToken` is an optional arg.
@@ -198,6 +211,59 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
         MessageStorageLib.sendMessage({sender: msg.sender, data: data});
     }
 
+    /// @notice Bridges a transfer with an optional list of instructions to the Solana bridge, paying part of the
+    ///         transferred amount to the relayer that executes the message on Solana.
+    ///
+    /// @dev `relayerFee` is denominated in the Solana base units of the transfer and is checked against
+    ///      `transfer.remoteAmount` once any transfer fee has been accounted for. The recipient receives the rest.
+    ///
+    /// @param transfer   The token transfer to execute.
+    /// @param ixs        The optional Solana instructions.
+    /// @param relayerFee The part of the transferred amount paid to the relayer on Solana.
+    function bridgeTokenWithRelayerFee(Transfer memory transfer, Ix[] calldata ixs, uint64 relayerFee)
+        external
+        payable
+        nonReentrant
+        whenNotPaused
+        isValidIxs(ixs)
+    {
+        // IMPORTANT: The `TokenLib.initializeTransfer` function might modify the `transfer.remoteAmount` field to
+        //            account for potential transfer fees.
+        SolanaTokenType transferType =
+            TokenLib.initializeTransfer({transfer: transfer, crossChainErc20Factory: CROSS_CHAIN_ERC20_FACTORY});
+        require(relayerFee <= transfer.remoteAmount, RelayerFeeExceedsAmount());
+
+        bytes memory data = SVMBridgeLib.serializeTransferWithRelayerFee({
+            transfer: transfer, tokenType: transferType, ixs: ixs, relayerFee: relayerFee
+        });
+        require(data.length <= SVMLib.MAX_SOLANA_DATA_LENGTH, SerializedMessageTooBig());
+        MessageStorageLib.sendMessage({sender: msg.sender, data: data});
+    }
+
+    /// @notice Bridges a transfer followed by a list of instructions to the Solana bridge. The transfer is finalized
+    ///         by the first relay transaction on Solana and the instructions may be executed over several relay
+    ///         transactions instead of atomically.
+    ///
+    /// @param transfer The token transfer to execute.
+    /// @param ixs      The Solana instructions.
+    function bridgeResumableToken(Transfer memory transfer, Ix[] calldata ixs)
+        external
+        payable
+        nonReentrant
+        whenNotPaused
+        isValidIxs(ixs)
+    {
+        // IMPORTANT: The `TokenLib.initializeTransfer` function might modify the `transfer.remoteAmount` field to
+        //            account for potential transfer fees.
+        SolanaTokenType transferType =
+            TokenLib.initializeTransfer({transfer: transfer, crossChainErc20Factory: CROSS_CHAIN_ERC20_FACTORY});
+
+        bytes memory data =
+            SVMBridgeLib.serializeResumableTransfer({transfer: transfer, tokenType: transferType, ixs: ixs});
+        require(data.length <= SVMLib.MAX_SOLANA_DATA_LENGTH, SerializedMessageTooBig());
+        MessageStorageLib.sendMessage({sender: msg.sender, data: data});
+    }
+
     /// @notice Relays messages sent from Solana to Base.
     ///
     /// @param messages The messages to relay.
@@ -31,18 +31,88 @@ library SVMBridgeLib {
         returns (bytes memory)
     {
         // Variant discriminator for Transfer (1)
-        bytes memory result = abi.encodePacked(uint8(1));
+        return abi.encodePacked(uint8(1), _serializeTokenTransfer(transfer, tokenType), SVMLib.serializeIxs(ixs));
+    }
+
+    /// @notice Serializes a Message::TransferWithRelayerFee variant to Borsh-compatible bytes.
+    ///
+    /// @param transfer The token transfer to serialize.
+    /// @param tokenType The Solana token type.
+    /// @param ixs The optional Solana instructions.
+    /// @param relayerFee The part of `transfer.remoteAmount` paid to the relayer on Solana.
+    ///
+    /// @return Serialized Message::TransferWithRelayerFee bytes ready for Solana deserialization
+    function serializeTransferWithRelayerFee(
+        Transfer memory transfer,
+        SolanaTokenType tokenType,
+        Ix[] memory ixs,
+        uint64 relayerFee
+    ) internal pure returns (bytes memory) {
+        // Variant discriminator for TransferWithRelayerFee (3)
+        return abi.encodePacked(
+            uint8(3),
+            _serializeTokenTransfer(transfer, tokenType),
+            SVMLib.serializeIxs(ixs),
+            SVMLib.toU64LittleEndian(relayerFee)
+        );
+    }
 
+    /// @notice Serializes a Message::Resumable variant without a transfer to Borsh-compatible bytes.
+    ///
+    /// @dev Resumable messages may be executed on Solana over several relay transactions instead of atomically.
+    ///
+    /// @param ixs The Solana instructions.
+    ///
+    /// @return Serialized Message::Resumable bytes ready for Solana deserialization
+    function serializeResumableCall(Ix[] memory ixs) internal pure returns (bytes memory) {
+        // Variant discriminator for Resumable (2), followed by `transfer: None`
+        return abi.encodePacked(uint8(2), uint8(0), SVMLib.serializeIxs(ixs));
+    }
+
+    /// @notice Serializes a Message::Resumable variant with a transfer to Borsh-compatible bytes.
+    ///
+    /// @dev The transfer is finalized by the first relay step on Solana; the instructions may be executed over
+    ///      several relay transactions instead of atomically.
+    ///
+    /// @param transfer The token transfer to serialize.
+    /// @param tokenType The Solana token type.
+    /// @param ixs The Solana instructions.
+    ///
+    /// @return Serialized Message::Resumable bytes ready for Solana deserialization
+    function serializeResumableTransfer(Transfer memory transfer, SolanaTokenType tokenType, Ix[] memory ixs)
+        internal
+        pure
+        returns (bytes memory)
+    {
+        // Variant discriminator for Resumable (2), followed by `transfer: Some(..)`
+        return abi.encodePacked(
+            uint8(2), uint8(1), _serializeTokenTransfer(transfer, tokenType), SVMLib.serializeIxs(ixs)
+        );
+    }
+
+    //////////////////////////////////////////////////////////////
+    ///                     Private Functions                  ///
+    //////////////////////////////////////////////////////////////
+
+    /// @notice Serializes a token transfer to the Borsh-compatible bytes of the Solana `Transfer` enum.
+    ///
+    /// @param transfer The token transfer to serialize.
+    /// @param tokenType The Solana token type.
+    ///
+    /// @return result Serialized Transfer bytes
+    function _serializeTokenTransfer(Transfer memory transfer, SolanaTokenType tokenType)
+        private
+        pure
+        returns (bytes memory result)
+    {
         if (tokenType == SolanaTokenType.Sol) {
             result = abi.encodePacked(
-                result,
                 uint8(0), // Sol
                 transfer.to, // to
                 SVMLib.toU64LittleEndian(transfer.remoteAmount) // amount
             );
         } else if (tokenType == SolanaTokenType.Spl) {
             result = abi.encodePacked(
-                result,
                 uint8(1), // Spl
                 transfer.localToken, // remote_token
                 transfer.remoteToken, // local_token
@@ -51,17 +121,11 @@ library SVMBridgeLib {
             );
         } else if (tokenType == SolanaTokenType.WrappedToken) {
             result = abi.encodePacked(
-                result,
                 uint8(2), // WrappedToken
                 transfer.remoteToken, // local_token
                 transfer.to, // to
                 SVMLib.toU64LittleEndian(transfer.remoteAmount) // amount
             );
         }
-
-        // Serialize the instructions array
-        result = abi.encodePacked(result, SVMLib.serializeIxs(ixs));
-
-        return result;
     }
 }
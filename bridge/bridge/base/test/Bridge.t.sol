@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 pragma solidity 0.8.28;
 
+import {Vm} from "forge-std/Vm.sol";
 import {Initializable} from "solady/utils/Initializable.sol";
 import {LibClone} from "solady/utils/LibClone.sol";
 
@@ -10,9 +11,10 @@ import {Bridge} from "../src/Bridge.sol";
 import {CrossChainERC20} from "../src/CrossChainERC20.sol";
 import {Call, CallType} from "../src/libraries/CallLib.sol";
 import {IncomingMessage, MessageType} from "../src/libraries/MessageLib.sol";
+import {Message} from "../src/libraries/MessageStorageLib.sol";
 import {SVMBridgeLib} from "../src/libraries/SVMBridgeLib.sol";
 import {Ix, Pubkey, SVMLib} from "../src/libraries/SVMLib.sol";
-import {TokenLib, Transfer} from "../src/libraries/TokenLib.sol";
+import {SolanaTokenType, TokenLib, Transfer} from "../src/libraries/TokenLib.sol";
 
 import {CommonTest} from "./CommonTest.t.sol";
 import {MockERC20} from "./mocks/MockERC20.sol";
@@ -321,6 +323,92 @@ contract BridgeTest is CommonTest {
         assertEq(user.balance, initialBalance - 1e18);
     }
 
+    function test_bridgeTokenWithRelayerFee_encodesRelayerFee() public {
+        Transfer memory transfer = Transfer({
+            localToken: TokenLib.ETH_ADDRESS,
+            remoteToken: TokenLib.NATIVE_SOL_PUBKEY,
+            to: bytes32(uint256(uint160(user))),
+            remoteAmount: 1e9
+        });
+
+        Ix[] memory ixs = new Ix[](0);
+
+        // Register ETH-SOL pair
+        _registerTokenPair(TokenLib.ETH_ADDRESS, TokenLib.NATIVE_SOL_PUBKEY, 9, 0);
+
+        vm.recordLogs();
+        vm.prank(user);
+        bridge.bridgeTokenWithRelayerFee{value: 1e18}(transfer, ixs, 1e6);
+
+        Vm.Log[] memory logs = vm.getRecordedLogs();
+        // `MessageInitiated` is the last event; its message hash and MMR root are indexed
+        Message memory message = abi.decode(logs[logs.length - 1].data, (Message));
+        assertEq(
+            message.data,
+            SVMBridgeLib.serializeTransferWithRelayerFee(transfer, SolanaTokenType.WrappedToken, ixs, 1e6),
+            "Relayer fee not encoded"
+        );
+    }
+
+    function test_bridgeResumableCall_encodesResumableMessage() public {
+        Ix[] memory ixs = new Ix[](1);
+        ixs[0] = Ix({programId: TEST_SENDER, serializedAccounts: new bytes[](0), data: hex"01"});
+
+        vm.recordLogs();
+        vm.prank(user);
+        bridge.bridgeResumableCall(ixs);
+
+        Vm.Log[] memory logs = vm.getRecordedLogs();
+        Message memory message = abi.decode(logs[logs.length - 1].data, (Message));
+        assertEq(message.sender, user, "Sender mismatch");
+        assertEq(message.data, SVMBridgeLib.serializeResumableCall(ixs), "Resumable call not encoded");
+    }
+
+    function test_bridgeResumableToken_encodesResumableMessage() public {
+        Transfer memory transfer = Transfer({
+            localToken: TokenLib.ETH_ADDRESS,
+            remoteToken: TokenLib.NATIVE_SOL_PUBKEY,
+            to: bytes32(uint256(uint160(user))),
+            remoteAmount: 1e9
+        });
+
+        Ix[] memory ixs = new Ix[](1);
+        ixs[0] = Ix({programId: TEST_SENDER, serializedAccounts: new bytes[](0), data: hex"01"});
+
+        // Register ETH-SOL pair
+        _registerTokenPair(TokenLib.ETH_ADDRESS, TokenLib.NATIVE_SOL_PUBKEY, 9, 0);
+
+        vm.recordLogs();
+        vm.prank(user);
+        bridge.bridgeResumableToken{value: 1e18}(transfer, ixs);
+
+        Vm.Log[] memory logs = vm.getRecordedLogs();
+        Message memory message = abi.decode(logs[logs.length - 1].data, (Message));
+        assertEq(
+            message.data,
+            SVMBridgeLib.serializeResumableTransfer(transfer, SolanaTokenType.WrappedToken, ixs),
+            "Resumable transfer not encoded"
+        );
+    }
+
+    function test_bridgeTokenWithRelayerFee_revertsWhenFeeExceedsAmount() public {
+        Transfer memory transfer = Transfer({
+            localToken: TokenLib.ETH_ADDRESS,
+            remoteToken: TokenLib.NATIVE_SOL_PUBKEY,
+            to: bytes32(uint256(uint160(user))),
+            remoteAmount: 1e9
+        });
+
+        Ix[] memory ixs = new Ix[](0);
+
+        // Register ETH-SOL pair
+        _registerTokenPair(TokenLib.ETH_ADDRESS, TokenLib.NATIVE_SOL_PUBKEY, 9, 0);
+
+        vm.expectRevert(Bridge.RelayerFeeExceedsAmount.selector);
+        vm.prank(user);
+        bridge.bridgeTokenWithRelayerFee{value: 1e18}(transfer, ixs, 1e9 + 1);
+    }
+
     function test_bridgeToken_revertsWithInvalidMsgValue() public {
         Transfer memory transfer = Transfer({
             localToken: TokenLib.ETH_ADDRESS,
@@ -239,6 +239,137 @@ contract SVMBridgeLibTest is Test {
         assertEq(result, expected, "Wrapped token transfer with instructions failed");
     }
 
+    //////////////////////////////////////////////////////////////
+    ///          SerializeTransferWithRelayerFee Tests         ///
+    //////////////////////////////////////////////////////////////
+
+    function test_serializeTransferWithRelayerFee_sol() public pure {
+        Transfer memory transfer =
+            Transfer({localToken: TEST_LOCAL_TOKEN, remoteToken: TEST_REMOTE_TOKEN, to: TEST_TO, remoteAmount: 1000});
+
+        Ix[] memory ixs = new Ix[](0);
+
+        bytes memory result = SVMBridgeLib.serializeTransferWithRelayerFee(transfer, SolanaTokenType.Sol, ixs, 10);
+
+        bytes memory expected = abi.encodePacked(
+            uint8(3), // TransferWithRelayerFee variant
+            uint8(0), // Sol token type
+            transfer.to, // to (32 bytes)
+            SVMLib.toU64LittleEndian(transfer.remoteAmount), // amount (8 bytes)
+            SVMLib.serializeIxs(ixs), // instructions
+            SVMLib.toU64LittleEndian(10) // relayer_fee (8 bytes)
+        );
+
+        assertEq(result, expected, "Sol transfer with relayer fee serialization failed");
+    }
+
+    function test_serializeTransferWithRelayerFee_spl_withInstructions() public pure {
+        Transfer memory transfer = Transfer({
+            localToken: TEST_LOCAL_TOKEN, remoteToken: TEST_REMOTE_TOKEN, to: TEST_TO, remoteAmount: 500000000
+        });
+
+        Ix[] memory ixs = new Ix[](1);
+        ixs[0] = Ix({programId: TEST_REMOTE_TOKEN, serializedAccounts: new bytes[](0), data: hex"deadbeef"});
+
+        bytes memory result = SVMBridgeLib.serializeTransferWithRelayerFee(transfer, SolanaTokenType.Spl, ixs, 1e6);
+
+        bytes memory expected = abi.encodePacked(
+            uint8(3), // TransferWithRelayerFee variant
+            uint8(1), // Spl token type
+            transfer.localToken, // remote_token
+            transfer.remoteToken, // local_token
+            transfer.to,
+            SVMLib.toU64LittleEndian(transfer.remoteAmount),
+            SVMLib.serializeIxs(ixs),
+            SVMLib.toU64LittleEndian(1e6)
+        );
+
+        assertEq(result, expected, "SPL transfer with relayer fee serialization failed");
+    }
+
+    /// @dev Same vectors as the Solana `Message` decoding test: transfers without a relayer fee keep the original
+    ///      encoding, with no trailing relayer fee byte.
+    function test_serializeTransfer_matchesSolanaVectors() public pure {
+        Transfer memory transfer = Transfer({
+            localToken: TEST_LOCAL_TOKEN,
+            remoteToken: TEST_REMOTE_TOKEN,
+            to: bytes32(0x1111111111111111111111111111111111111111111111111111111111111111),
+            remoteAmount: 1000
+        });
+        Ix[] memory ixs = new Ix[](0);
+
+        assertEq(
+            SVMBridgeLib.serializeTransfer(transfer, SolanaTokenType.Sol, ixs),
+            hex"01001111111111111111111111111111111111111111111111111111111111111111e80300000000000000000000",
+            "Transfer vector mismatch"
+        );
+        assertEq(
+            SVMBridgeLib.serializeTransferWithRelayerFee(transfer, SolanaTokenType.Sol, ixs, 10),
+            hex"03001111111111111111111111111111111111111111111111111111111111111111e803000000000000000000000a00000000000000",
+            "TransferWithRelayerFee vector mismatch"
+        );
+    }
+
+    //////////////////////////////////////////////////////////////
+    ///                 SerializeResumable Tests               ///
+    //////////////////////////////////////////////////////////////
+
+    function test_serializeResumableCall() public pure {
+        Ix[] memory ixs = new Ix[](2);
+        ixs[0] = Ix({programId: TEST_REMOTE_TOKEN, serializedAccounts: new bytes[](0), data: hex"01"});
+        ixs[1] = Ix({programId: TEST_NATIVE_SOL, serializedAccounts: new bytes[](0), data: hex"0203"});
+
+        bytes memory result = SVMBridgeLib.serializeResumableCall(ixs);
+
+        bytes memory expected = abi.encodePacked(
+            uint8(2), // Resumable variant
+            uint8(0), // transfer: None
+            SVMLib.serializeIxs(ixs) // instructions
+        );
+
+        assertEq(result, expected, "Resumable call serialization failed");
+    }
+
+    function test_serializeResumableTransfer_wrappedToken() public pure {
+        Transfer memory transfer =
+            Transfer({localToken: TEST_LOCAL_TOKEN, remoteToken: TEST_REMOTE_TOKEN, to: TEST_TO, remoteAmount: 1000});
+
+        Ix[] memory ixs = new Ix[](1);
+        ixs[0] = Ix({programId: TEST_REMOTE_TOKEN, serializedAccounts: new bytes[](0), data: hex"deadbeef"});
+
+        bytes memory result = SVMBridgeLib.serializeResumableTransfer(transfer, SolanaTokenType.WrappedToken, ixs);
+
+        bytes memory expected = abi.encodePacked(
+            uint8(2), // Resumable variant
+            uint8(1), // transfer: Some
+            uint8(2), // WrappedToken token type
+            transfer.remoteToken, // local_token (32 bytes)
+            transfer.to, // to (32 bytes)
+            SVMLib.toU64LittleEndian(transfer.remoteAmount), // amount (8 bytes)
+            SVMLib.serializeIxs(ixs) // instructions
+        );
+
+        assertEq(result, expected, "Resumable transfer serialization failed");
+    }
+
+    /// @dev Same vectors as the Solana `Message` decoding test.
+    function test_serializeResumable_matchesSolanaVectors() public pure {
+        Transfer memory transfer = Transfer({
+            localToken: TEST_LOCAL_TOKEN,
+            remoteToken: TEST_REMOTE_TOKEN,
+            to: bytes32(0x1111111111111111111111111111111111111111111111111111111111111111),
+            remoteAmount: 1000
+        });
+        Ix[] memory ixs = new Ix[](0);
+
+        assertEq(SVMBridgeLib.serializeResumableCall(ixs), hex"020000000000", "Resumable call vector mismatch");
+        assertEq(
+            SVMBridgeLib.serializeResumableTransfer(transfer, SolanaTokenType.Sol, ixs),
+            hex"0201001111111111111111111111111111111111111111111111111111111111111111e80300000000000000000000",
+            "Resumable transfer vector mismatch"
+        );
+    }
+
     //////////////////////////////////////////////////////////////
     ///                    Edge Cases                          ///
     //////////////////////////////////////////////////////////////
@@ -13,6 +13,18 @@ pub enum RelayerError {
     #[msg("Unauthorized to update configuration")]
     UnauthorizedConfigUpdate = 6100,
 
+    #[msg("Only the pending guardian can accept the guardian handover")]
+    UnauthorizedGuardianAcceptance,
+
+    #[msg("No guardian handover is pending")]
+    NoPendingGuardian,
+
+    #[msg("Zero address")]
+    ZeroAddress,
+
+    #[msg("Config account is not in the legacy layout")]
+    CfgAlreadyMigrated,
+
     // Gas Validation (6200-6299)
     #[msg("Gas limit too low")]
     GasLimitTooLow = 6200,
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when the guardian proposes a new guardian.
+#[event]
+pub struct GuardianProposed {
+    pub guardian: Pubkey,
+    pub pending_guardian: Pubkey,
+}
+
+/// Emitted when the guardian withdraws a pending guardian proposal.
+#[event]
+pub struct GuardianProposalCancelled {
+    pub pending_guardian: Pubkey,
+}
+
+/// Emitted when the pending guardian accepts and guardian authority is handed over.
+#[event]
+pub struct GuardianTransferred {
+    pub previous_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+}
@@ -0,0 +1,295 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::CFG_SEED, state::Cfg, GuardianProposalCancelled, GuardianProposed,
+    GuardianTransferred, RelayerError,
+};
+
+/// Accounts struct for proposing or cancelling a guardian handover
+/// Only the current guardian can call these instructions
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    /// The config account containing the guardian
+    #[account(
+        mut,
+        has_one = guardian @ RelayerError::UnauthorizedConfigUpdate,
+        seeds = [CFG_SEED],
+        bump
+    )]
+    pub cfg: Account<'info, Cfg>,
+
+    /// The current guardian
+    pub guardian: Signer<'info>,
+}
+
+/// Accounts struct for accepting a pending guardian handover
+/// Only the proposed guardian can accept
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptGuardian<'info> {
+    /// The config account containing the pending guardian
+    #[account(
+        mut,
+        constraint = cfg.pending_guardian == pending_guardian.key() @ RelayerError::UnauthorizedGuardianAcceptance,
+        seeds = [CFG_SEED],
+        bump
+    )]
+    pub cfg: Account<'info, Cfg>,
+
+    /// The proposed guardian; must sign to prove control of the key
+    pub pending_guardian: Signer<'info>,
+}
+
+pub fn propose_guardian_handler(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    require_keys_neq!(guardian, Pubkey::default(), RelayerError::ZeroAddress);
+    ctx.accounts.cfg.pending_guardian = guardian;
+
+    emit_cpi!(GuardianProposed {
+        guardian: ctx.accounts.cfg.guardian,
+        pending_guardian: guardian,
+    });
+
+    Ok(())
+}
+
+pub fn accept_guardian_handler(ctx: Context<AcceptGuardian>) -> Result<()> {
+    let previous_guardian = ctx.accounts.cfg.guardian;
+    let new_guardian = ctx.accounts.pending_guardian.key();
+
+    ctx.accounts.cfg.guardian = new_guardian;
+    ctx.accounts.cfg.pending_guardian = Pubkey::default();
+
+    emit_cpi!(GuardianTransferred {
+        previous_guardian,
+        new_guardian,
+    });
+
+    Ok(())
+}
+
+pub fn cancel_guardian_proposal_handler(ctx: Context<SetGuardian>) -> Result<()> {
+    let pending_guardian = ctx.accounts.cfg.pending_guardian;
+    require_keys_neq!(
+        pending_guardian,
+        Pubkey::default(),
+        RelayerError::NoPendingGuardian
+    );
+    ctx.accounts.cfg.pending_guardian = Pubkey::default();
+
+    emit_cpi!(GuardianProposalCancelled { pending_guardian });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData, ToAccountMetas};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, instruction, test_utils::*, ID};
+
+    fn propose_ix(cfg_pda: Pubkey, guardian: &Keypair, new_guardian: Pubkey) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::SetGuardian {
+                cfg: cfg_pda,
+                guardian: guardian.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: instruction::ProposeGuardian { new_guardian }.data(),
+        }
+    }
+
+    fn accept_ix(cfg_pda: Pubkey, pending_guardian: &Keypair) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::AcceptGuardian {
+                cfg: cfg_pda,
+                pending_guardian: pending_guardian.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: instruction::AcceptGuardian {}.data(),
+        }
+    }
+
+    fn fetch_cfg(svm: &litesvm::LiteSVM, cfg_pda: Pubkey) -> Cfg {
+        let cfg_account = svm.get_account(&cfg_pda).unwrap();
+        Cfg::try_deserialize(&mut &cfg_account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_propose_and_accept_guardian_succeeds() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        let new_guardian = Keypair::new();
+
+        let tx = Transaction::new(
+            &[&payer, &guardian],
+            Message::new(
+                &[propose_ix(cfg_pda, &guardian, new_guardian.pubkey())],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Current guardian should be able to propose a guardian");
+
+        let event = find_cpi_event::<GuardianProposed>(&meta)
+            .expect("GuardianProposed event should be emitted");
+        assert_eq!(event.guardian, guardian.pubkey());
+        assert_eq!(event.pending_guardian, new_guardian.pubkey());
+
+        // The current guardian stays in charge until the proposal is accepted
+        let cfg = fetch_cfg(&svm, cfg_pda);
+        assert_eq!(cfg.guardian, guardian.pubkey());
+        assert_eq!(cfg.pending_guardian, new_guardian.pubkey());
+
+        let tx = Transaction::new(
+            &[&payer, &new_guardian],
+            Message::new(&[accept_ix(cfg_pda, &new_guardian)], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Pending guardian should be able to accept");
+
+        let event = find_cpi_event::<GuardianTransferred>(&meta)
+            .expect("GuardianTransferred event should be emitted");
+        assert_eq!(event.previous_guardian, guardian.pubkey());
+        assert_eq!(event.new_guardian, new_guardian.pubkey());
+
+        // Verify the guardian was updated
+        let cfg = fetch_cfg(&svm, cfg_pda);
+        assert_eq!(cfg.guardian, new_guardian.pubkey());
+        assert_eq!(cfg.pending_guardian, Pubkey::default());
+    }
+
+    #[test]
+    fn test_propose_guardian_with_non_guardian_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian: _,
+            cfg_pda,
+        } = setup_relayer();
+
+        // Create a fake guardian (not the real one)
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), 1_000_000_000).unwrap();
+
+        let tx = Transaction::new(
+            &[&payer, &fake_guardian],
+            Message::new(
+                &[propose_ix(cfg_pda, &fake_guardian, Pubkey::new_unique())],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+
+        // Verify it's the correct error
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(error_string.contains("UnauthorizedConfigUpdate"));
+    }
+
+    #[test]
+    fn test_accept_guardian_with_non_pending_guardian_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        let impostor = Keypair::new();
+
+        let tx = Transaction::new(
+            &[&payer, &guardian, &impostor],
+            Message::new(
+                &[
+                    propose_ix(cfg_pda, &guardian, Pubkey::new_unique()),
+                    accept_ix(cfg_pda, &impostor),
+                ],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(error_string.contains("UnauthorizedGuardianAcceptance"));
+    }
+
+    #[test]
+    fn test_cancel_guardian_proposal_succeeds() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        let new_guardian = Keypair::new();
+
+        let cancel_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetGuardian {
+                cfg: cfg_pda,
+                guardian: guardian.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: instruction::CancelGuardianProposal {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &guardian],
+            Message::new(
+                &[
+                    propose_ix(cfg_pda, &guardian, new_guardian.pubkey()),
+                    cancel_ix,
+                ],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Current guardian should be able to cancel a proposal");
+
+        let event = find_cpi_event::<GuardianProposalCancelled>(&meta)
+            .expect("GuardianProposalCancelled event should be emitted");
+        assert_eq!(event.pending_guardian, new_guardian.pubkey());
+
+        assert_eq!(fetch_cfg(&svm, cfg_pda).pending_guardian, Pubkey::default());
+
+        // The cancelled proposal can no longer be accepted
+        let tx = Transaction::new(
+            &[&payer, &new_guardian],
+            Message::new(&[accept_ix(cfg_pda, &new_guardian)], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        assert!(svm.send_transaction(tx).is_err());
+        assert_eq!(fetch_cfg(&svm, cfg_pda).guardian, guardian.pubkey());
+    }
+}
@@ -0,0 +1,227 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+use crate::{
+    constants::{CFG_SEED, DISCRIMINATOR_LEN},
+    state::{Cfg, LegacyCfg},
+    RelayerError,
+};
+
+/// Accounts struct for extending a legacy config account to the current `Cfg` layout
+/// Only the guardian recorded in the legacy config can migrate it
+#[derive(Accounts)]
+pub struct MigrateCfg<'info> {
+    /// The config account in the legacy layout. It cannot be loaded as `Cfg` until
+    /// migrated, so the guardian is checked against the legacy layout by the handler.
+    /// CHECK: Seeds and owner are checked here; layout and discriminator by the handler.
+    #[account(mut, owner = crate::ID, seeds = [CFG_SEED], bump)]
+    pub cfg: UncheckedAccount<'info>,
+
+    /// The guardian recorded in the legacy config
+    pub guardian: Signer<'info>,
+
+    /// Funds the rent for the extended account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program used to move the additional rent
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_cfg_handler(ctx: Context<MigrateCfg>) -> Result<()> {
+    let info = ctx.accounts.cfg.to_account_info();
+    require_eq!(
+        info.data_len(),
+        DISCRIMINATOR_LEN + LegacyCfg::INIT_SPACE,
+        RelayerError::CfgAlreadyMigrated
+    );
+
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.starts_with(Cfg::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        LegacyCfg::deserialize(&mut &data[DISCRIMINATOR_LEN..])?
+    };
+    require_keys_eq!(
+        legacy.guardian,
+        ctx.accounts.guardian.key(),
+        RelayerError::UnauthorizedConfigUpdate
+    );
+
+    let new_len = DISCRIMINATOR_LEN + Cfg::INIT_SPACE;
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    info.realloc(new_len, false)?;
+
+    let cfg = Cfg {
+        nonce: legacy.nonce,
+        guardian: legacy.guardian,
+        eip1559: legacy.eip1559,
+        gas_config: legacy.gas_config,
+        pending_guardian: Pubkey::default(),
+    };
+    cfg.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData, ToAccountMetas,
+    };
+    use litesvm::LiteSVM;
+    use solana_account::Account;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, instruction, test_utils::*, ID};
+
+    fn migrate_ix(cfg_pda: Pubkey, guardian: Pubkey, payer: Pubkey) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::MigrateCfg {
+                cfg: cfg_pda,
+                guardian,
+                payer,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::MigrateCfg {}.data(),
+        }
+    }
+
+    fn fetch_cfg(svm: &LiteSVM, cfg_pda: Pubkey) -> Cfg {
+        let cfg_account = svm.get_account(&cfg_pda).unwrap();
+        Cfg::try_deserialize(&mut &cfg_account.data[..]).unwrap()
+    }
+
+    /// Rewrites the initialized config in the layout used before guardian handovers.
+    fn write_legacy_cfg(svm: &mut LiteSVM, cfg_pda: Pubkey) -> Cfg {
+        let cfg = fetch_cfg(svm, cfg_pda);
+        let legacy = LegacyCfg {
+            nonce: 7,
+            guardian: cfg.guardian,
+            eip1559: cfg.eip1559,
+            gas_config: cfg.gas_config,
+        };
+
+        let mut data = Cfg::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        assert_eq!(data.len(), DISCRIMINATOR_LEN + LegacyCfg::INIT_SPACE);
+
+        svm.set_account(
+            cfg_pda,
+            Account {
+                lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        Cfg {
+            nonce: legacy.nonce,
+            guardian: legacy.guardian,
+            eip1559: legacy.eip1559,
+            gas_config: legacy.gas_config,
+            pending_guardian: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn test_migrate_legacy_cfg_succeeds() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+        let expected = write_legacy_cfg(&mut svm, cfg_pda);
+
+        let tx = Transaction::new(
+            &[&payer, &guardian],
+            Message::new(
+                &[migrate_ix(cfg_pda, guardian.pubkey(), payer.pubkey())],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Guardian should be able to migrate the legacy config");
+
+        let cfg_account = svm.get_account(&cfg_pda).unwrap();
+        assert_eq!(cfg_account.data.len(), DISCRIMINATOR_LEN + Cfg::INIT_SPACE);
+        assert_eq!(
+            cfg_account.lamports,
+            svm.minimum_balance_for_rent_exemption(cfg_account.data.len())
+        );
+        assert_eq!(fetch_cfg(&svm, cfg_pda), expected);
+
+        // A migrated config cannot be migrated again
+        svm.expire_blockhash();
+        let tx = Transaction::new(
+            &[&payer, &guardian],
+            Message::new(
+                &[migrate_ix(cfg_pda, guardian.pubkey(), payer.pubkey())],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(error_string.contains("CfgAlreadyMigrated"));
+    }
+
+    #[test]
+    fn test_migrate_cfg_with_non_guardian_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian: _,
+            cfg_pda,
+        } = setup_relayer();
+        write_legacy_cfg(&mut svm, cfg_pda);
+
+        let fake_guardian = Keypair::new();
+
+        let tx = Transaction::new(
+            &[&payer, &fake_guardian],
+            Message::new(
+                &[migrate_ix(cfg_pda, fake_guardian.pubkey(), payer.pubkey())],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(error_string.contains("UnauthorizedConfigUpdate"));
+    }
+}
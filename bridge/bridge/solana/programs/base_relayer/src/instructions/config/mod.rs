@@ -19,10 +19,12 @@ pub struct SetConfig<'info> {
     pub guardian: Signer<'info>,
 }
 
+pub mod guardian;
+pub mod migrate_cfg;
 pub mod set_eip1559_config;
 pub mod set_gas_config;
-pub mod set_guardian;
 
+pub use guardian::*;
+pub use migrate_cfg::*;
 pub use set_eip1559_config::*;
 pub use set_gas_config::*;
-pub use set_guardian::*;
@@ -69,6 +69,7 @@ pub fn initialize_handler(
         },
         gas_config,
         nonce: 0,
+        pending_guardian: Pubkey::default(),
     };
 
     Ok(())
@@ -143,6 +144,7 @@ mod tests {
 
         // Verify all fields
         assert_eq!(cfg.guardian, guardian_pk);
+        assert_eq!(cfg.pending_guardian, Pubkey::default());
         assert_eq!(cfg.nonce, 0);
         assert_eq!(cfg.eip1559.config, Eip1559Config::test_new());
         assert_eq!(cfg.eip1559.current_base_fee, 1); // minimum_base_fee from test config
@@ -104,6 +104,7 @@ mod tests {
             eip1559: new_eip(),
             gas_config: GasConfig::test_new(TEST_GAS_FEE_RECEIVER),
             nonce: 0,
+            pending_guardian: Pubkey::default(),
         };
 
         let res = super::check_gas_limit(cfg.gas_config.max_gas_limit_per_message, &cfg);
@@ -117,6 +118,7 @@ mod tests {
             eip1559: new_eip(),
             gas_config: GasConfig::test_new(TEST_GAS_FEE_RECEIVER),
             nonce: 0,
+            pending_guardian: Pubkey::default(),
         };
         cfg.gas_config.max_gas_limit_per_message = 100;
 
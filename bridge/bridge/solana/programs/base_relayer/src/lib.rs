@@ -4,11 +4,13 @@ use anchor_lang::prelude::*;
 
 mod constants;
 mod errors;
+mod events;
 mod instructions;
 mod internal;
 mod state;
 
 pub use errors::*;
+pub use events::*;
 use instructions::*;
 use internal::*;
 use state::*;
@@ -69,15 +71,46 @@ pub mod base_relayer {
         set_gas_config_handler(ctx, gas_config)
     }
 
-    /// Updates the configured guardian.
+    /// Proposes a new guardian. The handover only takes effect once the proposed
+    /// guardian accepts it via `accept_guardian`.
+    /// Only the current `guardian` may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx`          - The context containing the `cfg` PDA and the `guardian` signer.
+    ///                    Authorization is enforced via an Anchor `has_one` constraint.
+    /// * `new_guardian` - The proposed guardian with permissions to update other configs.
+    pub fn propose_guardian(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<()> {
+        propose_guardian_handler(ctx, new_guardian)
+    }
+
+    /// Accepts a pending guardian handover.
+    /// Only the proposed `pending_guardian` may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the `cfg` PDA and the `pending_guardian` signer.
+    pub fn accept_guardian(ctx: Context<AcceptGuardian>) -> Result<()> {
+        accept_guardian_handler(ctx)
+    }
+
+    /// Cancels a pending guardian handover.
     /// Only the current `guardian` may call this instruction.
     ///
     /// # Arguments
     /// * `ctx` - The context containing the `cfg` PDA and the `guardian` signer.
     ///           Authorization is enforced via an Anchor `has_one` constraint.
-    /// * `cfg` - The new guardian with permissions to update other configs.
-    pub fn set_guardian(ctx: Context<SetConfig>, new_guardian: Pubkey) -> Result<()> {
-        set_guardian_handler(ctx, new_guardian)
+    pub fn cancel_guardian_proposal(ctx: Context<SetGuardian>) -> Result<()> {
+        cancel_guardian_proposal_handler(ctx)
+    }
+
+    /// Extends a `Cfg` account created before guardian handovers existed to the current
+    /// layout, with no guardian handover pending.
+    /// Only the recorded `guardian` may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the legacy `cfg` PDA, the `guardian` signer and the
+    ///           `payer` funding the additional rent.
+    pub fn migrate_cfg(ctx: Context<MigrateCfg>) -> Result<()> {
+        migrate_cfg_handler(ctx)
     }
 
     /// Pays the gas cost for relaying a message to Base and records the request.
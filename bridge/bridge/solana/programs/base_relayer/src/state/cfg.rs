@@ -13,4 +13,19 @@ pub struct Cfg {
     pub eip1559: Eip1559,
     /// Gas configuration
     pub gas_config: GasConfig,
+    /// Guardian proposed by the current guardian; must accept before taking over.
+    /// `Pubkey::default()` when no handover is in progress.
+    ///
+    /// Kept last so configs created before guardian handovers existed only need to be
+    /// extended by `migrate_cfg`.
+    pub pending_guardian: Pubkey,
+}
+
+/// Layout of `Cfg` before guardian handovers were introduced, used by `migrate_cfg`.
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq, Eq, InitSpace)]
+pub struct LegacyCfg {
+    pub nonce: u64,
+    pub guardian: Pubkey,
+    pub eip1559: Eip1559,
+    pub gas_config: GasConfig,
 }
@@ -5,7 +5,7 @@ use anchor_lang::{
     },
     system_program, InstructionData,
 };
-use litesvm::LiteSVM;
+use litesvm::{types::TransactionMetadata, LiteSVM};
 use solana_account::Account;
 use solana_keypair::Keypair;
 use solana_loader_v3_interface::state::UpgradeableLoaderState;
@@ -209,6 +209,24 @@ pub fn setup_relayer() -> SetupRelayerResult {
     }
 }
 
+pub fn event_authority_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &ID).0
+}
+
+/// Decodes the first event of type `T` emitted via `emit_cpi!` in the transaction.
+pub fn find_cpi_event<T: anchor_lang::Event + AnchorDeserialize>(
+    meta: &TransactionMetadata,
+) -> Option<T> {
+    meta.inner_instructions.iter().flatten().find_map(|ix| {
+        let data = ix
+            .instruction
+            .data
+            .strip_prefix(anchor_lang::event::EVENT_IX_TAG_LE)?
+            .strip_prefix(T::DISCRIMINATOR)?;
+        T::try_from_slice(data).ok()
+    })
+}
+
 pub fn mock_clock(svm: &mut LiteSVM, timestamp: i64) {
     let mut clock = svm.get_sysvar::<Clock>();
     clock.unix_timestamp = timestamp;
@@ -1,12 +1,36 @@
 use anchor_lang::prelude::*;
 
+use crate::common::{MAX_BASE_ORACLE_SIGNER_COUNT, MAX_PARTNER_SIGNER_WEIGHT_COUNT};
+
 #[constant]
 pub const INCOMING_MESSAGE_SEED: &[u8] = b"incoming_message";
 #[constant]
 pub const OUTPUT_ROOT_SEED: &[u8] = b"output_root";
 #[constant]
+pub const RATE_LIMIT_SEED: &[u8] = b"rate_limit";
+#[constant]
+pub const NONCE_BITMAP_SEED: &[u8] = b"nonce_bitmap";
+#[constant]
 pub const BRIDGE_CPI_AUTHORITY_SEED: &[u8] = b"bridge_cpi_authority";
 #[constant]
+pub const CPI_POLICY_SEED: &[u8] = b"cpi_policy";
+#[constant]
+pub const BASE_ORACLE_SIGNERS_SEED: &[u8] = b"base_oracle_signers";
+#[constant]
+pub const PENDING_OUTPUT_ROOT_SEED: &[u8] = b"pending_output_root";
+#[constant]
+pub const PENDING_OUTPUT_ROOT_TTL_SECONDS: i64 = 24 * 60 * 60; // 1 day
+#[constant]
+pub const MAX_PENDING_OUTPUT_ROOT_SIGNER_COUNT: u8 =
+    MAX_BASE_ORACLE_SIGNER_COUNT + MAX_PARTNER_SIGNER_WEIGHT_COUNT;
+// The combined cap is a `u8`, so it must cover every Base oracle and partner signer without overflow
+const _: () = assert!(
+    MAX_BASE_ORACLE_SIGNER_COUNT as u16 + MAX_PARTNER_SIGNER_WEIGHT_COUNT as u16 <= u8::MAX as u16
+);
+#[constant]
 pub const PARTNER_SIGNERS_ACCOUNT_SEED: &[u8] = b"signers";
 #[constant]
 pub const PARTNER_PROGRAM_ID: Pubkey = pubkey!("S1GN4jus9XzKVVnoHqfkjo1GN8bX46gjXZQwsdGBPHE");
+#[constant]
+pub const NONCE_BITMAP_PAGE_BITS: u64 = 4096; // Base nonces tracked by a single `NonceBitmap` page
+pub const NONCE_BITMAP_PAGE_BYTES: usize = (NONCE_BITMAP_PAGE_BITS / 8) as usize;
@@ -0,0 +1,431 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::base_to_solana::{
+    constants::{BRIDGE_CPI_AUTHORITY_SEED, INCOMING_MESSAGE_SEED, NONCE_BITMAP_SEED},
+    state::{IncomingMessage, NonceBitmap},
+    LegacyIncomingMessage, Message, Transfer,
+};
+use crate::common::{
+    bridge::Bridge, PartialTokenMetadata, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_RELAY_MESSAGE,
+};
+use crate::solana_to_base::{
+    internal::record_outgoing_transfer, OutgoingMessage, Transfer as OutgoingTransfer,
+    OUTGOING_MESSAGE_SEED,
+};
+use crate::{BridgeError, MessageBounced};
+
+/// Accounts struct for the bounce_message instruction that gives up on relaying a transfer message
+/// and returns the bridged amount to its Base sender through a Solana -> Base outgoing message.
+///
+/// Nothing was released on Solana for an unrelayed message, so no tokens move here: the outgoing
+/// message mints or unlocks the amount back on Base.
+///
+/// Only the deadline path is supported: a message becomes bounceable once
+/// `protocol_config.bounce_delay_seconds` have elapsed since it was proven. Failed relay attempts
+/// are not counted, as a failing relay reverts its whole transaction and cannot record anything
+/// on chain.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(outgoing_message_salt: [u8; 32])]
+pub struct BounceMessage<'info> {
+    /// The account that pays for the outgoing message, the nonce bitmap page and the gas fee.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The unrelayed incoming message being bounced; closed once bounced.
+    /// Messages still in the legacy layout must be migrated with `migrate_incoming_message` first.
+    #[account(
+        mut,
+        seeds = [INCOMING_MESSAGE_SEED, message.message_hash.as_ref()],
+        bump,
+        constraint = !LegacyIncomingMessage::is_legacy(&message.to_account_info())?
+            @ BridgeError::IncomingMessageNotMigrated,
+        close = message_payer
+    )]
+    pub message: Account<'info, IncomingMessage>,
+
+    /// The account that paid for the incoming message account; receives its rent.
+    /// CHECK: Checked against `message.payer`.
+    #[account(mut, address = message.payer @ BridgeError::IncorrectMessagePayer)]
+    pub message_payer: UncheckedAccount<'info>,
+
+    /// The replay protection page covering the message nonce.
+    /// The nonce is consumed so the message can never be relayed after bouncing.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + NonceBitmap::INIT_SPACE,
+        seeds = [NONCE_BITMAP_SEED, &NonceBitmap::page(message.nonce).to_le_bytes()],
+        bump
+    )]
+    pub nonce_bitmap: Account<'info, NonceBitmap>,
+
+    /// The main bridge state account that tracks outgoing nonces and fee parameters.
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The account that receives payment for the gas costs of the bounce on Base.
+    /// CHECK: This account is validated to be the same as bridge.gas_config.gas_fee_receiver
+    #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
+    pub gas_fee_receiver: AccountInfo<'info>,
+
+    /// The outgoing message account returning the bridged amount to the Base sender.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + OutgoingMessage::space::<OutgoingTransfer>(0),
+    )]
+    pub outgoing_message: Account<'info, OutgoingMessage>,
+
+    /// The wrapped token mint of wrapped token transfers, used to look up the Base token.
+    /// Ignored for SOL and SPL transfers.
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// System program required for account creation and the gas fee transfer.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn bounce_message_handler(
+    ctx: Context<BounceMessage>,
+    _outgoing_message_salt: [u8; 32],
+) -> Result<()> {
+    // Resumable messages that started executing already released their transfer
+    let message = &ctx.accounts.message;
+    require!(
+        !message.executed && message.cursor.is_none(),
+        BridgeError::MessageNotBounceable
+    );
+
+    let nonce = message.nonce;
+    let sender = message.sender;
+    let proven_at = message.proven_at;
+    let payload = message.message.clone();
+    let (local_token, amount, outgoing_nonce) = bounce_transfer(
+        &payload,
+        nonce,
+        sender,
+        proven_at,
+        &ctx.accounts.payer,
+        &ctx.accounts.gas_fee_receiver,
+        &mut ctx.accounts.bridge,
+        &mut ctx.accounts.nonce_bitmap,
+        &mut ctx.accounts.outgoing_message,
+        ctx.accounts.mint.as_ref(),
+        &ctx.accounts.system_program,
+        ctx.program_id,
+    )?;
+
+    emit_cpi!(MessageBounced {
+        incoming_message: ctx.accounts.message.key(),
+        nonce,
+        sender,
+        outgoing_message: ctx.accounts.outgoing_message.key(),
+        outgoing_nonce,
+        local_token,
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Returns the transfer of an unrelayed message proven at `proven_at` to its Base `sender` once
+/// the bounce delay has elapsed: consumes the message nonce and records the outgoing message.
+///
+/// Returns the bounced Solana mint, the amount and the nonce of the outgoing message.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn bounce_transfer<'info>(
+    message: &Message,
+    nonce: u64,
+    sender: [u8; 20],
+    proven_at: i64,
+    payer: &Signer<'info>,
+    gas_fee_receiver: &AccountInfo<'info>,
+    bridge: &mut Account<'info, Bridge>,
+    nonce_bitmap: &mut Account<'info, NonceBitmap>,
+    outgoing_message: &mut Account<'info, OutgoingMessage>,
+    mint: Option<&InterfaceAccount<'info, Mint>>,
+    system_program: &Program<'info, System>,
+    program_id: &Pubkey,
+) -> Result<(Pubkey, u64, u64)> {
+    // Bouncing replaces relaying the message
+    bridge.pause.require_not_paused(PAUSE_RELAY_MESSAGE)?;
+
+    let bounce_delay_seconds = bridge.protocol_config.bounce_delay_seconds;
+    require!(
+        bounce_delay_seconds > 0,
+        BridgeError::MessageBouncingDisabled
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= proven_at.saturating_add(bounce_delay_seconds as i64),
+        BridgeError::BounceDelayNotElapsed
+    );
+
+    let transfer = match message {
+        Message::Transfer { transfer, .. }
+        | Message::TransferWithRelayerFee { transfer, .. }
+        | Message::Resumable {
+            transfer: Some(transfer),
+            ..
+        } => transfer.clone(),
+        _ => return err!(BridgeError::MessageNotBounceable),
+    };
+    let local_token = transfer.local_token();
+    bridge.pause.require_mint_not_paused(&local_token)?;
+
+    let remote_token = match &transfer {
+        Transfer::Sol(_) => bridge.protocol_config.remote_sol_address,
+        Transfer::Spl(transfer) => transfer.remote_token,
+        Transfer::WrappedToken(transfer) => {
+            let mint = mint.ok_or(BridgeError::MintDoesNotMatchLocalToken)?;
+            require_keys_eq!(
+                mint.key(),
+                transfer.local_token,
+                BridgeError::MintDoesNotMatchLocalToken
+            );
+            PartialTokenMetadata::try_from(&mint.to_account_info())?.remote_token
+        }
+    };
+
+    nonce_bitmap.consume(nonce)?;
+
+    // The bounce is sent on behalf of the Base sender's bridge CPI authority
+    let (cpi_authority, _) =
+        Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()], program_id);
+
+    let amount = transfer.amount();
+    let outgoing_nonce = record_outgoing_transfer(
+        payer,
+        gas_fee_receiver,
+        bridge,
+        outgoing_message,
+        system_program,
+        cpi_authority,
+        OutgoingTransfer {
+            to: sender,
+            local_token,
+            remote_token,
+            amount,
+            call: None,
+        },
+    )?;
+
+    Ok((local_token, amount, outgoing_nonce))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer as _;
+
+    use crate::{
+        accounts,
+        base_to_solana::{hash_message, token::FinalizeBridgeSol},
+        common::MIN_BOUNCE_DELAY_SECONDS,
+        instruction::BounceMessage as BounceMessageIx,
+        solana_to_base::{Message as OutgoingPayload, NATIVE_SOL_PUBKEY},
+        test_utils::{
+            assert_error, event_authority_pda, find_cpi_event, mock_clock, send, setup_bridge,
+            SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+        },
+        ID,
+    };
+
+    const NONCE: u64 = 3;
+    const SENDER: [u8; 20] = [7u8; 20];
+    const PROVEN_AT: i64 = 1_000;
+    const AMOUNT: u64 = 5_000;
+
+    fn set_bounce_delay(svm: &mut LiteSVM, bridge_pda: Pubkey, delay: u64) {
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge.protocol_config.bounce_delay_seconds = delay;
+        let mut bridge_data = Vec::new();
+        bridge.try_serialize(&mut bridge_data).unwrap();
+        bridge_account.data = bridge_data;
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+    }
+
+    fn read_bridge(svm: &LiteSVM, bridge_pda: &Pubkey) -> Bridge {
+        let account = svm.get_account(bridge_pda).unwrap();
+        Bridge::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    /// Writes an unrelayed incoming message holding `message`, proven at `PROVEN_AT`
+    fn write_incoming_message(svm: &mut LiteSVM, payer: Pubkey, message: Message) -> Pubkey {
+        let message_hash = hash_message(
+            &NONCE.to_be_bytes(),
+            &SENDER,
+            &message.try_to_vec().unwrap(),
+        );
+        let incoming_message =
+            Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &message_hash], &ID).0;
+        let mut data = Vec::new();
+        IncomingMessage {
+            sender: SENDER,
+            nonce: NONCE,
+            message_hash,
+            payer,
+            message,
+            cursor: None,
+            proven_at: PROVEN_AT,
+            executed: false,
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        svm.set_account(
+            incoming_message,
+            SvmAccount {
+                lamports: 10_000_000,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+        incoming_message
+    }
+
+    fn sol_transfer() -> Message {
+        Message::Transfer {
+            transfer: Transfer::Sol(FinalizeBridgeSol {
+                to: Pubkey::new_unique(),
+                amount: AMOUNT,
+            }),
+            ixs: vec![],
+        }
+    }
+
+    fn nonce_bitmap_pda() -> Pubkey {
+        Pubkey::find_program_address(
+            &[NONCE_BITMAP_SEED, &NonceBitmap::page(NONCE).to_le_bytes()],
+            &ID,
+        )
+        .0
+    }
+
+    fn bounce_ix(
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        incoming_message: Pubkey,
+        salt: [u8; 32],
+    ) -> (Instruction, Pubkey) {
+        let outgoing_message =
+            Pubkey::find_program_address(&[OUTGOING_MESSAGE_SEED, salt.as_ref()], &ID).0;
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BounceMessage {
+                payer: payer.pubkey(),
+                message: incoming_message,
+                message_payer: payer.pubkey(),
+                nonce_bitmap: nonce_bitmap_pda(),
+                bridge: bridge_pda,
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                outgoing_message,
+                mint: None,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: BounceMessageIx {
+                outgoing_message_salt: salt,
+            }
+            .data(),
+        };
+        (ix, outgoing_message)
+    }
+
+    #[test]
+    fn test_bounce_sol_transfer_returns_amount_to_base_sender() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        set_bounce_delay(&mut svm, bridge_pda, MIN_BOUNCE_DELAY_SECONDS);
+        let incoming_message = write_incoming_message(&mut svm, payer.pubkey(), sol_transfer());
+        let bridge_before = read_bridge(&svm, &bridge_pda);
+
+        // Relayers get the whole delay to execute the message
+        mock_clock(&mut svm, PROVEN_AT + MIN_BOUNCE_DELAY_SECONDS as i64 - 1);
+        let (ix, _) = bounce_ix(&payer, bridge_pda, incoming_message, [1u8; 32]);
+        assert_error(send(&mut svm, &payer, ix), "BounceDelayNotElapsed");
+
+        mock_clock(&mut svm, PROVEN_AT + MIN_BOUNCE_DELAY_SECONDS as i64);
+        let (ix, outgoing_message) = bounce_ix(&payer, bridge_pda, incoming_message, [2u8; 32]);
+        let meta = send(&mut svm, &payer, ix).expect("bounce_message should succeed");
+
+        let cpi_authority =
+            Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, &SENDER], &ID).0;
+        let account = svm.get_account(&outgoing_message).unwrap();
+        let outgoing = OutgoingMessage::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(outgoing.nonce, bridge_before.nonce);
+        assert_eq!(outgoing.sender, cpi_authority);
+        assert_eq!(
+            outgoing.message,
+            OutgoingPayload::Transfer(OutgoingTransfer {
+                to: SENDER,
+                local_token: NATIVE_SOL_PUBKEY,
+                remote_token: bridge_before.protocol_config.remote_sol_address,
+                amount: AMOUNT,
+                call: None,
+            })
+        );
+        assert_eq!(
+            read_bridge(&svm, &bridge_pda).nonce,
+            bridge_before.nonce + 1
+        );
+
+        let event = find_cpi_event::<MessageBounced>(&meta)
+            .expect("MessageBounced event should be emitted");
+        assert_eq!(event.incoming_message, incoming_message);
+        assert_eq!(event.nonce, NONCE);
+        assert_eq!(event.sender, SENDER);
+        assert_eq!(event.outgoing_message, outgoing_message);
+        assert_eq!(event.outgoing_nonce, bridge_before.nonce);
+        assert_eq!(event.amount, AMOUNT);
+
+        // The message is closed and can never be relayed
+        assert!(svm
+            .get_account(&incoming_message)
+            .is_none_or(|a| a.lamports == 0));
+        let bitmap_account = svm.get_account(&nonce_bitmap_pda()).unwrap();
+        let bitmap = NonceBitmap::try_deserialize(&mut &bitmap_account.data[..]).unwrap();
+        assert!(bitmap.is_consumed(NONCE));
+    }
+
+    #[test]
+    fn test_bounce_message_rejects_disabled_bouncing_and_call_messages() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_clock(&mut svm, PROVEN_AT + 10 * MIN_BOUNCE_DELAY_SECONDS as i64);
+
+        let transfer = write_incoming_message(&mut svm, payer.pubkey(), sol_transfer());
+        let (ix, _) = bounce_ix(&payer, bridge_pda, transfer, [1u8; 32]);
+        assert_error(send(&mut svm, &payer, ix), "MessageBouncingDisabled");
+
+        set_bounce_delay(&mut svm, bridge_pda, MIN_BOUNCE_DELAY_SECONDS);
+        let call = write_incoming_message(&mut svm, payer.pubkey(), Message::Call(vec![]));
+        let (ix, _) = bounce_ix(&payer, bridge_pda, call, [2u8; 32]);
+        assert_error(send(&mut svm, &payer, ix), "MessageNotBounceable");
+
+        // Rent can only go back to the account that proved the message
+        let mut ix = bounce_ix(&payer, bridge_pda, transfer, [3u8; 32]).0;
+        ix.accounts[2].pubkey = Pubkey::new_unique();
+        assert_error(send(&mut svm, &payer, ix), "IncorrectMessagePayer");
+    }
+}
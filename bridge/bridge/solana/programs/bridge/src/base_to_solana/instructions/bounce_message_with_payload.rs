@@ -0,0 +1,312 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::base_to_solana::{
+    bounce_transfer,
+    constants::NONCE_BITMAP_SEED,
+    state::{IncomingMessageHash, NonceBitmap},
+    verify_payload,
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN};
+use crate::solana_to_base::{OutgoingMessage, Transfer as OutgoingTransfer, OUTGOING_MESSAGE_SEED};
+use crate::{BridgeError, MessageBounced};
+
+/// Accounts struct for the bounce_message_with_payload instruction that bounces a transfer message
+/// proven with `prove_message_hash`. The payload is provided inline and checked against the stored
+/// hash; the bounce conditions are the same as for `bounce_message`.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(outgoing_message_salt: [u8; 32])]
+pub struct BounceMessageWithPayload<'info> {
+    /// The account that pays for the outgoing message, the nonce bitmap page and the gas fee.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The unrelayed message hash account being bounced; closed once bounced.
+    #[account(mut, close = message_payer)]
+    pub message: Account<'info, IncomingMessageHash>,
+
+    /// The account that paid for the message hash account; receives its rent.
+    /// CHECK: Checked against `message.payer`.
+    #[account(mut, address = message.payer @ BridgeError::IncorrectMessagePayer)]
+    pub message_payer: UncheckedAccount<'info>,
+
+    /// The replay protection page covering the message nonce.
+    /// The nonce is consumed so the message can never be relayed after bouncing.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + NonceBitmap::INIT_SPACE,
+        seeds = [NONCE_BITMAP_SEED, &NonceBitmap::page(message.nonce).to_le_bytes()],
+        bump
+    )]
+    pub nonce_bitmap: Account<'info, NonceBitmap>,
+
+    /// The main bridge state account that tracks outgoing nonces and fee parameters.
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The account that receives payment for the gas costs of the bounce on Base.
+    /// CHECK: This account is validated to be the same as bridge.gas_config.gas_fee_receiver
+    #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
+    pub gas_fee_receiver: AccountInfo<'info>,
+
+    /// The outgoing message account returning the bridged amount to the Base sender.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + OutgoingMessage::space::<OutgoingTransfer>(0),
+    )]
+    pub outgoing_message: Account<'info, OutgoingMessage>,
+
+    /// The wrapped token mint of wrapped token transfers, used to look up the Base token.
+    /// Ignored for SOL and SPL transfers.
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// System program required for account creation and the gas fee transfer.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn bounce_message_with_payload_handler(
+    ctx: Context<BounceMessageWithPayload>,
+    _outgoing_message_salt: [u8; 32],
+    sender: [u8; 20],
+    data: Vec<u8>,
+) -> Result<()> {
+    let payload = verify_payload(&mut ctx.accounts.message, &sender, &data)?;
+
+    let nonce = ctx.accounts.message.nonce;
+    let (local_token, amount, outgoing_nonce) = bounce_transfer(
+        &payload,
+        nonce,
+        sender,
+        ctx.accounts.message.proven_at,
+        &ctx.accounts.payer,
+        &ctx.accounts.gas_fee_receiver,
+        &mut ctx.accounts.bridge,
+        &mut ctx.accounts.nonce_bitmap,
+        &mut ctx.accounts.outgoing_message,
+        ctx.accounts.mint.as_ref(),
+        &ctx.accounts.system_program,
+        ctx.program_id,
+    )?;
+
+    emit_cpi!(MessageBounced {
+        incoming_message: ctx.accounts.message.key(),
+        nonce,
+        sender,
+        outgoing_message: ctx.accounts.outgoing_message.key(),
+        outgoing_nonce,
+        local_token,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer as _;
+
+    use crate::{
+        accounts,
+        base_to_solana::{hash_message, token::FinalizeBridgeSol, Message, Transfer},
+        common::MIN_BOUNCE_DELAY_SECONDS,
+        instruction::BounceMessageWithPayload as BounceMessageWithPayloadIx,
+        solana_to_base::{Message as OutgoingPayload, NATIVE_SOL_PUBKEY},
+        test_utils::{
+            assert_error, event_authority_pda, find_cpi_event, mock_clock, send, setup_bridge,
+            SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+        },
+        ID,
+    };
+
+    const NONCE: u64 = 3;
+    const SENDER: [u8; 20] = [7u8; 20];
+    const PROVEN_AT: i64 = 1_000;
+    const AMOUNT: u64 = 5_000;
+
+    fn set_bounce_delay(svm: &mut LiteSVM, bridge_pda: Pubkey, delay: u64) {
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge.protocol_config.bounce_delay_seconds = delay;
+        let mut bridge_data = Vec::new();
+        bridge.try_serialize(&mut bridge_data).unwrap();
+        bridge_account.data = bridge_data;
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+    }
+
+    fn sol_transfer_data() -> Vec<u8> {
+        Message::Transfer {
+            transfer: Transfer::Sol(FinalizeBridgeSol {
+                to: Pubkey::new_unique(),
+                amount: AMOUNT,
+            }),
+            ixs: vec![],
+        }
+        .try_to_vec()
+        .unwrap()
+    }
+
+    /// Writes an unrelayed message hash account committing to `data`, proven at `PROVEN_AT`
+    fn write_incoming_message_hash(svm: &mut LiteSVM, payer: Pubkey, data: &[u8]) -> Pubkey {
+        let incoming_message = Pubkey::new_unique();
+        let mut account_data = Vec::new();
+        IncomingMessageHash {
+            nonce: NONCE,
+            payer,
+            message_hash: hash_message(&NONCE.to_be_bytes(), &SENDER, data),
+            proven_at: PROVEN_AT,
+            executed: false,
+        }
+        .try_serialize(&mut account_data)
+        .unwrap();
+        svm.set_account(
+            incoming_message,
+            SvmAccount {
+                lamports: 10_000_000,
+                data: account_data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+        incoming_message
+    }
+
+    fn nonce_bitmap_pda() -> Pubkey {
+        Pubkey::find_program_address(
+            &[NONCE_BITMAP_SEED, &NonceBitmap::page(NONCE).to_le_bytes()],
+            &ID,
+        )
+        .0
+    }
+
+    fn bounce_ix(
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        incoming_message: Pubkey,
+        salt: [u8; 32],
+        data: Vec<u8>,
+    ) -> (Instruction, Pubkey) {
+        let outgoing_message =
+            Pubkey::find_program_address(&[OUTGOING_MESSAGE_SEED, salt.as_ref()], &ID).0;
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BounceMessageWithPayload {
+                payer: payer.pubkey(),
+                message: incoming_message,
+                message_payer: payer.pubkey(),
+                nonce_bitmap: nonce_bitmap_pda(),
+                bridge: bridge_pda,
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                outgoing_message,
+                mint: None,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: BounceMessageWithPayloadIx {
+                outgoing_message_salt: salt,
+                sender: SENDER,
+                data,
+            }
+            .data(),
+        };
+        (ix, outgoing_message)
+    }
+
+    #[test]
+    fn test_bounce_message_with_payload_returns_amount_to_base_sender() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        set_bounce_delay(&mut svm, bridge_pda, MIN_BOUNCE_DELAY_SECONDS);
+        let data = sol_transfer_data();
+        let incoming_message = write_incoming_message_hash(&mut svm, payer.pubkey(), &data);
+
+        // Relayers get the whole delay to execute the message
+        mock_clock(&mut svm, PROVEN_AT + MIN_BOUNCE_DELAY_SECONDS as i64 - 1);
+        let (ix, _) = bounce_ix(
+            &payer,
+            bridge_pda,
+            incoming_message,
+            [1u8; 32],
+            data.clone(),
+        );
+        assert_error(send(&mut svm, &payer, ix), "BounceDelayNotElapsed");
+
+        mock_clock(&mut svm, PROVEN_AT + MIN_BOUNCE_DELAY_SECONDS as i64);
+
+        // The payload must match the proven message hash
+        let (ix, _) = bounce_ix(
+            &payer,
+            bridge_pda,
+            incoming_message,
+            [2u8; 32],
+            sol_transfer_data(),
+        );
+        assert_error(send(&mut svm, &payer, ix), "InvalidMessageHash");
+
+        let (ix, outgoing_message) =
+            bounce_ix(&payer, bridge_pda, incoming_message, [3u8; 32], data);
+        let meta = send(&mut svm, &payer, ix).expect("bounce_message_with_payload should succeed");
+
+        let account = svm.get_account(&outgoing_message).unwrap();
+        let outgoing = OutgoingMessage::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(matches!(
+            outgoing.message,
+            OutgoingPayload::Transfer(OutgoingTransfer {
+                to: SENDER,
+                local_token: NATIVE_SOL_PUBKEY,
+                amount: AMOUNT,
+                call: None,
+                ..
+            })
+        ));
+
+        let event = find_cpi_event::<MessageBounced>(&meta)
+            .expect("MessageBounced event should be emitted");
+        assert_eq!(event.incoming_message, incoming_message);
+        assert_eq!(event.nonce, NONCE);
+        assert_eq!(event.amount, AMOUNT);
+
+        // The message is closed and can never be relayed
+        assert!(svm
+            .get_account(&incoming_message)
+            .is_none_or(|a| a.lamports == 0));
+        let bitmap_account = svm.get_account(&nonce_bitmap_pda()).unwrap();
+        let bitmap = NonceBitmap::try_deserialize(&mut &bitmap_account.data[..]).unwrap();
+        assert!(bitmap.is_consumed(NONCE));
+    }
+
+    #[test]
+    fn test_bounce_message_with_payload_rejects_call_messages() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        set_bounce_delay(&mut svm, bridge_pda, MIN_BOUNCE_DELAY_SECONDS);
+        mock_clock(&mut svm, PROVEN_AT + MIN_BOUNCE_DELAY_SECONDS as i64);
+
+        let data = Message::Call(vec![]).try_to_vec().unwrap();
+        let incoming_message = write_incoming_message_hash(&mut svm, payer.pubkey(), &data);
+        let (ix, _) = bounce_ix(&payer, bridge_pda, incoming_message, [1u8; 32], data);
+        assert_error(send(&mut svm, &payer, ix), "MessageNotBounceable");
+    }
+}
@@ -3,9 +3,11 @@ pub mod append_to_prove_buffer_proof;
 pub mod close_prove_buffer;
 pub mod initialize_prove_buffer;
 pub mod prove_message_buffered;
+pub mod relay_message_with_payload_buffered;
 
 pub use append_to_prove_buffer_data::*;
 pub use append_to_prove_buffer_proof::*;
 pub use close_prove_buffer::*;
 pub use initialize_prove_buffer::*;
 pub use prove_message_buffered::*;
+pub use relay_message_with_payload_buffered::*;
@@ -1,15 +1,16 @@
-use anchor_lang::{prelude::*, solana_program::keccak};
+use anchor_lang::prelude::*;
 
-use crate::BridgeError;
 use crate::{
     base_to_solana::{
-        constants::INCOMING_MESSAGE_SEED, internal::mmr, state::IncomingMessage, Message,
-        OutputRoot, ProveBuffer,
+        constants::INCOMING_MESSAGE_SEED, hash_message, internal::mmr, state::IncomingMessage,
+        Message, OutputRoot, ProveBuffer,
     },
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_PROVE_MESSAGE},
 };
+use crate::{BridgeError, MessageProven};
 
 /// Buffered variant of `prove_message` that reads data/proof from a `ProveBuffer` and closes it.
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(nonce: u64, sender: [u8; 20], message_hash: [u8; 32])]
 pub struct ProveMessageBuffered<'info> {
@@ -55,8 +56,17 @@ pub fn prove_message_buffered_handler(
     sender: [u8; 20],
     message_hash: [u8; 32],
 ) -> Result<()> {
-    // Pause
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    // Check if this instruction family is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_PROVE_MESSAGE)?;
+
+    // Only roots past their challenge window that were not invalidated can be proven against
+    ctx.accounts.output_root.require_provable(
+        ctx.accounts.bridge.protocol_config.root_finality_seconds,
+        Clock::get()?.unix_timestamp,
+    )?;
 
     // Verify hash
     let data = &ctx.accounts.prove_buffer.data;
@@ -79,19 +89,24 @@ pub fn prove_message_buffered_handler(
     let message_enum = Message::try_from_slice(data)?;
     *ctx.accounts.message = IncomingMessage {
         executed: false,
+        cursor: None,
+        proven_at: Clock::get()?.unix_timestamp,
         sender,
+        nonce,
+        message_hash,
+        payer: ctx.accounts.payer.key(),
         message: message_enum,
     };
 
-    Ok(())
-}
+    emit_cpi!(MessageProven {
+        incoming_message: ctx.accounts.message.key(),
+        output_root: ctx.accounts.output_root.key(),
+        message_hash,
+        nonce,
+        sender,
+    });
 
-fn hash_message(nonce: &[u8], sender: &[u8; 20], data: &[u8]) -> [u8; 32] {
-    let mut data_to_hash = Vec::new();
-    data_to_hash.extend_from_slice(nonce);
-    data_to_hash.extend_from_slice(sender);
-    data_to_hash.extend_from_slice(data);
-    keccak::hash(&data_to_hash).0
+    Ok(())
 }
 
 #[cfg(test)]
@@ -102,7 +117,6 @@ mod tests {
     use anchor_lang::solana_program::keccak::hash as keccak_hash;
     use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
     use litesvm::LiteSVM;
-    use solana_account::Account as SvmAccount;
     use solana_keypair::Keypair;
     use solana_message::Message as SolMessage;
     use solana_signer::Signer as _;
@@ -116,7 +130,7 @@ mod tests {
             AppendToProveBufferData, AppendToProveBufferProof, InitializeProveBuffer,
             ProveMessageBuffered as ProveMessageBufferedIx,
         },
-        test_utils::{setup_bridge, SetupBridgeResult},
+        test_utils::{event_authority_pda, setup_bridge, write_output_root, SetupBridgeResult},
         ID,
     };
 
@@ -126,23 +140,18 @@ mod tests {
         root: [u8; 32],
         total_leaf_count: u64,
     ) {
-        let output_root = crate::base_to_solana::state::OutputRoot {
-            root,
-            total_leaf_count,
-        };
-        let mut data = Vec::new();
-        output_root.try_serialize(&mut data).unwrap();
-        svm.set_account(
+        write_output_root(
+            svm,
             root_pk,
-            SvmAccount {
-                lamports: 1_000_000,
-                data,
-                owner: ID,
-                executable: false,
-                rent_epoch: 0,
+            &crate::base_to_solana::state::OutputRoot {
+                root,
+                total_leaf_count,
+                base_block_number: 0,
+                payer: Pubkey::default(),
+                registered_at: 0,
+                invalidated: false,
             },
-        )
-        .unwrap();
+        );
     }
 
     fn compute_message_hash(nonce: u64, sender: [u8; 20], data: &[u8]) -> [u8; 32] {
@@ -286,6 +295,8 @@ mod tests {
             owner: owner.pubkey(),
             prove_buffer: prove_buffer.pubkey(),
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -314,6 +325,8 @@ mod tests {
         let incoming = IncomingMessage::try_deserialize(&mut &msg_account.data[..]).unwrap();
         assert!(!incoming.executed);
         assert_eq!(incoming.sender, sender);
+        assert_eq!(incoming.nonce, nonce);
+        assert_eq!(incoming.payer, payer.pubkey());
         let stored_bytes = incoming.message.clone().try_to_vec().unwrap();
         assert_eq!(stored_bytes, message_bytes);
 
@@ -357,6 +370,8 @@ mod tests {
             owner: unauthorized.pubkey(), // wrong owner
             prove_buffer: prove_buffer.pubkey(),
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -463,6 +478,8 @@ mod tests {
             owner: owner.pubkey(),
             prove_buffer: prove_buffer.pubkey(),
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -503,7 +520,7 @@ mod tests {
         // Pause the bridge
         let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
         let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.paused = true;
+        bridge.pause.flags = PAUSE_PROVE_MESSAGE;
         let mut new_data = Vec::new();
         bridge.try_serialize(&mut new_data).unwrap();
         bridge_acc.data = new_data;
@@ -526,6 +543,8 @@ mod tests {
             owner: owner.pubkey(),
             prove_buffer: prove_buffer.pubkey(),
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{
+    constants::{CPI_POLICY_SEED, NONCE_BITMAP_SEED},
+    execute_message,
+    state::{IncomingMessageHash, NonceBitmap, ProveBuffer},
+    verify_payload,
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_RELAY_MESSAGE};
+use crate::{BridgeError, MessageRelayed};
+
+/// Buffered variant of `relay_message_with_payload` that reads the payload from a `ProveBuffer`
+/// and closes it.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RelayMessageWithPayloadBuffered<'info> {
+    /// Pays for the nonce bitmap page if it does not exist yet
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The incoming message hash account committing to the buffered payload
+    #[account(mut)]
+    pub message: Account<'info, IncomingMessageHash>,
+
+    /// The replay protection page covering the message nonce
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + NonceBitmap::INIT_SPACE,
+        seeds = [NONCE_BITMAP_SEED, &NonceBitmap::page(message.nonce).to_le_bytes()],
+        bump
+    )]
+    pub nonce_bitmap: Account<'info, NonceBitmap>,
+
+    /// Bridge for pause checks
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The withdrawal rate limit PDA of the mint released by a transfer message.
+    /// CHECK: Address and ownership are validated in the handler.
+    #[account(mut)]
+    pub rate_limit: Option<UncheckedAccount<'info>>,
+
+    /// The guardian-managed policy restricting the programs the message instructions can target.
+    /// CHECK: Address is validated by the seeds constraint and ownership in the handler.
+    #[account(seeds = [CPI_POLICY_SEED], bump)]
+    pub cpi_policy: UncheckedAccount<'info>,
+
+    /// Owner receives rent when buffer is closed
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Prove buffer containing the payload; closed on success
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ BridgeError::BufferUnauthorizedClose,
+    )]
+    pub prove_buffer: Account<'info, ProveBuffer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn relay_message_with_payload_buffered_handler<'a, 'info>(
+    ctx: Context<'a, '_, 'info, 'info, RelayMessageWithPayloadBuffered<'info>>,
+    sender: [u8; 20],
+) -> Result<()> {
+    // Check if this instruction family is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_RELAY_MESSAGE)?;
+
+    let message = verify_payload(
+        &mut ctx.accounts.message,
+        &sender,
+        &ctx.accounts.prove_buffer.data,
+    )?;
+    ctx.accounts
+        .nonce_bitmap
+        .consume(ctx.accounts.message.nonce)?;
+
+    execute_message(
+        message,
+        &sender,
+        &ctx.accounts.payer.key(),
+        &ctx.accounts.bridge,
+        ctx.accounts.rate_limit.as_deref(),
+        &ctx.accounts.cpi_policy,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    emit_cpi!(MessageRelayed {
+        incoming_message: ctx.accounts.message.key(),
+        sender,
+        nonce: ctx.accounts.message.nonce,
+        message_hash: ctx.accounts.message.message_hash,
+        executed: ctx.accounts.message.executed,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,285 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{
+    constants::{INCOMING_MESSAGE_SEED, NONCE_BITMAP_SEED},
+    state::{IncomingMessage, NonceBitmap},
+    LegacyIncomingMessage,
+};
+use crate::{BridgeError, IncomingMessageClosed};
+
+/// Accounts struct for the close_incoming_message instruction that reclaims the rent of an
+/// executed incoming message. Anyone can call it; the rent always goes back to the original payer.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseIncomingMessage<'info> {
+    /// The executed incoming message account being closed.
+    /// - Its nonce must be consumed, by `relay_message` or by `prove_and_relay_message`
+    /// - Resumable messages can only be closed once all their instructions have run
+    /// - Rent is returned to the payer recorded when the message was proven
+    /// - Messages still in the legacy layout must be migrated with `migrate_incoming_message` first
+    #[account(
+        mut,
+        seeds = [INCOMING_MESSAGE_SEED, message.message_hash.as_ref()],
+        bump,
+        constraint = !LegacyIncomingMessage::is_legacy(&message.to_account_info())?
+            @ BridgeError::IncomingMessageNotMigrated,
+        has_one = payer @ BridgeError::IncorrectMessagePayer,
+        constraint = !message.is_in_progress() @ BridgeError::MessageNotExecuted,
+        close = payer
+    )]
+    pub message: Account<'info, IncomingMessage>,
+
+    /// The replay protection page covering the message nonce.
+    /// Checked to make sure closing the message cannot make it replayable.
+    #[account(
+        seeds = [NONCE_BITMAP_SEED, &NonceBitmap::page(message.nonce).to_le_bytes()],
+        bump
+    )]
+    pub nonce_bitmap: Account<'info, NonceBitmap>,
+
+    /// The account that paid for the incoming message account; receives the rent.
+    /// CHECK: Checked against `message.payer`.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn close_incoming_message_handler(ctx: Context<CloseIncomingMessage>) -> Result<()> {
+    let nonce = ctx.accounts.message.nonce;
+    require!(
+        ctx.accounts.nonce_bitmap.is_consumed(nonce),
+        BridgeError::MessageNotExecuted
+    );
+
+    emit_cpi!(IncomingMessageClosed {
+        incoming_message: ctx.accounts.message.key(),
+        nonce,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::solana_program::keccak::hash as keccak_hash;
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer as _;
+
+    use crate::{
+        accounts,
+        base_to_solana::{
+            constants::INCOMING_MESSAGE_SEED, state::OutputRoot, Message as BridgeMessage,
+        },
+        instruction::{
+            CloseIncomingMessage as CloseIncomingMessageIx, ProveMessage as ProveMessageIx,
+            RelayMessage as RelayMessageIx,
+        },
+        test_utils::{
+            cpi_policy_pda, event_authority_pda, find_cpi_event, send, setup_bridge,
+            write_output_root, SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const NONCE: u64 = 5;
+    const SENDER: [u8; 20] = [7u8; 20];
+
+    struct ProvenMessage {
+        message_hash: [u8; 32],
+        incoming_pda: Pubkey,
+        nonce_bitmap_pda: Pubkey,
+        output_root_pk: Pubkey,
+        data: Vec<u8>,
+    }
+
+    /// Registers a single-leaf output root committing to an empty call message
+    fn setup_message(svm: &mut LiteSVM) -> ProvenMessage {
+        let data = BridgeMessage::Call(vec![]).try_to_vec().unwrap();
+
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(&NONCE.to_be_bytes());
+        preimage.extend_from_slice(&SENDER);
+        preimage.extend_from_slice(&data);
+        let message_hash = keccak_hash(&preimage).0;
+
+        let output_root_pk = Keypair::new().pubkey();
+        write_output_root(
+            svm,
+            output_root_pk,
+            &OutputRoot {
+                root: message_hash,
+                total_leaf_count: 1,
+                base_block_number: 0,
+                payer: Pubkey::default(),
+                registered_at: 0,
+                invalidated: false,
+            },
+        );
+
+        ProvenMessage {
+            message_hash,
+            incoming_pda: Pubkey::find_program_address(
+                &[INCOMING_MESSAGE_SEED, &message_hash],
+                &ID,
+            )
+            .0,
+            nonce_bitmap_pda: Pubkey::find_program_address(
+                &[NONCE_BITMAP_SEED, &NonceBitmap::page(NONCE).to_le_bytes()],
+                &ID,
+            )
+            .0,
+            output_root_pk,
+            data,
+        }
+    }
+
+    fn prove_ix(payer: &Keypair, bridge_pda: Pubkey, msg: &ProvenMessage) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::ProveMessage {
+                payer: payer.pubkey(),
+                output_root: msg.output_root_pk,
+                message: msg.incoming_pda,
+                bridge: bridge_pda,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: ProveMessageIx {
+                nonce: NONCE,
+                sender: SENDER,
+                data: msg.data.clone(),
+                proof: vec![],
+                message_hash: msg.message_hash,
+            }
+            .data(),
+        }
+    }
+
+    fn relay_ix(payer: &Keypair, bridge_pda: Pubkey, msg: &ProvenMessage) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::RelayMessage {
+                payer: payer.pubkey(),
+                message: msg.incoming_pda,
+                nonce_bitmap: msg.nonce_bitmap_pda,
+                bridge: bridge_pda,
+                rate_limit: None,
+                cpi_policy: cpi_policy_pda(),
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: RelayMessageIx { max_ixs: None }.data(),
+        }
+    }
+
+    fn close_ix(payer: Pubkey, msg: &ProvenMessage) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::CloseIncomingMessage {
+                message: msg.incoming_pda,
+                nonce_bitmap: msg.nonce_bitmap_pda,
+                payer,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: CloseIncomingMessageIx {}.data(),
+        }
+    }
+
+    #[test]
+    fn test_close_executed_message_refunds_payer_and_blocks_replay() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let msg = setup_message(&mut svm);
+
+        // Anyone can trigger the close; the rent goes to the prover
+        let closer = Keypair::new();
+        svm.airdrop(&closer.pubkey(), 1_000_000_000).unwrap();
+
+        send(&mut svm, &payer, prove_ix(&payer, bridge_pda, &msg)).expect("prove should succeed");
+        send(&mut svm, &payer, relay_ix(&payer, bridge_pda, &msg)).expect("relay should succeed");
+
+        let bitmap_account = svm.get_account(&msg.nonce_bitmap_pda).unwrap();
+        let bitmap = NonceBitmap::try_deserialize(&mut &bitmap_account.data[..]).unwrap();
+        assert!(bitmap.is_consumed(NONCE));
+
+        let rent = svm.get_account(&msg.incoming_pda).unwrap().lamports;
+        let payer_balance = svm.get_balance(&payer.pubkey()).unwrap();
+
+        let meta =
+            send(&mut svm, &closer, close_ix(payer.pubkey(), &msg)).expect("close should succeed");
+
+        let event = find_cpi_event::<IncomingMessageClosed>(&meta)
+            .expect("IncomingMessageClosed event should be emitted");
+        assert_eq!(event.incoming_message, msg.incoming_pda);
+        assert_eq!(event.nonce, NONCE);
+        assert_eq!(
+            svm.get_balance(&payer.pubkey()).unwrap(),
+            payer_balance + rent
+        );
+        assert!(svm
+            .get_account(&msg.incoming_pda)
+            .is_none_or(|a| a.lamports == 0));
+
+        // The message can be proven again, but never relayed a second time
+        send(&mut svm, &payer, prove_ix(&payer, bridge_pda, &msg)).expect("prove should succeed");
+        let result = send(&mut svm, &payer, relay_ix(&payer, bridge_pda, &msg));
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("AlreadyExecuted"),
+            "Expected AlreadyExecuted error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_close_unexecuted_message_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let msg = setup_message(&mut svm);
+
+        send(&mut svm, &payer, prove_ix(&payer, bridge_pda, &msg)).expect("prove should succeed");
+
+        let result = send(&mut svm, &payer, close_ix(payer.pubkey(), &msg));
+        assert!(result.is_err());
+        assert!(svm.get_account(&msg.incoming_pda).unwrap().lamports > 0);
+    }
+
+    #[test]
+    fn test_close_to_wrong_payer_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let msg = setup_message(&mut svm);
+
+        send(&mut svm, &payer, prove_ix(&payer, bridge_pda, &msg)).expect("prove should succeed");
+        send(&mut svm, &payer, relay_ix(&payer, bridge_pda, &msg)).expect("relay should succeed");
+
+        let result = send(&mut svm, &payer, close_ix(Pubkey::new_unique(), &msg));
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("IncorrectMessagePayer"),
+            "Expected IncorrectMessagePayer error, got: {}",
+            error_string
+        );
+    }
+}
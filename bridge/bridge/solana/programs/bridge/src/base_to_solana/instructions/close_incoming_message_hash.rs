@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{
+    constants::NONCE_BITMAP_SEED,
+    state::{IncomingMessageHash, NonceBitmap},
+};
+use crate::{BridgeError, IncomingMessageClosed};
+
+/// Accounts struct for the close_incoming_message_hash instruction that reclaims the rent of an
+/// executed message proven with `prove_message_hash`. Anyone can call it; the rent always goes
+/// back to the original payer.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseIncomingMessageHash<'info> {
+    /// The executed incoming message hash account being closed.
+    /// Its nonce must be consumed, whichever instruction executed the message.
+    #[account(
+        mut,
+        has_one = payer @ BridgeError::IncorrectMessagePayer,
+        close = payer
+    )]
+    pub message: Account<'info, IncomingMessageHash>,
+
+    /// The replay protection page covering the message nonce.
+    /// Checked to make sure closing the message cannot make it replayable.
+    #[account(
+        seeds = [NONCE_BITMAP_SEED, &NonceBitmap::page(message.nonce).to_le_bytes()],
+        bump
+    )]
+    pub nonce_bitmap: Account<'info, NonceBitmap>,
+
+    /// The account that paid for the incoming message hash account; receives the rent.
+    /// CHECK: Checked against `message.payer`.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn close_incoming_message_hash_handler(ctx: Context<CloseIncomingMessageHash>) -> Result<()> {
+    let nonce = ctx.accounts.message.nonce;
+    require!(
+        ctx.accounts.nonce_bitmap.is_consumed(nonce),
+        BridgeError::MessageNotExecuted
+    );
+
+    emit_cpi!(IncomingMessageClosed {
+        incoming_message: ctx.accounts.message.key(),
+        nonce,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::state::OutputRoot;
+use crate::common::{bridge::Bridge, BRIDGE_SEED};
+use crate::{BridgeError, OutputRootClosed};
+
+/// Accounts struct for the close_output_root instruction that reclaims the rent of an output root
+/// outside the retention horizon. Anyone can call it; the rent always goes back to the original payer.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseOutputRoot<'info> {
+    /// The output root account being closed.
+    /// - Must be older than the retention horizon and not the latest registered root
+    /// - Rent is returned to the payer recorded when the root was registered
+    #[account(
+        mut,
+        has_one = payer @ BridgeError::IncorrectOutputRootPayer,
+        close = payer
+    )]
+    pub output_root: Account<'info, OutputRoot>,
+
+    /// The main bridge state account holding the latest registered Base block number and the
+    /// retention horizon.
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The account that paid for the output root account; receives the rent.
+    /// CHECK: Checked against `output_root.payer`.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn close_output_root_handler(ctx: Context<CloseOutputRoot>) -> Result<()> {
+    let base_block_number = ctx.accounts.output_root.base_block_number;
+    require!(
+        ctx.accounts
+            .bridge
+            .protocol_config
+            .is_output_root_expired(base_block_number, ctx.accounts.bridge.base_block_number),
+        BridgeError::OutputRootRetained
+    );
+
+    emit_cpi!(OutputRootClosed {
+        output_root_account: ctx.accounts.output_root.key(),
+        base_block_number,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer as _;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::constants::OUTPUT_ROOT_SEED,
+        instruction::CloseOutputRoot as CloseOutputRootIx,
+        test_utils::{
+            assert_error, event_authority_pda, find_cpi_event, setup_bridge, write_output_root,
+            SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const RETENTION_BLOCKS: u64 = 600;
+    const LATEST_BLOCK_NUMBER: u64 = 3000;
+
+    fn output_root_pda(base_block_number: u64) -> Pubkey {
+        Pubkey::find_program_address(&[OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()], &ID).0
+    }
+
+    /// Sets the latest registered block number and retention horizon on the bridge
+    fn set_bridge_state(svm: &mut LiteSVM, bridge_pda: Pubkey, retention_blocks: u64) {
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge.base_block_number = LATEST_BLOCK_NUMBER;
+        bridge.protocol_config.output_root_retention_blocks = retention_blocks;
+        let mut data = Vec::new();
+        bridge.try_serialize(&mut data).unwrap();
+        bridge_account.data = data;
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+    }
+
+    fn setup_output_root(svm: &mut LiteSVM, base_block_number: u64, payer: Pubkey) -> Pubkey {
+        let pda = output_root_pda(base_block_number);
+        write_output_root(
+            svm,
+            pda,
+            &OutputRoot {
+                root: [1u8; 32],
+                total_leaf_count: 1,
+                base_block_number,
+                payer,
+                registered_at: 0,
+                invalidated: false,
+            },
+        );
+        pda
+    }
+
+    fn send_close(
+        svm: &mut LiteSVM,
+        signer: &Keypair,
+        bridge_pda: Pubkey,
+        output_root: Pubkey,
+        payer: Pubkey,
+    ) -> std::result::Result<
+        litesvm::types::TransactionMetadata,
+        Box<litesvm::types::FailedTransactionMetadata>,
+    > {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::CloseOutputRoot {
+                output_root,
+                bridge: bridge_pda,
+                payer,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: CloseOutputRootIx {}.data(),
+        };
+
+        svm.expire_blockhash();
+        let tx = Transaction::new(
+            &[signer],
+            Message::new(&[ix], Some(&signer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)
+    }
+
+    #[test]
+    fn test_close_expired_output_root_refunds_payer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        set_bridge_state(&mut svm, bridge_pda, RETENTION_BLOCKS);
+
+        let base_block_number = LATEST_BLOCK_NUMBER - RETENTION_BLOCKS;
+        let root_pda = setup_output_root(&mut svm, base_block_number, payer.pubkey());
+
+        // Anyone can trigger the close; the rent goes to the registrant
+        let closer = Keypair::new();
+        svm.airdrop(&closer.pubkey(), 1_000_000_000).unwrap();
+
+        let rent = svm.get_account(&root_pda).unwrap().lamports;
+        let payer_balance = svm.get_balance(&payer.pubkey()).unwrap();
+
+        let meta = send_close(&mut svm, &closer, bridge_pda, root_pda, payer.pubkey())
+            .expect("close should succeed");
+
+        let event = find_cpi_event::<OutputRootClosed>(&meta)
+            .expect("OutputRootClosed event should be emitted");
+        assert_eq!(event.output_root_account, root_pda);
+        assert_eq!(event.base_block_number, base_block_number);
+        assert_eq!(
+            svm.get_balance(&payer.pubkey()).unwrap(),
+            payer_balance + rent
+        );
+        assert!(svm.get_account(&root_pda).is_none_or(|a| a.lamports == 0));
+    }
+
+    #[test]
+    fn test_close_output_root_within_retention_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        set_bridge_state(&mut svm, bridge_pda, RETENTION_BLOCKS);
+
+        let root_pda = setup_output_root(
+            &mut svm,
+            LATEST_BLOCK_NUMBER - RETENTION_BLOCKS + 300,
+            payer.pubkey(),
+        );
+
+        let result = send_close(&mut svm, &payer, bridge_pda, root_pda, payer.pubkey());
+        assert_error(result, "OutputRootRetained");
+    }
+
+    #[test]
+    fn test_close_latest_output_root_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        // Even a single block of retention never allows closing the latest root
+        set_bridge_state(&mut svm, bridge_pda, 1);
+
+        let root_pda = setup_output_root(&mut svm, LATEST_BLOCK_NUMBER, payer.pubkey());
+
+        let result = send_close(&mut svm, &payer, bridge_pda, root_pda, payer.pubkey());
+        assert_error(result, "OutputRootRetained");
+    }
+
+    #[test]
+    fn test_close_output_root_fails_when_retention_disabled() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        set_bridge_state(&mut svm, bridge_pda, 0);
+
+        let root_pda = setup_output_root(&mut svm, 300, payer.pubkey());
+
+        let result = send_close(&mut svm, &payer, bridge_pda, root_pda, payer.pubkey());
+        assert_error(result, "OutputRootRetained");
+    }
+
+    #[test]
+    fn test_close_output_root_to_wrong_payer_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        set_bridge_state(&mut svm, bridge_pda, RETENTION_BLOCKS);
+
+        let root_pda = setup_output_root(&mut svm, 300, payer.pubkey());
+
+        let result = send_close(&mut svm, &payer, bridge_pda, root_pda, Pubkey::new_unique());
+        assert_error(result, "IncorrectOutputRootPayer");
+    }
+}
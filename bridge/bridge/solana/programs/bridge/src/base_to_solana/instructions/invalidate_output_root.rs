@@ -0,0 +1,485 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::state::OutputRoot;
+use crate::base_to_solana::{
+    output_root_invalidation_digests, recover_unique_evm_addresses, require_partner_approvals,
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED};
+use crate::{BridgeError, OutputRootInvalidated};
+
+/// Accounts struct for the invalidate_output_root instruction that lets the guardian reject an
+/// output root before it becomes provable.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InvalidateOutputRoot<'info> {
+    /// The output root being invalidated; must still be in its challenge window.
+    #[account(mut)]
+    pub output_root: Account<'info, OutputRoot>,
+
+    /// The main bridge state account holding the guardian and the challenge window duration.
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The guardian account authorized to invalidate output roots
+    pub guardian: Signer<'info>,
+}
+
+/// Accounts struct for the invalidate_output_root_with_partner_signatures instruction that lets a
+/// quorum of partner signers reject an output root before it becomes provable.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InvalidateOutputRootWithPartnerSignatures<'info> {
+    /// The output root being invalidated; must still be in its challenge window.
+    #[account(mut)]
+    pub output_root: Account<'info, OutputRoot>,
+
+    /// The main bridge state account holding the partner threshold and the challenge window duration.
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// Partner `Config` account (PDA with seed "signers") owned by partner program.
+    /// CHECK: This is validated in the handler.
+    pub partner_config: AccountInfo<'info>,
+}
+
+pub fn invalidate_output_root_handler(ctx: Context<InvalidateOutputRoot>) -> Result<()> {
+    invalidate(&mut ctx.accounts.output_root, &ctx.accounts.bridge)?;
+
+    emit_cpi!(OutputRootInvalidated {
+        output_root_account: ctx.accounts.output_root.key(),
+        base_block_number: ctx.accounts.output_root.base_block_number,
+        invalidated_by: ctx.accounts.guardian.key(),
+    });
+
+    Ok(())
+}
+
+pub fn invalidate_output_root_with_partner_signatures_handler(
+    ctx: Context<InvalidateOutputRootWithPartnerSignatures>,
+    signatures: Vec<[u8; 65]>,
+) -> Result<()> {
+    // A zero partner threshold must not let anyone invalidate roots
+    require!(
+        ctx.accounts.bridge.partner_oracle_config.required_threshold > 0,
+        BridgeError::InsufficientPartnerSignatures
+    );
+
+    let output_root = &ctx.accounts.output_root;
+    let message_hashes = output_root_invalidation_digests(
+        &ctx.accounts.bridge.protocol_config.attestation,
+        &output_root.root,
+        output_root.base_block_number,
+        output_root.total_leaf_count,
+    );
+    let unique_signers = recover_unique_evm_addresses(&signatures, &message_hashes)?;
+    require_partner_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.partner_config,
+        &unique_signers,
+    )?;
+
+    invalidate(&mut ctx.accounts.output_root, &ctx.accounts.bridge)?;
+
+    emit_cpi!(OutputRootInvalidated {
+        output_root_account: ctx.accounts.output_root.key(),
+        base_block_number: ctx.accounts.output_root.base_block_number,
+        invalidated_by: Pubkey::default(),
+    });
+
+    Ok(())
+}
+
+/// Marks the root as invalidated if it is still in its challenge window
+fn invalidate(output_root: &mut OutputRoot, bridge: &Bridge) -> Result<()> {
+    require!(!output_root.invalidated, BridgeError::InvalidatedOutputRoot);
+    require!(
+        output_root.in_challenge_window(
+            bridge.protocol_config.root_finality_seconds,
+            Clock::get()?.unix_timestamp,
+        ),
+        BridgeError::ChallengeWindowElapsed
+    );
+
+    output_root.invalidated = true;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::solana_program::keccak::hash as keccak_hash;
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use secp256k1::{Message as SecpMessage, Secp256k1, SecretKey};
+    use solana_account::Account as SvmAccount;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer as _;
+
+    use crate::{
+        accounts,
+        base_to_solana::{
+            compute_output_root_invalidation_hash,
+            constants::{INCOMING_MESSAGE_SEED, PARTNER_PROGRAM_ID, PARTNER_SIGNERS_ACCOUNT_SEED},
+            state::{PartnerSigner, Signers},
+            Message as BridgeMessage,
+        },
+        instruction::{
+            InvalidateOutputRoot as InvalidateOutputRootIx,
+            InvalidateOutputRootWithPartnerSignatures as InvalidateOutputRootWithPartnerSignaturesIx,
+            ProveMessage as ProveMessageIx,
+        },
+        test_utils::{
+            assert_error, event_authority_pda, find_cpi_event, mock_clock, send, setup_bridge,
+            write_output_root, SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const FINALITY_SECONDS: u64 = 3600;
+    const REGISTERED_AT: i64 = 1_000_000;
+    const BASE_BLOCK_NUMBER: u64 = 600;
+    const NONCE: u64 = 0;
+    const SENDER: [u8; 20] = [7u8; 20];
+
+    struct TestRoot {
+        output_root_pk: Pubkey,
+        message_hash: [u8; 32],
+        data: Vec<u8>,
+    }
+
+    /// Enables the challenge window and registers a single-leaf output root at `REGISTERED_AT`
+    fn setup_root(svm: &mut LiteSVM, bridge_pda: Pubkey) -> TestRoot {
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge.protocol_config.root_finality_seconds = FINALITY_SECONDS;
+        let mut bridge_data = Vec::new();
+        bridge.try_serialize(&mut bridge_data).unwrap();
+        bridge_account.data = bridge_data;
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+
+        let data = BridgeMessage::Call(vec![]).try_to_vec().unwrap();
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(&NONCE.to_be_bytes());
+        preimage.extend_from_slice(&SENDER);
+        preimage.extend_from_slice(&data);
+        let message_hash = keccak_hash(&preimage).0;
+
+        let output_root_pk = Keypair::new().pubkey();
+        write_output_root(
+            svm,
+            output_root_pk,
+            &OutputRoot {
+                root: message_hash,
+                total_leaf_count: 1,
+                base_block_number: BASE_BLOCK_NUMBER,
+                payer: Pubkey::default(),
+                registered_at: REGISTERED_AT,
+                invalidated: false,
+            },
+        );
+
+        TestRoot {
+            output_root_pk,
+            message_hash,
+            data,
+        }
+    }
+
+    fn read_root(svm: &LiteSVM, root: &TestRoot) -> OutputRoot {
+        let account = svm.get_account(&root.output_root_pk).unwrap();
+        OutputRoot::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    fn invalidate_ix(guardian: Pubkey, bridge_pda: Pubkey, root: &TestRoot) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::InvalidateOutputRoot {
+                output_root: root.output_root_pk,
+                bridge: bridge_pda,
+                guardian,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: InvalidateOutputRootIx {}.data(),
+        }
+    }
+
+    fn invalidate_with_signatures_ix(
+        bridge_pda: Pubkey,
+        root: &TestRoot,
+        signatures: Vec<[u8; 65]>,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::InvalidateOutputRootWithPartnerSignatures {
+                output_root: root.output_root_pk,
+                bridge: bridge_pda,
+                partner_config: Pubkey::find_program_address(
+                    &[PARTNER_SIGNERS_ACCOUNT_SEED],
+                    &PARTNER_PROGRAM_ID,
+                )
+                .0,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: InvalidateOutputRootWithPartnerSignaturesIx { signatures }.data(),
+        }
+    }
+
+    fn prove_ix(payer: &Keypair, bridge_pda: Pubkey, root: &TestRoot) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::ProveMessage {
+                payer: payer.pubkey(),
+                output_root: root.output_root_pk,
+                message: Pubkey::find_program_address(
+                    &[INCOMING_MESSAGE_SEED, &root.message_hash],
+                    &ID,
+                )
+                .0,
+                bridge: bridge_pda,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: ProveMessageIx {
+                nonce: NONCE,
+                sender: SENDER,
+                data: root.data.clone(),
+                proof: vec![],
+                message_hash: root.message_hash,
+            }
+            .data(),
+        }
+    }
+
+    /// Writes a partner signer set and threshold, returning the signers' secret keys
+    fn setup_partner_signers(
+        svm: &mut LiteSVM,
+        bridge_pda: Pubkey,
+        threshold: u8,
+    ) -> Vec<SecretKey> {
+        let secp = Secp256k1::new();
+        let keys: Vec<SecretKey> = (1..=3u8)
+            .map(|i| SecretKey::from_slice(&[i; 32]).unwrap())
+            .collect();
+        let signers = Signers {
+            signers: keys
+                .iter()
+                .map(|sk| {
+                    let pk = secp256k1::PublicKey::from_secret_key(&secp, sk);
+                    let hashed = keccak_hash(&pk.serialize_uncompressed()[1..]);
+                    let mut addr = [0u8; 20];
+                    addr.copy_from_slice(&hashed.to_bytes()[12..]);
+                    PartnerSigner::from_evm_address(addr)
+                })
+                .collect(),
+        };
+        let mut data = Vec::new();
+        signers.try_serialize(&mut data).unwrap();
+        svm.set_account(
+            Pubkey::find_program_address(&[PARTNER_SIGNERS_ACCOUNT_SEED], &PARTNER_PROGRAM_ID).0,
+            SvmAccount {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: PARTNER_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge.partner_oracle_config.required_threshold = threshold;
+        let mut bridge_data = Vec::new();
+        bridge.try_serialize(&mut bridge_data).unwrap();
+        bridge_account.data = bridge_data;
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+
+        keys
+    }
+
+    fn sign_invalidation(sk: &SecretKey, root: &OutputRoot) -> [u8; 65] {
+        let msg_hash = compute_output_root_invalidation_hash(
+            &root.root,
+            root.base_block_number,
+            root.total_leaf_count,
+        );
+        let secp = Secp256k1::new();
+        let msg = SecpMessage::from_digest_slice(&msg_hash).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, sk).serialize_compact();
+        let mut sig65 = [0u8; 65];
+        sig65[..64].copy_from_slice(&sig_bytes64);
+        sig65[64] = 27 + rec_id.to_i32() as u8;
+        sig65
+    }
+
+    #[test]
+    fn test_prove_fails_during_challenge_window_and_succeeds_after() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let root = setup_root(&mut svm, bridge_pda);
+
+        mock_clock(&mut svm, REGISTERED_AT + FINALITY_SECONDS as i64 - 1);
+        let result = send(&mut svm, &payer, prove_ix(&payer, bridge_pda, &root));
+        assert_error(result, "OutputRootNotFinalized");
+
+        mock_clock(&mut svm, REGISTERED_AT + FINALITY_SECONDS as i64);
+        send(&mut svm, &payer, prove_ix(&payer, bridge_pda, &root))
+            .expect("prove should succeed once the root is final");
+    }
+
+    #[test]
+    fn test_guardian_invalidates_root_during_challenge_window() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+        let root = setup_root(&mut svm, bridge_pda);
+
+        mock_clock(&mut svm, REGISTERED_AT + 1);
+        let meta = send(
+            &mut svm,
+            &guardian,
+            invalidate_ix(guardian.pubkey(), bridge_pda, &root),
+        )
+        .expect("invalidation should succeed");
+
+        let event = find_cpi_event::<OutputRootInvalidated>(&meta)
+            .expect("OutputRootInvalidated event should be emitted");
+        assert_eq!(event.output_root_account, root.output_root_pk);
+        assert_eq!(event.base_block_number, BASE_BLOCK_NUMBER);
+        assert_eq!(event.invalidated_by, guardian.pubkey());
+        assert!(read_root(&svm, &root).invalidated);
+
+        // The root never becomes provable
+        mock_clock(&mut svm, REGISTERED_AT + FINALITY_SECONDS as i64);
+        let result = send(&mut svm, &payer, prove_ix(&payer, bridge_pda, &root));
+        assert_error(result, "InvalidatedOutputRoot");
+    }
+
+    #[test]
+    fn test_invalidate_with_non_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let root = setup_root(&mut svm, bridge_pda);
+
+        mock_clock(&mut svm, REGISTERED_AT + 1);
+        let result = send(
+            &mut svm,
+            &payer,
+            invalidate_ix(payer.pubkey(), bridge_pda, &root),
+        );
+        assert_error(result, "UnauthorizedConfigUpdate");
+    }
+
+    #[test]
+    fn test_invalidate_after_challenge_window_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let root = setup_root(&mut svm, bridge_pda);
+
+        mock_clock(&mut svm, REGISTERED_AT + FINALITY_SECONDS as i64);
+        let result = send(
+            &mut svm,
+            &guardian,
+            invalidate_ix(guardian.pubkey(), bridge_pda, &root),
+        );
+        assert_error(result, "ChallengeWindowElapsed");
+    }
+
+    #[test]
+    fn test_partner_quorum_invalidates_root() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let root = setup_root(&mut svm, bridge_pda);
+        let keys = setup_partner_signers(&mut svm, bridge_pda, 2);
+        let output_root = read_root(&svm, &root);
+
+        mock_clock(&mut svm, REGISTERED_AT + 1);
+
+        // A single partner signature is below the quorum
+        let result = send(
+            &mut svm,
+            &payer,
+            invalidate_with_signatures_ix(
+                bridge_pda,
+                &root,
+                vec![sign_invalidation(&keys[0], &output_root)],
+            ),
+        );
+        assert_error(result, "InsufficientPartnerSignatures");
+
+        let meta = send(
+            &mut svm,
+            &payer,
+            invalidate_with_signatures_ix(
+                bridge_pda,
+                &root,
+                vec![
+                    sign_invalidation(&keys[0], &output_root),
+                    sign_invalidation(&keys[2], &output_root),
+                ],
+            ),
+        )
+        .expect("invalidation with a partner quorum should succeed");
+
+        let event = find_cpi_event::<OutputRootInvalidated>(&meta)
+            .expect("OutputRootInvalidated event should be emitted");
+        assert_eq!(event.invalidated_by, Pubkey::default());
+        assert!(read_root(&svm, &root).invalidated);
+    }
+
+    #[test]
+    fn test_partner_invalidation_fails_without_partner_threshold() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let root = setup_root(&mut svm, bridge_pda);
+        setup_partner_signers(&mut svm, bridge_pda, 0);
+
+        mock_clock(&mut svm, REGISTERED_AT + 1);
+        let result = send(
+            &mut svm,
+            &payer,
+            invalidate_with_signatures_ix(bridge_pda, &root, vec![]),
+        );
+        assert_error(result, "InsufficientPartnerSignatures");
+    }
+}
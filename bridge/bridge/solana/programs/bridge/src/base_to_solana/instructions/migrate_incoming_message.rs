@@ -0,0 +1,364 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+    Discriminator,
+};
+
+use crate::base_to_solana::{
+    constants::{INCOMING_MESSAGE_SEED, NONCE_BITMAP_SEED},
+    hash_message,
+    state::{IncomingMessage, NonceBitmap},
+    Message,
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN};
+use crate::BridgeError;
+
+/// Layout of `IncomingMessage` before the nonce, message hash, payer, execution cursor and proof
+/// timestamp were recorded. Such accounts were allocated with `20 + (4 + data_len) + 1` bytes, so
+/// the serialized fields are followed by 4 unused bytes.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyIncomingMessage {
+    pub sender: [u8; 20],
+    pub message: Message,
+    pub executed: bool,
+}
+
+impl LegacyIncomingMessage {
+    /// Returns the byte size the legacy `prove_message` allocated for a payload of `data_len` bytes,
+    /// excluding the discriminator.
+    pub fn space(data_len: usize) -> usize {
+        20 + (4 + data_len) + 1
+    }
+
+    /// Returns whether `account` is an incoming message still serialized with the legacy layout,
+    /// i.e. whose contents decode as the legacy layout and fill exactly the size it allocated.
+    pub fn is_legacy(account: &AccountInfo) -> Result<bool> {
+        if account.owner != &crate::ID {
+            return Ok(false);
+        }
+        let data = account.try_borrow_data()?;
+        if !data.starts_with(IncomingMessage::DISCRIMINATOR) {
+            return Ok(false);
+        }
+        let Ok(legacy) = Self::deserialize(&mut &data[DISCRIMINATOR_LEN..]) else {
+            return Ok(false);
+        };
+        Ok(data.len() == DISCRIMINATOR_LEN + Self::space(legacy.message.try_to_vec()?.len()))
+    }
+}
+
+/// Accounts struct for the migrate_incoming_message instruction that extends an incoming message
+/// proven with the legacy layout to the current `IncomingMessage` layout.
+/// Only the guardian can migrate messages, as the legacy layout does not record who paid for the
+/// account and the guardian provides the address its rent is refunded to.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct MigrateIncomingMessage<'info> {
+    /// The guardian account; funds the rent of the extended account and of the nonce bitmap page if
+    /// it does not exist yet. The rent of the whole account goes to the recorded original payer once
+    /// the message is closed or bounced.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The incoming message account, still serialized with the legacy layout.
+    /// CHECK: Deserialized manually in the handler, which also checks its address against the
+    /// message hash derived from `nonce` and the legacy contents.
+    #[account(mut, owner = crate::ID)]
+    pub message: UncheckedAccount<'info>,
+
+    /// The replay protection page covering the message nonce.
+    /// Marked when migrating a message the legacy layout recorded as executed.
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        space = DISCRIMINATOR_LEN + NonceBitmap::INIT_SPACE,
+        seeds = [NONCE_BITMAP_SEED, &NonceBitmap::page(nonce).to_le_bytes()],
+        bump
+    )]
+    pub nonce_bitmap: Account<'info, NonceBitmap>,
+
+    /// System program required for transferring the additional rent and creating the bitmap page.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_incoming_message_handler(
+    ctx: Context<MigrateIncomingMessage>,
+    nonce: u64,
+    original_payer: Pubkey,
+) -> Result<()> {
+    let message_info = ctx.accounts.message.to_account_info();
+
+    let legacy = {
+        let data = message_info.try_borrow_data()?;
+        require!(
+            data.starts_with(IncomingMessage::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        LegacyIncomingMessage::deserialize(&mut &data[DISCRIMINATOR_LEN..])
+            .map_err(|_| BridgeError::IncomingMessageAlreadyMigrated)?
+    };
+    let data = legacy.message.try_to_vec()?;
+    require_eq!(
+        message_info.data_len(),
+        DISCRIMINATOR_LEN + LegacyIncomingMessage::space(data.len()),
+        BridgeError::IncomingMessageAlreadyMigrated
+    );
+
+    // The legacy layout does not record the nonce, so the provided one is checked through the
+    // message hash that seeds the account address
+    let message_hash = hash_message(&nonce.to_be_bytes(), &legacy.sender, &data);
+    let (expected_address, _) =
+        Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &message_hash], ctx.program_id);
+    require_keys_eq!(
+        message_info.key(),
+        expected_address,
+        ErrorCode::ConstraintSeeds
+    );
+
+    // Legacy messages relied on their `executed` flag for replay protection
+    if legacy.executed && !ctx.accounts.nonce_bitmap.is_consumed(nonce) {
+        ctx.accounts.nonce_bitmap.consume(nonce)?;
+    }
+
+    // Top up the rent for the larger account
+    let new_len = DISCRIMINATOR_LEN + IncomingMessage::space(data.len());
+    let rent_delta = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(message_info.lamports());
+    if rent_delta > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.guardian.to_account_info(),
+                to: message_info.clone(),
+            },
+        );
+        system_program::transfer(cpi_ctx, rent_delta)?;
+    }
+
+    message_info.realloc(new_len, true)?;
+    IncomingMessage {
+        sender: legacy.sender,
+        nonce,
+        message_hash,
+        payer: original_payer,
+        message: legacy.message,
+        cursor: None,
+        // The legacy proof time is unknown, so the bounce delay starts over from the migration
+        proven_at: Clock::get()?.unix_timestamp,
+        executed: legacy.executed,
+    }
+    .try_serialize(&mut &mut message_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer as _;
+
+    use crate::{
+        accounts,
+        instruction::{
+            MigrateIncomingMessage as MigrateIncomingMessageIx, RelayMessage as RelayMessageIx,
+        },
+        test_utils::{
+            assert_error, cpi_policy_pda, event_authority_pda, send, setup_bridge,
+            SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const NONCE: u64 = 3;
+    const SENDER: [u8; 20] = [5u8; 20];
+    const ORIGINAL_PAYER: Pubkey = Pubkey::new_from_array([9u8; 32]);
+
+    fn nonce_bitmap_pda(nonce: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[NONCE_BITMAP_SEED, &NonceBitmap::page(nonce).to_le_bytes()],
+            &ID,
+        )
+        .0
+    }
+
+    /// Writes an incoming message the way the legacy `prove_message` allocated and serialized it
+    fn write_legacy_incoming_message(svm: &mut LiteSVM, executed: bool) -> Pubkey {
+        let message = Message::Call(vec![]);
+        let data = message.try_to_vec().unwrap();
+        let message_hash = hash_message(&NONCE.to_be_bytes(), &SENDER, &data);
+        let address = Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &message_hash], &ID).0;
+
+        let mut account_data =
+            vec![0u8; DISCRIMINATOR_LEN + LegacyIncomingMessage::space(data.len())];
+        account_data[..DISCRIMINATOR_LEN].copy_from_slice(IncomingMessage::DISCRIMINATOR);
+        LegacyIncomingMessage {
+            sender: SENDER,
+            message,
+            executed,
+        }
+        .serialize(&mut &mut account_data[DISCRIMINATOR_LEN..])
+        .unwrap();
+
+        svm.set_account(
+            address,
+            SvmAccount {
+                lamports: svm.minimum_balance_for_rent_exemption(account_data.len()),
+                data: account_data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+        address
+    }
+
+    fn migrate_ix(guardian: &Keypair, message: Pubkey, nonce: u64) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::MigrateIncomingMessage {
+                guardian: guardian.pubkey(),
+                bridge: Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0,
+                message,
+                nonce_bitmap: nonce_bitmap_pda(nonce),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: MigrateIncomingMessageIx {
+                nonce,
+                original_payer: ORIGINAL_PAYER,
+            }
+            .data(),
+        }
+    }
+
+    fn read_incoming_message(svm: &LiteSVM, message: &Pubkey) -> IncomingMessage {
+        let account = svm.get_account(message).unwrap();
+        IncomingMessage::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    fn read_nonce_bitmap(svm: &LiteSVM) -> NonceBitmap {
+        let account = svm.get_account(&nonce_bitmap_pda(NONCE)).unwrap();
+        NonceBitmap::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_migrate_legacy_incoming_message_then_relay() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+        let message = write_legacy_incoming_message(&mut svm, false);
+
+        send(&mut svm, &guardian, migrate_ix(&guardian, message, NONCE))
+            .expect("legacy incoming message should migrate");
+
+        let account = svm.get_account(&message).unwrap();
+        assert_eq!(
+            account.data.len(),
+            DISCRIMINATOR_LEN
+                + IncomingMessage::space(Message::Call(vec![]).try_to_vec().unwrap().len())
+        );
+        assert_eq!(
+            account.lamports,
+            svm.minimum_balance_for_rent_exemption(account.data.len())
+        );
+        let migrated = read_incoming_message(&svm, &message);
+        assert_eq!(migrated.sender, SENDER);
+        assert_eq!(migrated.nonce, NONCE);
+        // The rent goes back to the original payer, not to the guardian migrating the message
+        assert_eq!(migrated.payer, ORIGINAL_PAYER);
+        assert!(migrated.cursor.is_none());
+        assert!(!migrated.executed);
+        assert!(!read_nonce_bitmap(&svm).is_consumed(NONCE));
+
+        // The migrated message relays like one proven with the current layout
+        let relay_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RelayMessage {
+                payer: payer.pubkey(),
+                message,
+                nonce_bitmap: nonce_bitmap_pda(NONCE),
+                bridge: bridge_pda,
+                rate_limit: None,
+                cpi_policy: cpi_policy_pda(),
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: RelayMessageIx { max_ixs: None }.data(),
+        };
+        send(&mut svm, &payer, relay_ix).expect("migrated message should relay");
+
+        assert!(read_incoming_message(&svm, &message).executed);
+        assert!(read_nonce_bitmap(&svm).is_consumed(NONCE));
+    }
+
+    #[test]
+    fn test_migrate_executed_legacy_incoming_message_consumes_nonce() {
+        let SetupBridgeResult {
+            mut svm, guardian, ..
+        } = setup_bridge();
+        let message = write_legacy_incoming_message(&mut svm, true);
+
+        send(&mut svm, &guardian, migrate_ix(&guardian, message, NONCE))
+            .expect("executed legacy incoming message should migrate");
+
+        assert!(read_incoming_message(&svm, &message).executed);
+        assert!(read_nonce_bitmap(&svm).is_consumed(NONCE));
+    }
+
+    #[test]
+    fn test_migrate_incoming_message_with_wrong_nonce_fails() {
+        let SetupBridgeResult {
+            mut svm, guardian, ..
+        } = setup_bridge();
+        let message = write_legacy_incoming_message(&mut svm, false);
+
+        let result = send(
+            &mut svm,
+            &guardian,
+            migrate_ix(&guardian, message, NONCE + 1),
+        );
+        assert_error(result, "ConstraintSeeds");
+    }
+
+    #[test]
+    fn test_migrate_incoming_message_twice_fails() {
+        let SetupBridgeResult {
+            mut svm, guardian, ..
+        } = setup_bridge();
+        let message = write_legacy_incoming_message(&mut svm, false);
+
+        send(&mut svm, &guardian, migrate_ix(&guardian, message, NONCE)).unwrap();
+
+        let result = send(&mut svm, &guardian, migrate_ix(&guardian, message, NONCE));
+        assert_error(result, "IncomingMessageAlreadyMigrated");
+    }
+
+    #[test]
+    fn test_migrate_incoming_message_requires_guardian() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+        let message = write_legacy_incoming_message(&mut svm, false);
+
+        let result = send(&mut svm, &payer, migrate_ix(&payer, message, NONCE));
+        assert_error(result, "UnauthorizedConfigUpdate");
+    }
+}
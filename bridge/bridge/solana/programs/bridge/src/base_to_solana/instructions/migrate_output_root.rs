@@ -0,0 +1,224 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+    Discriminator,
+};
+
+use crate::base_to_solana::{constants::OUTPUT_ROOT_SEED, state::OutputRoot};
+use crate::common::DISCRIMINATOR_LEN;
+use crate::BridgeError;
+
+/// Layout of `OutputRoot` before the Base block number, payer, registration timestamp and
+/// invalidation flag were recorded.
+#[derive(AnchorDeserialize, AnchorSerialize, InitSpace)]
+pub struct LegacyOutputRoot {
+    pub root: [u8; 32],
+    pub total_leaf_count: u64,
+}
+
+/// Accounts struct for the migrate_output_root instruction that extends an output root registered
+/// with the legacy layout to the current `OutputRoot` layout. Anyone can call it.
+#[derive(Accounts)]
+#[instruction(base_block_number: u64)]
+pub struct MigrateOutputRoot<'info> {
+    /// Funds the rent of the extended account. Recorded as the payer of the migrated root, so it
+    /// receives the rent once the root is closed.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The output root account, still serialized with the legacy layout.
+    /// CHECK: Deserialized manually in the handler as it does not match the current `OutputRoot` layout.
+    #[account(
+        mut,
+        seeds = [OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()],
+        bump,
+        owner = crate::ID
+    )]
+    pub output_root: UncheckedAccount<'info>,
+
+    /// System program required for transferring the additional rent.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_output_root_handler(
+    ctx: Context<MigrateOutputRoot>,
+    base_block_number: u64,
+) -> Result<()> {
+    let output_root_info = ctx.accounts.output_root.to_account_info();
+    require_eq!(
+        output_root_info.data_len(),
+        DISCRIMINATOR_LEN + LegacyOutputRoot::INIT_SPACE,
+        BridgeError::OutputRootAlreadyMigrated
+    );
+
+    let legacy = {
+        let data = output_root_info.try_borrow_data()?;
+        require!(
+            data.starts_with(OutputRoot::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        LegacyOutputRoot::deserialize(&mut &data[DISCRIMINATOR_LEN..])?
+    };
+
+    // Top up the rent for the larger account
+    let new_len = DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE;
+    let rent_delta = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(output_root_info.lamports());
+    if rent_delta > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: output_root_info.clone(),
+            },
+        );
+        system_program::transfer(cpi_ctx, rent_delta)?;
+    }
+
+    output_root_info.realloc(new_len, true)?;
+    OutputRoot {
+        root: legacy.root,
+        total_leaf_count: legacy.total_leaf_count,
+        base_block_number,
+        payer: ctx.accounts.payer.key(),
+        // Legacy roots were provable as soon as they were registered, so a migrated root is
+        // treated as registered at the Unix epoch and is already past any challenge window
+        registered_at: 0,
+        invalidated: false,
+    }
+    .try_serialize(&mut &mut output_root_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer as _;
+
+    use crate::{
+        accounts,
+        instruction::MigrateOutputRoot as MigrateOutputRootIx,
+        test_utils::{assert_error, send, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const BASE_BLOCK_NUMBER: u64 = 300;
+    const ROOT: [u8; 32] = [4u8; 32];
+    const TOTAL_LEAF_COUNT: u64 = 12;
+
+    fn output_root_pda(base_block_number: u64) -> Pubkey {
+        Pubkey::find_program_address(&[OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()], &ID).0
+    }
+
+    /// Writes an output root the way the legacy `register_output_root` allocated and serialized it
+    fn write_legacy_output_root(svm: &mut LiteSVM) -> Pubkey {
+        let mut data = OutputRoot::DISCRIMINATOR.to_vec();
+        LegacyOutputRoot {
+            root: ROOT,
+            total_leaf_count: TOTAL_LEAF_COUNT,
+        }
+        .serialize(&mut data)
+        .unwrap();
+
+        let address = output_root_pda(BASE_BLOCK_NUMBER);
+        svm.set_account(
+            address,
+            SvmAccount {
+                lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+        address
+    }
+
+    fn migrate_ix(payer: &Keypair, base_block_number: u64, output_root: Pubkey) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::MigrateOutputRoot {
+                payer: payer.pubkey(),
+                output_root,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: MigrateOutputRootIx { base_block_number }.data(),
+        }
+    }
+
+    #[test]
+    fn test_migrate_legacy_output_root() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+        let output_root = write_legacy_output_root(&mut svm);
+
+        send(
+            &mut svm,
+            &payer,
+            migrate_ix(&payer, BASE_BLOCK_NUMBER, output_root),
+        )
+        .expect("legacy output root should migrate");
+
+        let account = svm.get_account(&output_root).unwrap();
+        assert_eq!(
+            account.data.len(),
+            DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE
+        );
+        assert_eq!(
+            account.lamports,
+            svm.minimum_balance_for_rent_exemption(account.data.len())
+        );
+
+        let migrated = OutputRoot::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(migrated.root, ROOT);
+        assert_eq!(migrated.total_leaf_count, TOTAL_LEAF_COUNT);
+        assert_eq!(migrated.base_block_number, BASE_BLOCK_NUMBER);
+        assert_eq!(migrated.payer, payer.pubkey());
+        assert_eq!(migrated.registered_at, 0);
+        assert!(!migrated.invalidated);
+
+        // A migrated root is final whatever the challenge window
+        assert!(migrated.require_provable(3_600, 1_700_000_000).is_ok());
+    }
+
+    #[test]
+    fn test_migrate_output_root_twice_fails() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+        let output_root = write_legacy_output_root(&mut svm);
+
+        send(
+            &mut svm,
+            &payer,
+            migrate_ix(&payer, BASE_BLOCK_NUMBER, output_root),
+        )
+        .unwrap();
+
+        let result = send(
+            &mut svm,
+            &payer,
+            migrate_ix(&payer, BASE_BLOCK_NUMBER, output_root),
+        );
+        assert_error(result, "OutputRootAlreadyMigrated");
+    }
+
+    #[test]
+    fn test_migrate_output_root_with_wrong_block_number_fails() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+        let output_root = write_legacy_output_root(&mut svm);
+
+        let result = send(
+            &mut svm,
+            &payer,
+            migrate_ix(&payer, BASE_BLOCK_NUMBER + 1, output_root),
+        );
+        assert_error(result, "ConstraintSeeds");
+    }
+}
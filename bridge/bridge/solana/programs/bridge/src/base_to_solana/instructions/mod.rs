@@ -1,10 +1,38 @@
+pub mod bounce_message;
+pub mod bounce_message_with_payload;
 pub mod buffered;
+pub mod close_incoming_message;
+pub mod close_incoming_message_hash;
+pub mod close_output_root;
+pub mod invalidate_output_root;
+pub mod migrate_incoming_message;
+pub mod migrate_output_root;
+pub mod pending_output_root;
+pub mod prove_and_relay_message;
 pub mod prove_message;
+pub mod prove_message_hash;
+pub mod prove_messages;
 pub mod register_output_root;
 pub mod relay_message;
+pub mod relay_message_with_payload;
+pub mod revoke_output_root;
 pub mod token;
 
+pub use bounce_message::*;
+pub use bounce_message_with_payload::*;
 pub use buffered::*;
+pub use close_incoming_message::*;
+pub use close_incoming_message_hash::*;
+pub use close_output_root::*;
+pub use invalidate_output_root::*;
+pub use migrate_incoming_message::*;
+pub use migrate_output_root::*;
+pub use pending_output_root::*;
+pub use prove_and_relay_message::*;
 pub use prove_message::*;
+pub use prove_message_hash::*;
+pub use prove_messages::*;
 pub use register_output_root::*;
 pub use relay_message::*;
+pub use relay_message_with_payload::*;
+pub use revoke_output_root::*;
@@ -0,0 +1,673 @@
+use anchor_lang::{prelude::*, solana_program::sysvar};
+
+use crate::base_to_solana::constants::{
+    BASE_ORACLE_SIGNERS_SEED, OUTPUT_ROOT_SEED, PENDING_OUTPUT_ROOT_SEED,
+    PENDING_OUTPUT_ROOT_TTL_SECONDS,
+};
+use crate::base_to_solana::state::{
+    BaseOracleSigners, OracleSignerKey, OutputRoot, PendingOutputRoot,
+};
+use crate::base_to_solana::{
+    collect_oracle_signers, load_partner_signers, output_root_attestation_digests,
+    record_output_root, require_oracle_approvals,
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_REGISTER_OUTPUT_ROOT};
+use crate::{
+    BridgeError, OutputRootRegistered, OutputRootSignaturesAdded, PendingOutputRootClosed,
+};
+
+/// Accounts struct for the `add_output_root_signatures` instruction that collects oracle
+/// attestations for an output root across several transactions. Anyone can submit signatures;
+/// only those from Base oracle or partner signers are kept.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(output_root: [u8; 32], base_block_number: u64, total_leaf_count: u64)]
+pub struct AddOutputRootSignatures<'info> {
+    /// Payer funds the pending account creation on the first submission.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The pending output root collecting the attestations.
+    /// - Uses PDA with PENDING_OUTPUT_ROOT_SEED, base_block_number, output_root and
+    ///   total_leaf_count so that competing candidates for a block never share an account
+    /// - Created on the first submission, sized for the largest possible signer set
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PendingOutputRoot::SPACE,
+        seeds = [
+            PENDING_OUTPUT_ROOT_SEED,
+            &base_block_number.to_le_bytes(),
+            &output_root,
+            &total_leaf_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub pending_output_root: Account<'info, PendingOutputRoot>,
+
+    /// The main bridge state account holding the latest registered Base block number.
+    /// - Uses PDA with BRIDGE_SEED
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The Base oracle signer epoch covering `base_block_number`.
+    #[account(
+        seeds = [BASE_ORACLE_SIGNERS_SEED, &base_oracle_signers.epoch.to_le_bytes()],
+        bump
+    )]
+    pub base_oracle_signers: Account<'info, BaseOracleSigners>,
+
+    /// Partner `Config` account (PDA with seed "config") owned by partner program.
+    /// Only read when a partner threshold is configured.
+    /// CHECK: This is validated in the handler.
+    pub partner_config: AccountInfo<'info>,
+
+    /// Instructions sysvar used to find Secp256k1 and Ed25519 program instructions attesting to
+    /// the root.
+    /// CHECK: Address is checked against the instructions sysvar id.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+
+    /// System program required for creating the pending account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for the `finalize_output_root` instruction that registers a pending output
+/// root once its collected attestations meet both the Base oracle and partner thresholds.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeOutputRoot<'info> {
+    /// Payer funds the output root account creation.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The pending output root holding the collected attestations; closed once finalized.
+    #[account(
+        mut,
+        close = pending_payer,
+        seeds = [
+            PENDING_OUTPUT_ROOT_SEED,
+            &pending_output_root.base_block_number.to_le_bytes(),
+            &pending_output_root.output_root,
+            &pending_output_root.total_leaf_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub pending_output_root: Account<'info, PendingOutputRoot>,
+
+    /// The account that paid for the pending output root; receives its rent.
+    /// CHECK: Checked against `pending_output_root.payer`.
+    #[account(
+        mut,
+        address = pending_output_root.payer @ BridgeError::IncorrectPendingOutputRootPayer
+    )]
+    pub pending_payer: UncheckedAccount<'info>,
+
+    /// The output root account being created for the checkpoint.
+    /// - An existing account can only be reused if its root was invalidated or revoked
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE,
+        seeds = [OUTPUT_ROOT_SEED, &pending_output_root.base_block_number.to_le_bytes()],
+        bump
+    )]
+    pub root: Account<'info, OutputRoot>,
+
+    /// The main bridge state account that tracks the latest registered Base block number.
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The Base oracle signer epoch covering the pending root's block number.
+    #[account(
+        seeds = [BASE_ORACLE_SIGNERS_SEED, &base_oracle_signers.epoch.to_le_bytes()],
+        bump
+    )]
+    pub base_oracle_signers: Account<'info, BaseOracleSigners>,
+
+    /// Partner `Config` account (PDA with seed "config") owned by partner program.
+    /// CHECK: This is validated in the handler.
+    pub partner_config: AccountInfo<'info>,
+
+    /// System program required for creating the output root account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for the `close_pending_output_root` instruction that reclaims the rent of a
+/// pending output root that expired before being finalized. Anyone can call it.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClosePendingOutputRoot<'info> {
+    /// The expired pending output root being closed.
+    #[account(
+        mut,
+        has_one = payer @ BridgeError::IncorrectPendingOutputRootPayer,
+        close = payer
+    )]
+    pub pending_output_root: Account<'info, PendingOutputRoot>,
+
+    /// The account that paid for the pending output root; receives the rent.
+    /// CHECK: Checked against `pending_output_root.payer`.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+#[allow(clippy::manual_is_multiple_of)]
+pub fn add_output_root_signatures_handler(
+    ctx: Context<AddOutputRootSignatures>,
+    output_root: [u8; 32],
+    base_block_number: u64,
+    total_leaf_count: u64,
+    signatures: Vec<[u8; 65]>,
+) -> Result<()> {
+    let bridge = &ctx.accounts.bridge;
+    bridge
+        .pause
+        .require_not_paused(PAUSE_REGISTER_OUTPUT_ROOT)?;
+
+    require!(
+        ctx.accounts.base_oracle_signers.covers(base_block_number),
+        BridgeError::BaseOracleEpochMismatch
+    );
+    require!(
+        base_block_number > bridge.base_block_number
+            && base_block_number % bridge.protocol_config.block_interval_requirement == 0,
+        BridgeError::IncorrectBlockNumber
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let pending = &mut ctx.accounts.pending_output_root;
+    if pending.payer == Pubkey::default() {
+        pending.output_root = output_root;
+        pending.base_block_number = base_block_number;
+        pending.total_leaf_count = total_leaf_count;
+        pending.payer = ctx.accounts.payer.key();
+        pending.expires_at = now.saturating_add(PENDING_OUTPUT_ROOT_TTL_SECONDS);
+    }
+    require!(
+        !pending.is_expired(now),
+        BridgeError::PendingOutputRootExpired
+    );
+
+    let message_hashes = output_root_attestation_digests(
+        &bridge.protocol_config.attestation,
+        &output_root,
+        base_block_number,
+        total_leaf_count,
+    );
+    let (evm_signers, ed25519_signers) = collect_oracle_signers(
+        &signatures,
+        &message_hashes,
+        ctx.accounts.instructions.as_deref(),
+    )?;
+
+    // Recovering against a digest that was not signed yields unrelated addresses, so only keep
+    // signers that can count toward one of the thresholds
+    let partner_signers = if bridge.partner_oracle_config.required_threshold > 0 {
+        Some(load_partner_signers(&ctx.accounts.partner_config)?)
+    } else {
+        None
+    };
+    let base_oracle_signers = &ctx.accounts.base_oracle_signers;
+    for addr in evm_signers {
+        let is_partner = partner_signers.as_ref().is_some_and(|partner| {
+            partner.approved_weight(&[addr], &bridge.partner_oracle_config) > 0
+        });
+        let key = OracleSignerKey::Evm(addr);
+        if is_partner || base_oracle_signers.contains(&key) {
+            pending.add_signer(key)?;
+        }
+    }
+    for pubkey in ed25519_signers {
+        let key = OracleSignerKey::Ed25519(pubkey);
+        if base_oracle_signers.contains(&key) {
+            pending.add_signer(key)?;
+        }
+    }
+
+    emit_cpi!(OutputRootSignaturesAdded {
+        pending_output_root: pending.key(),
+        output_root,
+        base_block_number,
+        signer_count: pending.signers.len() as u16,
+    });
+
+    Ok(())
+}
+
+pub fn finalize_output_root_handler(ctx: Context<FinalizeOutputRoot>) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_REGISTER_OUTPUT_ROOT)?;
+
+    let pending = &ctx.accounts.pending_output_root;
+    require!(
+        !pending.is_expired(Clock::get()?.unix_timestamp),
+        BridgeError::PendingOutputRootExpired
+    );
+    require!(
+        ctx.accounts
+            .base_oracle_signers
+            .covers(pending.base_block_number),
+        BridgeError::BaseOracleEpochMismatch
+    );
+
+    require_oracle_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.base_oracle_signers,
+        &ctx.accounts.partner_config,
+        &pending.evm_signers(),
+        &pending.ed25519_signers(),
+    )?;
+
+    let (output_root, base_block_number, total_leaf_count) = (
+        pending.output_root,
+        pending.base_block_number,
+        pending.total_leaf_count,
+    );
+    record_output_root(
+        &mut ctx.accounts.root,
+        &mut ctx.accounts.bridge,
+        ctx.accounts.payer.key(),
+        output_root,
+        base_block_number,
+        total_leaf_count,
+    )?;
+
+    emit_cpi!(OutputRootRegistered {
+        output_root_account: ctx.accounts.root.key(),
+        output_root,
+        base_block_number,
+        total_leaf_count,
+    });
+
+    Ok(())
+}
+
+pub fn close_pending_output_root_handler(ctx: Context<ClosePendingOutputRoot>) -> Result<()> {
+    let pending = &ctx.accounts.pending_output_root;
+    require!(
+        pending.is_expired(Clock::get()?.unix_timestamp),
+        BridgeError::PendingOutputRootNotExpired
+    );
+
+    emit_cpi!(PendingOutputRootClosed {
+        pending_output_root: pending.key(),
+        base_block_number: pending.base_block_number,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use secp256k1::{Message as SecpMessage, Secp256k1, SecretKey};
+    use solana_account::Account as SvmAccount;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer as _;
+
+    use crate::{
+        accounts,
+        base_to_solana::{
+            compute_output_root_message_hash,
+            constants::{PARTNER_PROGRAM_ID, PARTNER_SIGNERS_ACCOUNT_SEED},
+            state::{PartnerSigner, Signers},
+        },
+        instruction::{
+            AddOutputRootSignatures as AddOutputRootSignaturesIx,
+            ClosePendingOutputRoot as ClosePendingOutputRootIx,
+            FinalizeOutputRoot as FinalizeOutputRootIx,
+        },
+        test_utils::{
+            assert_error, base_oracle_signers_pda, event_authority_pda, find_cpi_event, mock_clock,
+            send, set_base_oracle_signers, setup_bridge, SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const OUTPUT_ROOT: [u8; 32] = [17u8; 32];
+    const BASE_BLOCK_NUMBER: u64 = 300;
+    const TOTAL_LEAF_COUNT: u64 = 8;
+    const NOW: i64 = 1_000_000;
+
+    fn pending_output_root_pda() -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                PENDING_OUTPUT_ROOT_SEED,
+                &BASE_BLOCK_NUMBER.to_le_bytes(),
+                &OUTPUT_ROOT,
+                &TOTAL_LEAF_COUNT.to_le_bytes(),
+            ],
+            &ID,
+        )
+        .0
+    }
+
+    fn output_root_pda() -> Pubkey {
+        Pubkey::find_program_address(&[OUTPUT_ROOT_SEED, &BASE_BLOCK_NUMBER.to_le_bytes()], &ID).0
+    }
+
+    fn partner_config_pda() -> Pubkey {
+        Pubkey::find_program_address(&[PARTNER_SIGNERS_ACCOUNT_SEED], &PARTNER_PROGRAM_ID).0
+    }
+
+    /// Signs the legacy attestation of the test root and returns the signature and EVM address
+    fn sign(sk_byte: u8) -> ([u8; 65], [u8; 20]) {
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&[sk_byte; 32]).unwrap();
+        let digest =
+            compute_output_root_message_hash(&OUTPUT_ROOT, BASE_BLOCK_NUMBER, TOTAL_LEAF_COUNT);
+        let msg = SecpMessage::from_digest_slice(&digest).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, &sk).serialize_compact();
+        let mut sig65 = [0u8; 65];
+        sig65[..64].copy_from_slice(&sig_bytes64);
+        sig65[64] = 27 + rec_id.to_i32() as u8;
+
+        let pk = secp256k1::PublicKey::from_secret_key(&secp, &sk);
+        let hashed = anchor_lang::solana_program::keccak::hash(&pk.serialize_uncompressed()[1..]);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&hashed.to_bytes()[12..]);
+        (sig65, addr)
+    }
+
+    /// Requires one approval from `partner` on top of the Base oracle threshold
+    fn set_partner_signer(svm: &mut LiteSVM, bridge_pda: Pubkey, partner: [u8; 20]) {
+        let mut data = Vec::new();
+        Signers {
+            signers: vec![PartnerSigner::from_evm_address(partner)],
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        svm.set_account(
+            partner_config_pda(),
+            SvmAccount {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: PARTNER_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge.partner_oracle_config.required_threshold = 1;
+        let mut data = Vec::new();
+        bridge.try_serialize(&mut data).unwrap();
+        bridge_account.data = data;
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+    }
+
+    fn add_ix(payer: Pubkey, bridge_pda: Pubkey, signatures: Vec<[u8; 65]>) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::AddOutputRootSignatures {
+                payer,
+                pending_output_root: pending_output_root_pda(),
+                bridge: bridge_pda,
+                base_oracle_signers: base_oracle_signers_pda(0),
+                partner_config: partner_config_pda(),
+                instructions: None,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: AddOutputRootSignaturesIx {
+                output_root: OUTPUT_ROOT,
+                base_block_number: BASE_BLOCK_NUMBER,
+                total_leaf_count: TOTAL_LEAF_COUNT,
+                signatures,
+            }
+            .data(),
+        }
+    }
+
+    fn finalize_ix(payer: Pubkey, pending_payer: Pubkey, bridge_pda: Pubkey) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::FinalizeOutputRoot {
+                payer,
+                pending_output_root: pending_output_root_pda(),
+                pending_payer,
+                root: output_root_pda(),
+                bridge: bridge_pda,
+                base_oracle_signers: base_oracle_signers_pda(0),
+                partner_config: partner_config_pda(),
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: FinalizeOutputRootIx {}.data(),
+        }
+    }
+
+    fn close_ix(payer: Pubkey) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::ClosePendingOutputRoot {
+                pending_output_root: pending_output_root_pda(),
+                payer,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: ClosePendingOutputRootIx {}.data(),
+        }
+    }
+
+    fn read_pending(svm: &LiteSVM) -> PendingOutputRoot {
+        let account = svm.get_account(&pending_output_root_pda()).unwrap();
+        PendingOutputRoot::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_signatures_accumulate_across_transactions_until_finalized() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_clock(&mut svm, NOW);
+
+        let (base_sig_1, base_addr_1) = sign(1);
+        let (base_sig_2, base_addr_2) = sign(2);
+        let (partner_sig, partner_addr) = sign(3);
+        set_base_oracle_signers(&mut svm, 2, &[base_addr_1, base_addr_2]);
+        set_partner_signer(&mut svm, bridge_pda, partner_addr);
+
+        // First batch: one Base oracle signer and the partner signer
+        let meta = send(
+            &mut svm,
+            &payer,
+            add_ix(payer.pubkey(), bridge_pda, vec![base_sig_1, partner_sig]),
+        )
+        .expect("Adding signatures should create the pending output root");
+        let event = find_cpi_event::<OutputRootSignaturesAdded>(&meta).unwrap();
+        assert_eq!(event.signer_count, 2);
+
+        let pending = read_pending(&svm);
+        assert_eq!(pending.payer, payer.pubkey());
+        assert_eq!(pending.expires_at, NOW + PENDING_OUTPUT_ROOT_TTL_SECONDS);
+        assert_eq!(
+            pending.signers,
+            vec![
+                OracleSignerKey::Evm(base_addr_1),
+                OracleSignerKey::Evm(partner_addr)
+            ]
+        );
+
+        // The Base oracle threshold is not met yet
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                finalize_ix(payer.pubkey(), payer.pubkey(), bridge_pda),
+            ),
+            "InsufficientBaseSignatures",
+        );
+
+        // Second batch from another submitter: a repeated signature is deduplicated
+        let relayer = Keypair::new();
+        svm.airdrop(&relayer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        send(
+            &mut svm,
+            &relayer,
+            add_ix(relayer.pubkey(), bridge_pda, vec![base_sig_1, base_sig_2]),
+        )
+        .expect("Adding more signatures should succeed");
+        assert_eq!(read_pending(&svm).signers.len(), 3);
+
+        // The pending account's rent goes back to its creator, not the finalizer
+        assert_error(
+            send(
+                &mut svm,
+                &relayer,
+                finalize_ix(relayer.pubkey(), relayer.pubkey(), bridge_pda),
+            ),
+            "IncorrectPendingOutputRootPayer",
+        );
+        let meta = send(
+            &mut svm,
+            &relayer,
+            finalize_ix(relayer.pubkey(), payer.pubkey(), bridge_pda),
+        )
+        .expect("Finalizing should succeed once both thresholds are met");
+        let event = find_cpi_event::<OutputRootRegistered>(&meta).unwrap();
+        assert_eq!(event.output_root, OUTPUT_ROOT);
+
+        assert!(svm.get_account(&pending_output_root_pda()).is_none());
+        let root_account = svm.get_account(&output_root_pda()).unwrap();
+        let root = OutputRoot::try_deserialize(&mut &root_account.data[..]).unwrap();
+        assert_eq!(root.root, OUTPUT_ROOT);
+        assert_eq!(root.total_leaf_count, TOTAL_LEAF_COUNT);
+        assert_eq!(root.payer, relayer.pubkey());
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.base_block_number, BASE_BLOCK_NUMBER);
+    }
+
+    #[test]
+    fn test_finalize_expired_pending_output_root_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_clock(&mut svm, NOW);
+
+        let (base_sig, base_addr) = sign(1);
+        set_base_oracle_signers(&mut svm, 1, &[base_addr]);
+        send(
+            &mut svm,
+            &payer,
+            add_ix(payer.pubkey(), bridge_pda, vec![base_sig]),
+        )
+        .expect("Adding signatures should succeed");
+
+        // Approvals collected before the expiry cannot register the root afterwards
+        mock_clock(&mut svm, NOW + PENDING_OUTPUT_ROOT_TTL_SECONDS);
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                finalize_ix(payer.pubkey(), payer.pubkey(), bridge_pda),
+            ),
+            "PendingOutputRootExpired",
+        );
+        assert!(svm.get_account(&output_root_pda()).is_none());
+    }
+
+    #[test]
+    fn test_add_signatures_ignores_unknown_signers() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let (_, base_addr) = sign(1);
+        let (unknown_sig, _) = sign(9);
+        set_base_oracle_signers(&mut svm, 1, &[base_addr]);
+
+        send(
+            &mut svm,
+            &payer,
+            add_ix(payer.pubkey(), bridge_pda, vec![unknown_sig]),
+        )
+        .expect("Adding signatures should succeed");
+        assert!(read_pending(&svm).signers.is_empty());
+    }
+
+    #[test]
+    fn test_close_pending_output_root_after_expiry() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_clock(&mut svm, NOW);
+
+        let (base_sig_1, base_addr_1) = sign(1);
+        let (base_sig_2, base_addr_2) = sign(2);
+        set_base_oracle_signers(&mut svm, 2, &[base_addr_1, base_addr_2]);
+        send(
+            &mut svm,
+            &payer,
+            add_ix(payer.pubkey(), bridge_pda, vec![base_sig_1]),
+        )
+        .expect("Adding signatures should succeed");
+
+        let closer = Keypair::new();
+        svm.airdrop(&closer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        assert_error(
+            send(&mut svm, &closer, close_ix(payer.pubkey())),
+            "PendingOutputRootNotExpired",
+        );
+
+        // Once expired, no more signatures are accepted and anyone can close the account
+        mock_clock(&mut svm, NOW + PENDING_OUTPUT_ROOT_TTL_SECONDS);
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                add_ix(payer.pubkey(), bridge_pda, vec![base_sig_2]),
+            ),
+            "PendingOutputRootExpired",
+        );
+        assert_error(
+            send(&mut svm, &closer, close_ix(closer.pubkey())),
+            "IncorrectPendingOutputRootPayer",
+        );
+
+        let payer_balance = svm.get_balance(&payer.pubkey()).unwrap();
+        let rent = svm
+            .get_account(&pending_output_root_pda())
+            .unwrap()
+            .lamports;
+        let meta = send(&mut svm, &closer, close_ix(payer.pubkey()))
+            .expect("Closing an expired pending output root should succeed");
+        let event = find_cpi_event::<PendingOutputRootClosed>(&meta).unwrap();
+        assert_eq!(event.base_block_number, BASE_BLOCK_NUMBER);
+
+        assert!(svm.get_account(&pending_output_root_pda()).is_none());
+        assert_eq!(
+            svm.get_balance(&payer.pubkey()).unwrap(),
+            payer_balance + rent
+        );
+    }
+}
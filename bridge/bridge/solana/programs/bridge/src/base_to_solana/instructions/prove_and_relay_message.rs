@@ -0,0 +1,499 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{
+    constants::{CPI_POLICY_SEED, INCOMING_MESSAGE_SEED, NONCE_BITMAP_SEED},
+    execute_message, hash_message,
+    internal::mmr,
+    state::{NonceBitmap, OutputRoot},
+    LegacyIncomingMessage, Message,
+};
+use crate::common::{
+    bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_PROVE_MESSAGE, PAUSE_RELAY_MESSAGE,
+};
+use crate::{BridgeError, MessageProvenAndRelayed};
+
+/// Accounts struct for the prove_and_relay_message instruction that verifies a message exists on
+/// Base and executes it in the same instruction. No incoming message account is created: replay
+/// protection only relies on the nonce bitmap.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ProveAndRelayMessage<'info> {
+    /// The account that pays for the nonce bitmap page if it does not exist yet.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The output root account containing the MMR root from Base.
+    /// This root must have been previously registered via register_output_root instruction.
+    pub output_root: Account<'info, OutputRoot>,
+
+    /// The replay protection page covering the message nonce.
+    /// - Uses PDA with NONCE_BITMAP_SEED and the page index of the nonce
+    /// - Created by the first relay of a nonce in the page
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + NonceBitmap::INIT_SPACE,
+        seeds = [NONCE_BITMAP_SEED, &NonceBitmap::page(nonce).to_le_bytes()],
+        bump
+    )]
+    pub nonce_bitmap: Account<'info, NonceBitmap>,
+
+    /// The incoming message PDA of the message, derived from `[INCOMING_MESSAGE_SEED, message_hash]`.
+    /// It must not exist: messages already proven with `prove_message` are relayed with `relay_message`.
+    /// Legacy layout accounts only recorded execution in their `executed` flag, so a message still
+    /// proven with the legacy layout must be migrated with `migrate_incoming_message` first.
+    /// CHECK: Address is validated in the handler against the computed message hash.
+    pub incoming_message: UncheckedAccount<'info>,
+
+    /// The main bridge state account used to check pause status
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The withdrawal rate limit PDA of the mint released by a transfer message.
+    /// Required only for transfers of mints in `bridge.rate_limited_mints`.
+    /// CHECK: Address and ownership are validated in the handler.
+    #[account(mut)]
+    pub rate_limit: Option<UncheckedAccount<'info>>,
+
+    /// The guardian-managed policy restricting the programs the message instructions can target.
+    /// CHECK: Address is validated by the seeds constraint and ownership in the handler.
+    #[account(seeds = [CPI_POLICY_SEED], bump)]
+    pub cpi_policy: UncheckedAccount<'info>,
+
+    /// System program required for creating the nonce bitmap page.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn prove_and_relay_message_handler<'a, 'info>(
+    ctx: Context<'a, '_, 'info, 'info, ProveAndRelayMessage<'info>>,
+    nonce: u64,
+    sender: [u8; 20],
+    data: Vec<u8>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    // Both the prove and relay instruction families must be live
+    let pause = &ctx.accounts.bridge.pause;
+    pause.require_not_paused(PAUSE_PROVE_MESSAGE)?;
+    pause.require_not_paused(PAUSE_RELAY_MESSAGE)?;
+
+    // Only roots past their challenge window that were not invalidated can be proven against
+    ctx.accounts.output_root.require_provable(
+        ctx.accounts.bridge.protocol_config.root_finality_seconds,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // Verify the MMR proof of the message hash
+    let message_hash = hash_message(&nonce.to_be_bytes(), &sender, &data);
+    mmr::verify_proof(
+        &ctx.accounts.output_root.root,
+        &message_hash,
+        &nonce,
+        &proof,
+        ctx.accounts.output_root.total_leaf_count,
+    )?;
+
+    let (incoming_message, _) =
+        Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &message_hash], ctx.program_id);
+    require_keys_eq!(
+        ctx.accounts.incoming_message.key(),
+        incoming_message,
+        ErrorCode::ConstraintSeeds
+    );
+    require!(
+        !LegacyIncomingMessage::is_legacy(&ctx.accounts.incoming_message)?,
+        BridgeError::IncomingMessageNotMigrated
+    );
+    // A message already proven with `prove_message` must be relayed through its account, which
+    // would otherwise be left with a consumed nonce and never be executed
+    require!(
+        ctx.accounts.incoming_message.data_is_empty(),
+        BridgeError::MessageAlreadyProven
+    );
+
+    ctx.accounts.nonce_bitmap.consume(nonce)?;
+
+    execute_message(
+        Message::try_from_slice(&data)?,
+        &sender,
+        &ctx.accounts.payer.key(),
+        &ctx.accounts.bridge,
+        ctx.accounts.rate_limit.as_deref(),
+        &ctx.accounts.cpi_policy,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    emit_cpi!(MessageProvenAndRelayed {
+        output_root: ctx.accounts.output_root.key(),
+        message_hash,
+        nonce,
+        sender,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::instruction::Instruction, system_program, Discriminator, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer as _;
+
+    use crate::{
+        accounts,
+        base_to_solana::state::IncomingMessage,
+        instruction::{
+            CloseIncomingMessage as CloseIncomingMessageIx,
+            MigrateIncomingMessage as MigrateIncomingMessageIx,
+            ProveAndRelayMessage as ProveAndRelayMessageIx, ProveMessage as ProveMessageIx,
+        },
+        test_utils::{
+            assert_error, cpi_policy_pda, event_authority_pda, find_cpi_event, mmr_proof, mmr_root,
+            send, setup_bridge, write_output_root, SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const SENDER: [u8; 20] = [7u8; 20];
+    const LEAF_COUNT: u64 = 6;
+
+    fn data() -> Vec<u8> {
+        Message::Call(vec![]).try_to_vec().unwrap()
+    }
+
+    fn leaf_hashes() -> Vec<[u8; 32]> {
+        (0..LEAF_COUNT)
+            .map(|nonce| hash_message(&nonce.to_be_bytes(), &SENDER, &data()))
+            .collect()
+    }
+
+    fn nonce_bitmap_pda(nonce: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[NONCE_BITMAP_SEED, &NonceBitmap::page(nonce).to_le_bytes()],
+            &ID,
+        )
+        .0
+    }
+
+    fn incoming_message_pda(nonce: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[INCOMING_MESSAGE_SEED, &leaf_hashes()[nonce as usize]],
+            &ID,
+        )
+        .0
+    }
+
+    /// Registers an output root over `LEAF_COUNT` empty call messages
+    fn setup_output_root(svm: &mut LiteSVM) -> Pubkey {
+        let output_root = Pubkey::new_unique();
+        write_output_root(
+            svm,
+            output_root,
+            &OutputRoot {
+                root: mmr_root(&leaf_hashes()),
+                total_leaf_count: LEAF_COUNT,
+                base_block_number: 0,
+                payer: Pubkey::default(),
+                registered_at: 0,
+                invalidated: false,
+            },
+        );
+        output_root
+    }
+
+    fn prove_and_relay_ix(
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        output_root: Pubkey,
+        nonce: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::ProveAndRelayMessage {
+                payer: payer.pubkey(),
+                output_root,
+                nonce_bitmap: nonce_bitmap_pda(nonce),
+                incoming_message: incoming_message_pda(nonce),
+                bridge: bridge_pda,
+                rate_limit: None,
+                cpi_policy: cpi_policy_pda(),
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: ProveAndRelayMessageIx {
+                nonce,
+                sender: SENDER,
+                data: data(),
+                proof,
+            }
+            .data(),
+        }
+    }
+
+    #[test]
+    fn test_prove_and_relay_message_executes_without_incoming_message() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let output_root = setup_output_root(&mut svm);
+        let nonce = 4;
+        let proof = mmr_proof(&leaf_hashes(), nonce);
+
+        let meta = send(
+            &mut svm,
+            &payer,
+            prove_and_relay_ix(&payer, bridge_pda, output_root, nonce, proof.clone()),
+        )
+        .expect("prove_and_relay_message should succeed");
+
+        let event = find_cpi_event::<MessageProvenAndRelayed>(&meta)
+            .expect("MessageProvenAndRelayed event should be emitted");
+        assert_eq!(event.output_root, output_root);
+        assert_eq!(event.message_hash, leaf_hashes()[nonce as usize]);
+        assert_eq!(event.nonce, nonce);
+        assert_eq!(event.sender, SENDER);
+
+        let bitmap_account = svm.get_account(&nonce_bitmap_pda(nonce)).unwrap();
+        let bitmap = NonceBitmap::try_deserialize(&mut &bitmap_account.data[..]).unwrap();
+        assert!(bitmap.is_consumed(nonce));
+
+        let incoming_pda = Pubkey::find_program_address(
+            &[INCOMING_MESSAGE_SEED, &leaf_hashes()[nonce as usize]],
+            &ID,
+        )
+        .0;
+        assert!(svm
+            .get_account(&incoming_pda)
+            .is_none_or(|a| a.lamports == 0));
+
+        // The message cannot be executed twice
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                prove_and_relay_ix(&payer, bridge_pda, output_root, nonce, proof),
+            ),
+            "AlreadyExecuted",
+        );
+    }
+
+    #[test]
+    fn test_prove_and_relay_message_rejects_invalid_proof() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let output_root = setup_output_root(&mut svm);
+
+        // Proof of another leaf
+        let proof = mmr_proof(&leaf_hashes(), 1);
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                prove_and_relay_ix(&payer, bridge_pda, output_root, 2, proof),
+            ),
+            "InvalidProof",
+        );
+        assert!(svm.get_account(&nonce_bitmap_pda(2)).is_none());
+    }
+
+    fn prove_ix(
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        output_root: Pubkey,
+        nonce: u64,
+    ) -> Instruction {
+        let message_hash = leaf_hashes()[nonce as usize];
+        Instruction {
+            program_id: ID,
+            accounts: accounts::ProveMessage {
+                payer: payer.pubkey(),
+                output_root,
+                message: incoming_message_pda(nonce),
+                bridge: bridge_pda,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: ProveMessageIx {
+                nonce,
+                sender: SENDER,
+                data: data(),
+                proof: mmr_proof(&leaf_hashes(), nonce),
+                message_hash,
+            }
+            .data(),
+        }
+    }
+
+    #[test]
+    fn test_prove_and_relay_message_rejects_proven_message() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let output_root = setup_output_root(&mut svm);
+        let nonce = 2;
+        let proof = mmr_proof(&leaf_hashes(), nonce);
+
+        // Someone proves the message with its full payload first
+        send(
+            &mut svm,
+            &payer,
+            prove_ix(&payer, bridge_pda, output_root, nonce),
+        )
+        .expect("prove_message should succeed");
+
+        // Consuming its nonce here would leave the incoming message unrelayable
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                prove_and_relay_ix(&payer, bridge_pda, output_root, nonce, proof),
+            ),
+            "MessageAlreadyProven",
+        );
+        assert!(svm.get_account(&nonce_bitmap_pda(nonce)).is_none());
+    }
+
+    #[test]
+    fn test_incoming_message_proven_after_prove_and_relay_can_be_closed() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let output_root = setup_output_root(&mut svm);
+        let nonce = 2;
+        let proof = mmr_proof(&leaf_hashes(), nonce);
+
+        send(
+            &mut svm,
+            &payer,
+            prove_and_relay_ix(&payer, bridge_pda, output_root, nonce, proof),
+        )
+        .expect("prove_and_relay_message should succeed");
+
+        // The message can still be proven afterwards, but never relayed
+        send(
+            &mut svm,
+            &payer,
+            prove_ix(&payer, bridge_pda, output_root, nonce),
+        )
+        .expect("prove_message should succeed");
+
+        // The consumed nonce lets the prover reclaim the unused incoming message rent
+        let close_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::CloseIncomingMessage {
+                message: incoming_message_pda(nonce),
+                nonce_bitmap: nonce_bitmap_pda(nonce),
+                payer: payer.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: CloseIncomingMessageIx {}.data(),
+        };
+        send(&mut svm, &payer, close_ix).expect("close_incoming_message should succeed");
+        assert!(svm
+            .get_account(&incoming_message_pda(nonce))
+            .is_none_or(|a| a.lamports == 0));
+    }
+
+    #[test]
+    fn test_prove_and_relay_message_rejects_legacy_incoming_message() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+        let output_root = setup_output_root(&mut svm);
+        let nonce = 3;
+        let proof = mmr_proof(&leaf_hashes(), nonce);
+
+        // The message was proven and executed before the nonce bitmap existed
+        let mut account_data =
+            vec![0u8; DISCRIMINATOR_LEN + LegacyIncomingMessage::space(data().len())];
+        account_data[..DISCRIMINATOR_LEN].copy_from_slice(IncomingMessage::DISCRIMINATOR);
+        LegacyIncomingMessage {
+            sender: SENDER,
+            message: Message::Call(vec![]),
+            executed: true,
+        }
+        .serialize(&mut &mut account_data[DISCRIMINATOR_LEN..])
+        .unwrap();
+        svm.set_account(
+            incoming_message_pda(nonce),
+            SvmAccount {
+                lamports: svm.minimum_balance_for_rent_exemption(account_data.len()),
+                data: account_data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                prove_and_relay_ix(&payer, bridge_pda, output_root, nonce, proof.clone()),
+            ),
+            "IncomingMessageNotMigrated",
+        );
+
+        // Migrating records the legacy execution in the nonce bitmap
+        let migrate_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::MigrateIncomingMessage {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                message: incoming_message_pda(nonce),
+                nonce_bitmap: nonce_bitmap_pda(nonce),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: MigrateIncomingMessageIx {
+                nonce,
+                original_payer: payer.pubkey(),
+            }
+            .data(),
+        };
+        send(&mut svm, &guardian, migrate_ix).expect("migrate_incoming_message should succeed");
+
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                prove_and_relay_ix(&payer, bridge_pda, output_root, nonce, proof),
+            ),
+            "MessageAlreadyProven",
+        );
+        let bitmap_account = svm.get_account(&nonce_bitmap_pda(nonce)).unwrap();
+        let bitmap = NonceBitmap::try_deserialize(&mut &bitmap_account.data[..]).unwrap();
+        assert!(bitmap.is_consumed(nonce));
+    }
+}
@@ -1,7 +1,6 @@
 use anchor_lang::{prelude::*, solana_program::keccak};
 
-use crate::common::{bridge::Bridge, BRIDGE_SEED};
-use crate::BridgeError;
+use crate::common::{bridge::Bridge, BRIDGE_SEED, PAUSE_PROVE_MESSAGE};
 use crate::{
     base_to_solana::{
         constants::INCOMING_MESSAGE_SEED,
@@ -11,10 +10,12 @@ use crate::{
     },
     common::DISCRIMINATOR_LEN,
 };
+use crate::{BridgeError, MessageProven};
 
 /// Accounts struct for the prove_message instruction that verifies a message exists on Base.
 /// This instruction creates a proven message account after validating the message against an MMR proof
 /// and an output root. The proven message can later be relayed/executed on Solana.
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(nonce: u64, sender: [u8; 20], data: Vec<u8>, _proof: Vec<[u8; 32]>, message_hash: [u8; 32])]
 pub struct ProveMessage<'info> {
@@ -60,8 +61,17 @@ pub fn prove_message_handler(
     proof: Vec<[u8; 32]>,
     message_hash: [u8; 32],
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    // Check if this instruction family is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_PROVE_MESSAGE)?;
+
+    // Only roots past their challenge window that were not invalidated can be proven against
+    ctx.accounts.output_root.require_provable(
+        ctx.accounts.bridge.protocol_config.root_finality_seconds,
+        Clock::get()?.unix_timestamp,
+    )?;
 
     // Verify that the provided message hash matches the computed hash
     let computed_hash = hash_message(&nonce.to_be_bytes(), &sender, &data);
@@ -81,10 +91,23 @@ pub fn prove_message_handler(
 
     *ctx.accounts.message = IncomingMessage {
         executed: false,
+        cursor: None,
+        proven_at: Clock::get()?.unix_timestamp,
         sender,
+        nonce,
+        message_hash,
+        payer: ctx.accounts.payer.key(),
         message: Message::try_from_slice(&data)?,
     };
 
+    emit_cpi!(MessageProven {
+        incoming_message: ctx.accounts.message.key(),
+        output_root: ctx.accounts.output_root.key(),
+        message_hash,
+        nonce,
+        sender,
+    });
+
     Ok(())
 }
 
@@ -93,11 +116,141 @@ pub fn prove_message_handler(
 /// - `nonce` is encoded as big-endian bytes.
 /// - `sender` is a 20-byte Base/EVM address.
 /// - `data` is the Borsh-serialized `Message` payload.
-fn hash_message(nonce: &[u8], sender: &[u8; 20], data: &[u8]) -> [u8; 32] {
-    let mut data_to_hash = Vec::new();
-    data_to_hash.extend_from_slice(nonce);
-    data_to_hash.extend_from_slice(sender);
-    data_to_hash.extend_from_slice(data);
+///
+/// The parts are hashed in place, without copying the payload.
+pub fn hash_message(nonce: &[u8], sender: &[u8; 20], data: &[u8]) -> [u8; 32] {
+    keccak::hashv(&[nonce, sender, data]).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SolMessage;
+    use solana_signer::Signer as _;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::ProveMessage as ProveMessageIx,
+        test_utils::{event_authority_pda, setup_bridge, write_output_root, SetupBridgeResult},
+        ID,
+    };
+
+    const SENDER: [u8; 20] = [7u8; 20];
+
+    fn setup_output_root(svm: &mut LiteSVM, root: [u8; 32], total_leaf_count: u64) -> Pubkey {
+        let output_root = Pubkey::new_unique();
+        write_output_root(
+            svm,
+            output_root,
+            &OutputRoot {
+                root,
+                total_leaf_count,
+                base_block_number: 0,
+                payer: Pubkey::default(),
+                registered_at: 0,
+                invalidated: false,
+            },
+        );
+        output_root
+    }
+
+    /// Proves message `nonce` with the given proof and returns the compute units consumed
+    fn prove(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        output_root: Pubkey,
+        nonce: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> u64 {
+        let data = Message::Call(vec![]).try_to_vec().unwrap();
+        let message_hash = hash_message(&nonce.to_be_bytes(), &SENDER, &data);
+        let message = Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &message_hash], &ID).0;
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ProveMessage {
+                payer: payer.pubkey(),
+                output_root,
+                message,
+                bridge: bridge_pda,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: ProveMessageIx {
+                nonce,
+                sender: SENDER,
+                data,
+                proof,
+                message_hash,
+            }
+            .data(),
+        };
+
+        svm.expire_blockhash();
+        let tx = Transaction::new(
+            &[payer],
+            SolMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("prove_message should succeed")
+            .compute_units_consumed
+    }
+
+    #[test]
+    fn test_prove_message_compute_cost_grows_linearly_with_proof_depth() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let data = Message::Call(vec![]).try_to_vec().unwrap();
+
+        // Single leaf MMR: the leaf is the root and the proof is empty
+        let leaf = hash_message(&0u64.to_be_bytes(), &SENDER, &data);
+        let shallow_root = setup_output_root(&mut svm, leaf, 1);
+        let shallow_cu = prove(&mut svm, &payer, bridge_pda, shallow_root, 0, vec![]);
+
+        // 2^31 - 1 leaves form 31 mountains. Leaf 1 sits in the tallest one, so its proof has
+        // 30 siblings followed by the 30 other peaks.
+        let total_leaf_count = (1u64 << 31) - 1;
+        let leaf = hash_message(&1u64.to_be_bytes(), &SENDER, &data);
+        let siblings: Vec<[u8; 32]> = (0..30u8).map(|i| [i + 1; 32]).collect();
+        let peaks: Vec<[u8; 32]> = (0..30u8).map(|i| [i + 101; 32]).collect();
+
+        let mut root = siblings.iter().fold(leaf, |hash, sibling| {
+            let (a, b) = if hash <= *sibling {
+                (hash, *sibling)
+            } else {
+                (*sibling, hash)
+            };
+            keccak::hashv(&[&a, &b]).0
+        });
+        for peak in &peaks {
+            root = keccak::hashv(&[&root, peak]).0;
+        }
+
+        let deep_root = setup_output_root(&mut svm, root, total_leaf_count);
+        let proof: Vec<[u8; 32]> = siblings.into_iter().chain(peaks).collect();
+        let proof_len = proof.len() as u64;
+        let deep_cu = prove(&mut svm, &payer, bridge_pda, deep_root, 1, proof);
 
-    keccak::hash(&data_to_hash).0
+        let per_element = (deep_cu - shallow_cu) / proof_len;
+        assert!(
+            per_element < 400,
+            "proof verification costs {per_element} CU per element"
+        );
+    }
 }
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{
+    constants::INCOMING_MESSAGE_SEED,
+    internal::mmr,
+    state::{IncomingMessageHash, OutputRoot},
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_PROVE_MESSAGE};
+use crate::MessageHashProven;
+
+/// Accounts struct for the prove_message_hash instruction that verifies a message exists on Base
+/// without storing its payload. Only the message hash is kept; the payload is provided when the
+/// message is relayed with `relay_message_with_payload`.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nonce: u64, message_hash: [u8; 32])]
+pub struct ProveMessageHash<'info> {
+    /// The account that pays for the transaction and incoming message account creation.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The output root account containing the MMR root from Base.
+    /// This root must have been previously registered via register_output_root instruction.
+    pub output_root: Account<'info, OutputRoot>,
+
+    /// The incoming message hash account being created.
+    /// - Shares the `IncomingMessage` PDA, so a message cannot be proven in both modes
+    /// - Fixed size, whatever the size of the message payload
+    #[account(
+        init,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + IncomingMessageHash::INIT_SPACE,
+        seeds = [INCOMING_MESSAGE_SEED, &message_hash],
+        bump
+    )]
+    pub message: Account<'info, IncomingMessageHash>,
+
+    /// The main bridge state account used to check pause status
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// System program required for creating new accounts.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn prove_message_hash_handler(
+    ctx: Context<ProveMessageHash>,
+    nonce: u64,
+    message_hash: [u8; 32],
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    // Check if this instruction family is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_PROVE_MESSAGE)?;
+
+    // Only roots past their challenge window that were not invalidated can be proven against
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.output_root.require_provable(
+        ctx.accounts.bridge.protocol_config.root_finality_seconds,
+        now,
+    )?;
+
+    // The leaf at `nonce` must be the message hash; the payload is checked against it at relay time
+    mmr::verify_proof(
+        &ctx.accounts.output_root.root,
+        &message_hash,
+        &nonce,
+        &proof,
+        ctx.accounts.output_root.total_leaf_count,
+    )?;
+
+    *ctx.accounts.message = IncomingMessageHash {
+        nonce,
+        payer: ctx.accounts.payer.key(),
+        message_hash,
+        proven_at: now,
+        executed: false,
+    };
+
+    emit_cpi!(MessageHashProven {
+        incoming_message: ctx.accounts.message.key(),
+        output_root: ctx.accounts.output_root.key(),
+        message_hash,
+        nonce,
+    });
+
+    Ok(())
+}
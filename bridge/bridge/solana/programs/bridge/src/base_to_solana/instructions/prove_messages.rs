@@ -0,0 +1,381 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::common::{
+    bridge::Bridge, create_pda_account, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_PROVE_MESSAGE,
+};
+use crate::{
+    base_to_solana::{
+        constants::INCOMING_MESSAGE_SEED,
+        hash_message,
+        internal::mmr,
+        state::{IncomingMessage, OutputRoot},
+        Message,
+    },
+    BridgeError, MessageProven,
+};
+
+/// A Base message leaf proven by `prove_messages`.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct MessageLeaf {
+    /// The Base nonce of the message, which is also its leaf index in the MMR
+    pub nonce: u64,
+    /// The 20-byte sender of the message on Base
+    pub sender: [u8; 20],
+    /// The Borsh-serialized `Message` payload
+    pub data: Vec<u8>,
+}
+
+/// Accounts struct for the prove_messages instruction that proves several Base messages against
+/// a single output root with one MMR multiproof.
+///
+/// The `IncomingMessage` accounts are passed as remaining accounts, one per message and in the
+/// same order, at the PDA derived from INCOMING_MESSAGE_SEED and the message hash.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProveMessages<'info> {
+    /// The account that pays for the transaction and the incoming message accounts creation.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The output root account containing the MMR root from Base.
+    pub output_root: Account<'info, OutputRoot>,
+
+    /// The main bridge state account used to check pause status
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// System program required for creating the incoming message accounts.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn prove_messages_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ProveMessages<'info>>,
+    messages: Vec<MessageLeaf>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    // Check if this instruction family is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_PROVE_MESSAGE)?;
+
+    // Only roots past their challenge window that were not invalidated can be proven against
+    let proven_at = Clock::get()?.unix_timestamp;
+    ctx.accounts.output_root.require_provable(
+        ctx.accounts.bridge.protocol_config.root_finality_seconds,
+        proven_at,
+    )?;
+
+    require!(
+        ctx.remaining_accounts.len() == messages.len(),
+        BridgeError::InvalidProof
+    );
+
+    // Verify all messages were included on the source chain with one multiproof; leaves must be
+    // sorted by nonce
+    let leaves: Vec<(u64, [u8; 32])> = messages
+        .iter()
+        .map(|m| {
+            (
+                m.nonce,
+                hash_message(&m.nonce.to_be_bytes(), &m.sender, &m.data),
+            )
+        })
+        .collect();
+    mmr::verify_multiproof(
+        &ctx.accounts.output_root.root,
+        &leaves,
+        &proof,
+        ctx.accounts.output_root.total_leaf_count,
+    )?;
+
+    for ((message, (_, message_hash)), account) in messages
+        .into_iter()
+        .zip(leaves)
+        .zip(ctx.remaining_accounts.iter())
+    {
+        let incoming_message = IncomingMessage {
+            executed: false,
+            cursor: None,
+            proven_at,
+            sender: message.sender,
+            nonce: message.nonce,
+            message_hash,
+            payer: ctx.accounts.payer.key(),
+            message: Message::try_from_slice(&message.data)?,
+        };
+        create_incoming_message_account(
+            &ctx.accounts.payer,
+            account,
+            &ctx.accounts.system_program,
+            &message_hash,
+            DISCRIMINATOR_LEN + IncomingMessage::space(message.data.len()),
+        )?;
+        incoming_message.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+        emit_cpi!(MessageProven {
+            incoming_message: account.key(),
+            output_root: ctx.accounts.output_root.key(),
+            message_hash,
+            nonce: message.nonce,
+            sender: message.sender,
+        });
+    }
+
+    Ok(())
+}
+
+/// Create the `IncomingMessage` PDA for `message_hash`, as Anchor's `init` constraint would.
+fn create_incoming_message_account<'info>(
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    message_hash: &[u8; 32],
+    space: usize,
+) -> Result<()> {
+    let (expected, bump) =
+        Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, message_hash], &crate::ID);
+    require_keys_eq!(
+        account.key(),
+        expected,
+        anchor_lang::error::ErrorCode::ConstraintSeeds
+    );
+
+    // The message must not have been proven already
+    require_keys_eq!(
+        *account.owner,
+        system_program::ID,
+        BridgeError::MessageAlreadyProven
+    );
+
+    create_pda_account(
+        &payer.to_account_info(),
+        account,
+        &system_program.to_account_info(),
+        &[&[INCOMING_MESSAGE_SEED, message_hash, &[bump]]],
+        space,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::instruction::{AccountMeta, Instruction},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_signer::Signer as _;
+
+    use crate::{
+        accounts,
+        base_to_solana::Message as BridgeMessage,
+        instruction::ProveMessages as ProveMessagesIx,
+        test_utils::{
+            assert_error, event_authority_pda, mmr_multiproof, mmr_root, send, setup_bridge,
+            write_output_root, SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const SENDER: [u8; 20] = [7u8; 20];
+
+    fn leaf(nonce: u64) -> MessageLeaf {
+        MessageLeaf {
+            nonce,
+            sender: SENDER,
+            data: BridgeMessage::Call(vec![]).try_to_vec().unwrap(),
+        }
+    }
+
+    fn leaf_hash(leaf: &MessageLeaf) -> [u8; 32] {
+        hash_message(&leaf.nonce.to_be_bytes(), &leaf.sender, &leaf.data)
+    }
+
+    fn incoming_message_pda(leaf: &MessageLeaf) -> Pubkey {
+        Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &leaf_hash(leaf)], &ID).0
+    }
+
+    /// Registers an output root over the first `count` message leaves
+    fn setup_output_root(svm: &mut LiteSVM, count: u64) -> Pubkey {
+        let leaf_hashes: Vec<[u8; 32]> = (0..count).map(|n| leaf_hash(&leaf(n))).collect();
+        let output_root = Pubkey::new_unique();
+        write_output_root(
+            svm,
+            output_root,
+            &OutputRoot {
+                root: mmr_root(&leaf_hashes),
+                total_leaf_count: count,
+                base_block_number: 0,
+                payer: Pubkey::default(),
+                registered_at: 0,
+                invalidated: false,
+            },
+        );
+        output_root
+    }
+
+    fn prove_ix(
+        payer: Pubkey,
+        bridge_pda: Pubkey,
+        output_root: Pubkey,
+        messages: Vec<MessageLeaf>,
+        message_accounts: Vec<Pubkey>,
+        proof: Vec<[u8; 32]>,
+    ) -> Instruction {
+        let mut accounts = accounts::ProveMessages {
+            payer,
+            output_root,
+            bridge: bridge_pda,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
+        }
+        .to_account_metas(None);
+        accounts.extend(
+            message_accounts
+                .into_iter()
+                .map(|account| AccountMeta::new(account, false)),
+        );
+
+        Instruction {
+            program_id: ID,
+            accounts,
+            data: ProveMessagesIx { messages, proof }.data(),
+        }
+    }
+
+    #[test]
+    fn test_prove_messages_creates_all_incoming_messages() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let output_root = setup_output_root(&mut svm, 7);
+
+        let nonces = [1u64, 3, 4, 6];
+        let messages: Vec<MessageLeaf> = nonces.iter().map(|n| leaf(*n)).collect();
+        let leaf_hashes: Vec<[u8; 32]> = (0..7).map(|n| leaf_hash(&leaf(n))).collect();
+        let proof = mmr_multiproof(&leaf_hashes, &nonces);
+        let message_accounts: Vec<Pubkey> = messages.iter().map(incoming_message_pda).collect();
+
+        send(
+            &mut svm,
+            &payer,
+            prove_ix(
+                payer.pubkey(),
+                bridge_pda,
+                output_root,
+                messages.clone(),
+                message_accounts.clone(),
+                proof.clone(),
+            ),
+        )
+        .expect("prove_messages should succeed");
+
+        for (message, account) in messages.iter().zip(&message_accounts) {
+            let account = svm.get_account(account).unwrap();
+            assert_eq!(account.owner, ID);
+            let incoming = IncomingMessage::try_deserialize(&mut &account.data[..]).unwrap();
+            assert_eq!(incoming.nonce, message.nonce);
+            assert_eq!(incoming.sender, SENDER);
+            assert_eq!(incoming.payer, payer.pubkey());
+            assert!(!incoming.executed);
+        }
+
+        // The same messages cannot be proven twice
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                prove_ix(
+                    payer.pubkey(),
+                    bridge_pda,
+                    output_root,
+                    messages,
+                    message_accounts,
+                    proof,
+                ),
+            ),
+            "MessageAlreadyProven",
+        );
+    }
+
+    #[test]
+    fn test_prove_messages_rejects_invalid_batches() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let output_root = setup_output_root(&mut svm, 5);
+
+        let nonces = [0u64, 2];
+        let messages: Vec<MessageLeaf> = nonces.iter().map(|n| leaf(*n)).collect();
+        let leaf_hashes: Vec<[u8; 32]> = (0..5).map(|n| leaf_hash(&leaf(n))).collect();
+        let proof = mmr_multiproof(&leaf_hashes, &nonces);
+        let message_accounts: Vec<Pubkey> = messages.iter().map(incoming_message_pda).collect();
+
+        // Incoming message accounts out of order
+        let swapped = vec![message_accounts[1], message_accounts[0]];
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                prove_ix(
+                    payer.pubkey(),
+                    bridge_pda,
+                    output_root,
+                    messages.clone(),
+                    swapped,
+                    proof.clone(),
+                ),
+            ),
+            "ConstraintSeeds",
+        );
+
+        // A message that is not in the root
+        let mut tampered = messages.clone();
+        tampered[1].sender = [8u8; 20];
+        let tampered_accounts = tampered.iter().map(incoming_message_pda).collect();
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                prove_ix(
+                    payer.pubkey(),
+                    bridge_pda,
+                    output_root,
+                    tampered,
+                    tampered_accounts,
+                    proof.clone(),
+                ),
+            ),
+            "InvalidProof",
+        );
+
+        // Leaves must be sorted by nonce
+        let reversed: Vec<MessageLeaf> = messages.iter().rev().cloned().collect();
+        let reversed_accounts = reversed.iter().map(incoming_message_pda).collect();
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                prove_ix(
+                    payer.pubkey(),
+                    bridge_pda,
+                    output_root,
+                    reversed,
+                    reversed_accounts,
+                    proof,
+                ),
+            ),
+            "InvalidProof",
+        );
+    }
+}
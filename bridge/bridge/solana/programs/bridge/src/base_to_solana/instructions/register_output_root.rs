@@ -1,23 +1,28 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::sysvar};
 
-use crate::base_to_solana::constants::{PARTNER_PROGRAM_ID, PARTNER_SIGNERS_ACCOUNT_SEED};
-use crate::base_to_solana::state::Signers;
-use crate::base_to_solana::{compute_output_root_message_hash, recover_unique_evm_addresses};
-use crate::BridgeError;
+use crate::base_to_solana::constants::{
+    BASE_ORACLE_SIGNERS_SEED, PARTNER_PROGRAM_ID, PARTNER_SIGNERS_ACCOUNT_SEED,
+};
+use crate::base_to_solana::state::{BaseOracleSigners, Signers};
+use crate::base_to_solana::{
+    output_root_attestation_digests, recover_unique_evm_addresses, verified_precompile_signers,
+};
 use crate::{
     base_to_solana::{constants::OUTPUT_ROOT_SEED, state::OutputRoot},
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_REGISTER_OUTPUT_ROOT},
 };
+use crate::{BridgeError, OutputRootRegistered};
 
 /// Accounts struct for the `register_output_root` instruction that stores Base MMR roots
 /// on Solana for cross-chain message verification. This instruction allows a trusted oracle to
 /// register output roots from Base at specific block intervals, enabling subsequent message
 /// proofs and cross-chain operations. The instruction also records the MMR's total leaf count
 /// needed for proof verification at that checkpoint.
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(output_root: [u8; 32], base_block_number: u64)]
 pub struct RegisterOutputRoot<'info> {
-    /// Payer funds the account creation. Authorization is enforced via oracle EVM signature.
+    /// Payer funds the account creation. Authorization is enforced via oracle signatures.
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -26,8 +31,9 @@ pub struct RegisterOutputRoot<'info> {
     /// - Payer funds the account creation (authorization is enforced via EVM signatures)
     /// - Space allocated for output root state (DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE)
     /// - Each output root corresponds to a specific Base block number
+    /// - An existing account can only be reused if its root was invalidated or revoked
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         space = DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE,
         seeds = [OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()],
@@ -42,11 +48,27 @@ pub struct RegisterOutputRoot<'info> {
     #[account(mut, seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
 
+    /// The Base oracle signer epoch holding the authorized signers and approval threshold.
+    /// - Uses PDA with BASE_ORACLE_SIGNERS_SEED and the epoch number
+    /// - Must cover `base_block_number`; during a rotation overlap either epoch is accepted
+    #[account(
+        seeds = [BASE_ORACLE_SIGNERS_SEED, &base_oracle_signers.epoch.to_le_bytes()],
+        bump
+    )]
+    pub base_oracle_signers: Account<'info, BaseOracleSigners>,
+
     /// Partner `Config` account (PDA with seed "config") owned by partner program.
     /// Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler.
     /// CHECK: This is validated in the handler.
     pub partner_config: AccountInfo<'info>,
 
+    /// Instructions sysvar used to find Secp256k1 and Ed25519 program instructions attesting to
+    /// the root. Only needed when signatures are verified by those programs instead of being
+    /// passed as instruction arguments.
+    /// CHECK: Address is checked against the instructions sysvar id.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+
     /// System program required for creating new accounts.
     /// Used internally by Anchor for output root account initialization.
     pub system_program: Program<'info, System>,
@@ -59,64 +81,173 @@ pub fn register_output_root_handler(
     total_leaf_count: u64,
     signatures: Vec<[u8; 65]>,
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    // Check if this instruction family is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_REGISTER_OUTPUT_ROOT)?;
+
+    // The signer set must be the one in charge of this block number
+    require!(
+        ctx.accounts.base_oracle_signers.covers(base_block_number),
+        BridgeError::BaseOracleEpochMismatch
+    );
+
+    // Build the message hashes the oracles may have signed, per accepted attestation format
+    let message_hashes = output_root_attestation_digests(
+        &ctx.accounts.bridge.protocol_config.attestation,
+        &output_root,
+        base_block_number,
+        total_leaf_count,
+    );
 
-    // Build message hash for signatures
-    let message_hash =
-        compute_output_root_message_hash(&output_root, base_block_number, total_leaf_count);
+    let (evm_signers, ed25519_signers) = collect_oracle_signers(
+        &signatures,
+        &message_hashes,
+        ctx.accounts.instructions.as_deref(),
+    )?;
+
+    require_oracle_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.base_oracle_signers,
+        &ctx.accounts.partner_config,
+        &evm_signers,
+        &ed25519_signers,
+    )?;
+
+    record_output_root(
+        &mut ctx.accounts.root,
+        &mut ctx.accounts.bridge,
+        ctx.accounts.payer.key(),
+        output_root,
+        base_block_number,
+        total_leaf_count,
+    )?;
+
+    emit_cpi!(OutputRootRegistered {
+        output_root_account: ctx.accounts.root.key(),
+        output_root,
+        base_block_number,
+        total_leaf_count,
+    });
 
+    Ok(())
+}
+
+/// Collect the unique EVM and Ed25519 signers attesting to any of `message_hashes`, from the
+/// provided signatures and from Secp256k1 and Ed25519 program instructions in the transaction.
+pub(crate) fn collect_oracle_signers(
+    signatures: &[[u8; 65]],
+    message_hashes: &[[u8; 32]],
+    instructions: Option<&AccountInfo>,
+) -> Result<(Vec<[u8; 20]>, Vec<Pubkey>)> {
     // Recover unique EVM signers from provided signatures
-    let unique_signers = recover_unique_evm_addresses(&signatures, &message_hash)?;
+    let mut evm_signers = recover_unique_evm_addresses(signatures, message_hashes)?;
 
-    // Verify Base oracle approvals
-    let base_approved_count = ctx
-        .accounts
-        .bridge
-        .base_oracle_config
-        .count_approvals(&unique_signers);
+    // Add signers already verified by the Secp256k1 and Ed25519 programs in this transaction
+    let precompile_signers = match instructions {
+        Some(instructions) => verified_precompile_signers(instructions, message_hashes),
+        None => Default::default(),
+    };
+    for signer in precompile_signers.evm {
+        if !evm_signers.contains(&signer) {
+            evm_signers.push(signer);
+        }
+    }
+
+    Ok((evm_signers, precompile_signers.ed25519))
+}
+
+/// Load the partner signer set, checking the account is the partner program's signers PDA.
+pub(crate) fn load_partner_signers(partner_config: &AccountInfo) -> Result<Signers> {
+    // Validate partner_config PDA using seed with the partner program id
+    let expected_partner_cfg =
+        Pubkey::find_program_address(&[PARTNER_SIGNERS_ACCOUNT_SEED], &PARTNER_PROGRAM_ID).0;
+    require_keys_eq!(
+        partner_config.key(),
+        expected_partner_cfg,
+        anchor_lang::error::ErrorCode::ConstraintSeeds
+    );
+
+    // Deserialize manually as the account is owned by the partner program
+    Signers::try_deserialize(&mut &partner_config.data.borrow()[..])
+}
 
+/// Fail unless the signers meet both the Base oracle and the partner oracle thresholds.
+pub(crate) fn require_oracle_approvals(
+    bridge: &Bridge,
+    base_oracle_signers: &BaseOracleSigners,
+    partner_config: &AccountInfo,
+    evm_signers: &[[u8; 20]],
+    ed25519_signers: &[Pubkey],
+) -> Result<()> {
+    // Verify Base oracle approvals; EVM and Ed25519 signers count toward the same threshold
+    let base_approved_weight = base_oracle_signers.approved_weight(evm_signers, ed25519_signers);
     require!(
-        base_approved_count as u8 >= ctx.accounts.bridge.base_oracle_config.threshold,
+        base_approved_weight >= base_oracle_signers.threshold as u32,
         BridgeError::InsufficientBaseSignatures
     );
 
-    if ctx.accounts.bridge.partner_oracle_config.required_threshold > 0 {
-        // Validate partner_config PDA using seed with the partner program id
-        let expected_partner_cfg =
-            Pubkey::find_program_address(&[PARTNER_SIGNERS_ACCOUNT_SEED], &PARTNER_PROGRAM_ID).0;
-        require_keys_eq!(
-            ctx.accounts.partner_config.key(),
-            expected_partner_cfg,
-            anchor_lang::error::ErrorCode::ConstraintSeeds
-        );
-
-        // Verify partner approvals using partner's signers (deserialize manually)
-        let partner_oracle_config = &ctx.accounts.bridge.partner_oracle_config;
-        let partner_config =
-            Signers::try_deserialize(&mut &ctx.accounts.partner_config.data.borrow()[..])?;
-        let partner_approved_count = partner_config.count_approvals(&unique_signers);
-        require!(
-            partner_approved_count as u8 >= partner_oracle_config.required_threshold,
-            BridgeError::InsufficientPartnerSignatures
-        );
+    if bridge.partner_oracle_config.required_threshold > 0 {
+        require_partner_approvals(bridge, partner_config, evm_signers)?;
     }
 
+    Ok(())
+}
+
+/// Fail unless the EVM signers meet the partner oracle threshold, using the partner's signer set.
+pub(crate) fn require_partner_approvals(
+    bridge: &Bridge,
+    partner_config: &AccountInfo,
+    evm_signers: &[[u8; 20]],
+) -> Result<()> {
+    let partner_oracle_config = &bridge.partner_oracle_config;
+    let partner_config = load_partner_signers(partner_config)?;
+    let required_weight = partner_oracle_config.required_threshold as u32;
+    require!(
+        partner_config.total_weight(partner_oracle_config) >= required_weight,
+        BridgeError::InvalidPartnerThreshold
+    );
+
+    let partner_approved_weight =
+        partner_config.approved_weight(evm_signers, partner_oracle_config);
     require!(
-        base_block_number > ctx.accounts.bridge.base_block_number
-            && base_block_number
-                % ctx
-                    .accounts
-                    .bridge
-                    .protocol_config
-                    .block_interval_requirement
-                == 0,
+        partner_approved_weight >= required_weight,
+        BridgeError::InsufficientPartnerSignatures
+    );
+
+    Ok(())
+}
+
+/// Store an attested output root as the latest registered checkpoint.
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn record_output_root(
+    root: &mut OutputRoot,
+    bridge: &mut Bridge,
+    payer: Pubkey,
+    output_root: [u8; 32],
+    base_block_number: u64,
+    total_leaf_count: u64,
+) -> Result<()> {
+    require!(
+        base_block_number > bridge.base_block_number
+            && base_block_number % bridge.protocol_config.block_interval_requirement == 0,
         BridgeError::IncorrectBlockNumber
     );
 
-    ctx.accounts.root.root = output_root;
-    ctx.accounts.root.total_leaf_count = total_leaf_count;
-    ctx.accounts.bridge.base_block_number = base_block_number;
+    // A revoked checkpoint can be registered again; its account keeps refunding the original payer
+    if root.payer == Pubkey::default() {
+        root.payer = payer;
+    } else {
+        require!(root.invalidated, BridgeError::OutputRootAlreadyRegistered);
+    }
+
+    root.root = output_root;
+    root.total_leaf_count = total_leaf_count;
+    root.base_block_number = base_block_number;
+    root.invalidated = false;
+    root.registered_at = Clock::get()?.unix_timestamp;
+    bridge.base_block_number = base_block_number;
 
     Ok(())
 }
@@ -126,10 +257,15 @@ mod tests {
     use super::*;
 
     use anchor_lang::{
-        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        solana_program::{
+            instruction::Instruction, native_token::LAMPORTS_PER_SOL, secp256k1_program,
+        },
         system_program, InstructionData,
     };
-    use litesvm::LiteSVM;
+    use litesvm::{
+        types::{FailedTransactionMetadata, TransactionMetadata},
+        LiteSVM,
+    };
     use solana_account::Account as SvmAccount;
     use solana_keypair::Keypair;
     use solana_message::Message;
@@ -139,13 +275,19 @@ mod tests {
     use crate::{
         accounts,
         base_to_solana::state::signers::{PartnerSigner, Signers},
+        base_to_solana::state::{BaseOracleSigner, OracleSignerKey},
         base_to_solana::{
             constants::{OUTPUT_ROOT_SEED, PARTNER_SIGNERS_ACCOUNT_SEED},
-            internal::compute_output_root_message_hash,
+            internal::{compute_output_root_message_hash, compute_typed_output_root_hash},
+        },
+        common::bridge::{AttestationConfig, AttestationFormat, Bridge},
+        instruction::{
+            RegisterOutputRoot as RegisterOutputRootIx, RevokeOutputRoot as RevokeOutputRootIx,
+        },
+        test_utils::{
+            base_oracle_signers_pda, ed25519_verify_ix, event_authority_pda, set_base_oracle_epoch,
+            set_base_oracle_signers, setup_bridge, SetupBridgeResult,
         },
-        common::{bridge::Bridge, MAX_SIGNER_COUNT},
-        instruction::RegisterOutputRoot as RegisterOutputRootIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
         ID,
     };
 
@@ -196,17 +338,72 @@ mod tests {
         total_leaf_count: u64,
         signatures: Vec<[u8; 65]>,
     ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
-        let root_pda = output_root_pda(base_block_number);
+        send_register_for_epoch(
+            svm,
+            payer,
+            bridge_pda,
+            partner_cfg_pda,
+            0,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            signatures,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn send_register_for_epoch(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        partner_cfg_pda: Pubkey,
+        epoch: u64,
+        output_root: [u8; 32],
+        base_block_number: u64,
+        total_leaf_count: u64,
+        signatures: Vec<[u8; 65]>,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let ix = register_ix(
+            payer.pubkey(),
+            bridge_pda,
+            partner_cfg_pda,
+            epoch,
+            None,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            signatures,
+        );
+        send_ixs(svm, payer, &[ix])?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn register_ix(
+        payer: Pubkey,
+        bridge_pda: Pubkey,
+        partner_cfg_pda: Pubkey,
+        epoch: u64,
+        instructions: Option<Pubkey>,
+        output_root: [u8; 32],
+        base_block_number: u64,
+        total_leaf_count: u64,
+        signatures: Vec<[u8; 65]>,
+    ) -> Instruction {
         let accounts = accounts::RegisterOutputRoot {
-            payer: payer.pubkey(),
-            root: root_pda,
+            payer,
+            root: output_root_pda(base_block_number),
             bridge: bridge_pda,
+            base_oracle_signers: base_oracle_signers_pda(epoch),
             partner_config: partner_cfg_pda,
+            instructions,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
-        let ix = Instruction {
+        Instruction {
             program_id: ID,
             accounts,
             data: RegisterOutputRootIx {
@@ -216,16 +413,83 @@ mod tests {
                 signatures,
             }
             .data(),
-        };
+        }
+    }
 
+    fn send_ixs(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        ixs: &[Instruction],
+    ) -> std::result::Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+        svm.expire_blockhash();
         let tx = Transaction::new(
             &[payer],
-            Message::new(&[ix], Some(&payer.pubkey())),
+            Message::new(ixs, Some(&payer.pubkey())),
             svm.latest_blockhash(),
         );
 
-        svm.send_transaction(tx).map_err(Box::new)?;
-        Ok(())
+        svm.send_transaction(tx).map_err(Box::new)
+    }
+
+    /// Builds a Secp256k1 program instruction verifying `signatures` (r||s||v with v in 27..=28)
+    /// over the shared `message`, assuming the instruction sits at `ix_index` in the transaction.
+    fn secp256k1_verify_ix(
+        signatures: &[([u8; 65], [u8; 20])],
+        message: &[u8],
+        ix_index: u8,
+    ) -> Instruction {
+        const OFFSETS_LEN: usize = 11;
+        const ENTRY_LEN: usize = 20 + 65;
+        let entries_start = 1 + signatures.len() * OFFSETS_LEN;
+        let message_offset = (entries_start + signatures.len() * ENTRY_LEN) as u16;
+
+        let mut data = vec![signatures.len() as u8];
+        for i in 0..signatures.len() {
+            let address_offset = (entries_start + i * ENTRY_LEN) as u16;
+            let signature_offset = address_offset + 20;
+            data.extend_from_slice(&signature_offset.to_le_bytes());
+            data.push(ix_index);
+            data.extend_from_slice(&address_offset.to_le_bytes());
+            data.push(ix_index);
+            data.extend_from_slice(&message_offset.to_le_bytes());
+            data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+            data.push(ix_index);
+        }
+        for (signature, address) in signatures {
+            data.extend_from_slice(address);
+            data.extend_from_slice(&signature[..64]);
+            data.push(signature[64] - 27);
+        }
+        data.extend_from_slice(message);
+
+        Instruction {
+            program_id: secp256k1_program::ID,
+            accounts: vec![],
+            data,
+        }
+    }
+
+    /// EIP-191 preimage whose keccak256 is `compute_output_root_message_hash`
+    fn output_root_message_preimage(
+        output_root: [u8; 32],
+        base_block_number: u64,
+        total_leaf_count: u64,
+    ) -> Vec<u8> {
+        let mut message = b"\x19Ethereum Signed Message:\n48".to_vec();
+        message.extend_from_slice(&output_root);
+        message.extend_from_slice(&base_block_number.to_be_bytes());
+        message.extend_from_slice(&total_leaf_count.to_be_bytes());
+        message
+    }
+
+    fn compute_unit_limit_ix(units: u32) -> Instruction {
+        let mut data = vec![2u8];
+        data.extend_from_slice(&units.to_le_bytes());
+        Instruction {
+            program_id: pubkey!("ComputeBudget111111111111111111111111111111"),
+            accounts: vec![],
+            data,
+        }
     }
 
     fn make_eth_sig_and_addr(
@@ -237,7 +501,10 @@ mod tests {
         // Compute the raw message hash exactly as the on-chain code does
         let msg_hash =
             compute_output_root_message_hash(&output_root, base_block_number, total_leaf_count);
+        sign_digest_and_addr(sk_bytes, msg_hash)
+    }
 
+    fn sign_digest_and_addr(sk_bytes: [u8; 32], msg_hash: [u8; 32]) -> ([u8; 65], [u8; 20]) {
         // secp256k1 crate expects 32-byte message; use raw hash (no Ethereum prefix) to match on-chain
         let secp = Secp256k1::new();
         let sk = SecretKey::from_slice(&sk_bytes).unwrap();
@@ -261,27 +528,8 @@ mod tests {
         (sig65, addr)
     }
 
-    fn set_base_oracle_signers_threshold_one(
-        svm: &mut LiteSVM,
-        bridge_pda: Pubkey,
-        addr: [u8; 20],
-    ) {
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.base_oracle_config.threshold = 1;
-        bridge.base_oracle_config.signer_count = 1;
-        let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        fixed_signers[0] = addr;
-        bridge.base_oracle_config.signers = fixed_signers;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
-    }
-
     fn prepare_base_sig_and_set_oracle(
         svm: &mut LiteSVM,
-        bridge_pda: Pubkey,
         sk_bytes: [u8; 32],
         output_root: [u8; 32],
         base_block_number: u64,
@@ -289,7 +537,7 @@ mod tests {
     ) -> [u8; 65] {
         let (sig, addr) =
             make_eth_sig_and_addr(sk_bytes, output_root, base_block_number, total_leaf_count);
-        set_base_oracle_signers_threshold_one(svm, bridge_pda, addr);
+        set_base_oracle_signers(svm, 1, &[addr]);
         sig
     }
 
@@ -310,7 +558,6 @@ mod tests {
         // Configure base oracle with a signer matching our generated signature
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [42u8; 32],
             output_root,
             base_block_number,
@@ -353,7 +600,6 @@ mod tests {
         // Configure base oracle and provide a valid signature
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [43u8; 32],
             output_root,
             base_block_number,
@@ -377,6 +623,8 @@ mod tests {
             .unwrap();
         let root = OutputRoot::try_deserialize(&mut &root_account.data[..]).unwrap();
         assert_eq!(root.total_leaf_count, total_leaf_count);
+        assert_eq!(root.base_block_number, base_block_number);
+        assert_eq!(root.payer, payer.pubkey());
     }
 
     #[test]
@@ -396,7 +644,6 @@ mod tests {
         // Configure base oracle and provide a valid signature
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [44u8; 32],
             output_root,
             base_block_number,
@@ -433,7 +680,7 @@ mod tests {
         // Pause the bridge
         let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
         let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.paused = true;
+        bridge.pause.flags = PAUSE_REGISTER_OUTPUT_ROOT;
         let mut new_data = Vec::new();
         bridge.try_serialize(&mut new_data).unwrap();
         bridge_acc.data = new_data;
@@ -472,7 +719,6 @@ mod tests {
         // Configure base oracle and provide a valid signature so we hit the block interval check
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [45u8; 32],
             output_root,
             base_block_number,
@@ -524,7 +770,6 @@ mod tests {
         // Configure base oracle and provide a valid signature so we hit the monotonicity check
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [46u8; 32],
             output_root,
             base_block_number,
@@ -549,6 +794,100 @@ mod tests {
         assert!(err_str.contains("IncorrectBlockNumber"));
     }
 
+    #[test]
+    fn test_register_output_root_again_after_revocation() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let base_block_number = 600;
+        let total_leaf_count = 10;
+        let bad_root = [5u8; 32];
+        let sig = prepare_base_sig_and_set_oracle(
+            &mut svm,
+            [48u8; 32],
+            bad_root,
+            base_block_number,
+            total_leaf_count,
+        );
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            bad_root,
+            base_block_number,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("register_output_root should succeed");
+
+        // The guardian revokes the bad root and rolls the checkpoint back
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RevokeOutputRoot {
+                output_root: output_root_pda(base_block_number),
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: RevokeOutputRootIx {
+                roll_back_to: Some(0),
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("revoke_output_root should succeed");
+
+        // A corrected root can be registered for the same checkpoint by another payer
+        let corrected_root = [6u8; 32];
+        let other_payer = Keypair::new();
+        svm.airdrop(&other_payer.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        let sig = prepare_base_sig_and_set_oracle(
+            &mut svm,
+            [48u8; 32],
+            corrected_root,
+            base_block_number,
+            total_leaf_count,
+        );
+        send_register(
+            &mut svm,
+            &other_payer,
+            bridge_pda,
+            partner_cfg,
+            corrected_root,
+            base_block_number,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("re-registering a revoked root should succeed");
+
+        let root_account = svm
+            .get_account(&output_root_pda(base_block_number))
+            .unwrap();
+        let root = OutputRoot::try_deserialize(&mut &root_account.data[..]).unwrap();
+        assert_eq!(root.root, corrected_root);
+        assert!(!root.invalidated);
+        // The rent still belongs to the original payer
+        assert_eq!(root.payer, payer.pubkey());
+
+        let bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        assert_eq!(bridge.base_block_number, base_block_number);
+    }
+
     #[test]
     fn test_register_output_root_fails_with_insufficient_base_signatures() {
         let SetupBridgeResult {
@@ -559,18 +898,8 @@ mod tests {
         } = setup_bridge();
         let partner_cfg = write_partner_config_account(&mut svm, &[]);
 
-        // Raise base oracle threshold to 1 and set a dummy signer on the bridge config
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.base_oracle_config.threshold = 1;
-        bridge.base_oracle_config.signer_count = 1;
-        let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        fixed_signers[0] = [7u8; 20];
-        bridge.base_oracle_config.signers = fixed_signers;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        // Raise base oracle threshold to 1 and set a dummy signer in the registry
+        set_base_oracle_signers(&mut svm, 1, &[[7u8; 20]]);
 
         // No signatures provided -> not enough unique approvals
         let result = send_register(
@@ -617,7 +946,6 @@ mod tests {
         let total_leaf_count = 10;
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [47u8; 32],
             output_root,
             base_block_number,
@@ -651,10 +979,7 @@ mod tests {
             ..
         } = setup_bridge();
 
-        // Configure base oracle signers threshold = 2 with 2 authorized addrs on the bridge config
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-
+        // Configure base oracle signers threshold = 2 with 2 authorized addrs in the registry
         // Generate two ECDSA keypairs and signatures
         let sk1 = [1u8; 32];
         let sk2 = [2u8; 32];
@@ -666,16 +991,7 @@ mod tests {
         let (sig2, addr2) =
             make_eth_sig_and_addr(sk2, output_root, base_block_number, total_leaf_count);
 
-        bridge.base_oracle_config.threshold = 2;
-        let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        fixed_signers[0] = addr1;
-        fixed_signers[1] = addr2;
-        bridge.base_oracle_config.signers = fixed_signers;
-        bridge.base_oracle_config.signer_count = 2;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        set_base_oracle_signers(&mut svm, 2, &[addr1, addr2]);
 
         // Partner requires 1 signature and authorizes signer addr1
         let partner_cfg = write_partner_config_account(&mut svm, &[addr1]);
@@ -711,9 +1027,6 @@ mod tests {
         } = setup_bridge();
 
         // Base oracle requires 2 unique approvals, but we will submit the same signer twice
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-
         let sk = [3u8; 32];
         let output_root = [12u8; 32];
         let base_block_number = 900;
@@ -721,15 +1034,21 @@ mod tests {
         let (sig, addr) =
             make_eth_sig_and_addr(sk, output_root, base_block_number, total_leaf_count);
 
-        bridge.base_oracle_config.threshold = 2;
-        let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        fixed_signers[0] = addr;
-        bridge.base_oracle_config.signers = fixed_signers;
-        bridge.base_oracle_config.signer_count = 1;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        // The registry is written directly as a threshold above the total weight is invalid
+        let registry = BaseOracleSigners {
+            threshold: 2,
+            signers: vec![BaseOracleSigner {
+                key: OracleSignerKey::Evm(addr),
+                weight: 1,
+            }],
+            ..BaseOracleSigners::test_new()
+        };
+        let mut data = Vec::new();
+        registry.try_serialize(&mut data).unwrap();
+        let mut registry_acc = svm.get_account(&base_oracle_signers_pda(0)).unwrap();
+        registry_acc.data = data;
+        svm.set_account(base_oracle_signers_pda(0), registry_acc)
+            .unwrap();
 
         // Partner threshold 0; focus on base signer dedup
         let partner_cfg = write_partner_config_account(&mut svm, &[]);
@@ -763,18 +1082,8 @@ mod tests {
         let partner_cfg = write_partner_config_account(&mut svm, &[]);
 
         // Base oracle requires 1 signer but we'll submit an invalid signature (bad v)
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.base_oracle_config.threshold = 1;
         // authorize some dummy address so that threshold logic would pass if signature were valid
-        let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        fixed_signers[0] = [0xAA; 20];
-        bridge.base_oracle_config.signers = fixed_signers;
-        bridge.base_oracle_config.signer_count = 1;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        set_base_oracle_signers(&mut svm, 1, &[[0xAA; 20]]);
 
         let output_root = [13u8; 32];
         let base_block_number = 1200;
@@ -806,4 +1115,451 @@ mod tests {
                 || err_str.contains("custom program error")
         );
     }
+
+    fn set_attestation_and_base_signers(
+        svm: &mut LiteSVM,
+        bridge_pda: Pubkey,
+        attestation: AttestationConfig,
+        signers: &[[u8; 20]],
+        threshold: u16,
+    ) {
+        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        bridge.protocol_config.attestation = attestation;
+        let mut new_data = Vec::new();
+        bridge.try_serialize(&mut new_data).unwrap();
+        bridge_acc.data = new_data;
+        svm.set_account(bridge_pda, bridge_acc).unwrap();
+
+        set_base_oracle_signers(svm, threshold, signers);
+    }
+
+    fn eip712_attestation(format: AttestationFormat) -> AttestationConfig {
+        AttestationConfig {
+            format,
+            base_chain_id: 8453,
+            solana_genesis_hash: [21u8; 32],
+        }
+    }
+
+    #[test]
+    fn test_register_output_root_with_ed25519_and_evm_signers() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let output_root = [31u8; 32];
+        let base_block_number = 300;
+        let total_leaf_count = 4;
+        let (evm_sig, evm_addr) =
+            make_eth_sig_and_addr([7u8; 32], output_root, base_block_number, total_leaf_count);
+        let ed25519_signer = Keypair::new();
+        set_base_oracle_epoch(
+            &mut svm,
+            &BaseOracleSigners {
+                threshold: 2,
+                signers: vec![
+                    BaseOracleSigner {
+                        key: OracleSignerKey::Evm(evm_addr),
+                        weight: 1,
+                    },
+                    BaseOracleSigner {
+                        key: OracleSignerKey::Ed25519(ed25519_signer.pubkey()),
+                        weight: 1,
+                    },
+                ],
+                ..BaseOracleSigners::test_new()
+            },
+        );
+
+        let digest =
+            compute_output_root_message_hash(&output_root, base_block_number, total_leaf_count);
+        let register = |instructions| {
+            register_ix(
+                payer.pubkey(),
+                bridge_pda,
+                partner_cfg,
+                0,
+                instructions,
+                output_root,
+                base_block_number,
+                total_leaf_count,
+                vec![evm_sig],
+            )
+        };
+        let expect_insufficient = |result: std::result::Result<TransactionMetadata, _>| {
+            let error_string = format!("{:?}", result.unwrap_err());
+            assert!(
+                error_string.contains("InsufficientBaseSignatures"),
+                "Expected InsufficientBaseSignatures error, got: {}",
+                error_string
+            );
+        };
+
+        // Without the instructions sysvar the Ed25519 attestation is not seen
+        expect_insufficient(send_ixs(
+            &mut svm,
+            &payer,
+            &[ed25519_verify_ix(&ed25519_signer, &digest), register(None)],
+        ));
+
+        // An Ed25519 signature over another message does not count
+        expect_insufficient(send_ixs(
+            &mut svm,
+            &payer,
+            &[
+                ed25519_verify_ix(&ed25519_signer, &[0u8; 32]),
+                register(Some(sysvar::instructions::ID)),
+            ],
+        ));
+
+        send_ixs(
+            &mut svm,
+            &payer,
+            &[
+                ed25519_verify_ix(&ed25519_signer, &digest),
+                register(Some(sysvar::instructions::ID)),
+            ],
+        )
+        .expect("EVM and Ed25519 approvals should reach the threshold together");
+
+        let root_acc = svm
+            .get_account(&output_root_pda(base_block_number))
+            .unwrap();
+        let root = OutputRoot::try_deserialize(&mut &root_acc.data[..]).unwrap();
+        assert_eq!(root.root, output_root);
+    }
+
+    #[test]
+    fn test_register_output_root_with_secp256k1_precompile() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let output_root = [32u8; 32];
+        let base_block_number = 300;
+        let total_leaf_count = 5;
+        let preimage =
+            output_root_message_preimage(output_root, base_block_number, total_leaf_count);
+        let digest =
+            compute_output_root_message_hash(&output_root, base_block_number, total_leaf_count);
+        assert_eq!(keccak_hash(&preimage).0, digest);
+
+        let base = sign_digest_and_addr([8u8; 32], digest);
+        let partner = sign_digest_and_addr([9u8; 32], digest);
+        set_base_oracle_signers(&mut svm, 1, &[base.1]);
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge.partner_oracle_config.required_threshold = 1;
+        let mut data = Vec::new();
+        bridge.try_serialize(&mut data).unwrap();
+        bridge_account.data = data;
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+        let partner_cfg = write_partner_config_account(&mut svm, &[partner.1]);
+
+        let register = register_ix(
+            payer.pubkey(),
+            bridge_pda,
+            partner_cfg,
+            0,
+            Some(sysvar::instructions::ID),
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![],
+        );
+
+        // A precompile signature over another message does not count
+        let other = output_root_message_preimage([0u8; 32], base_block_number, total_leaf_count);
+        let other_sigs = [
+            sign_digest_and_addr([8u8; 32], keccak_hash(&other).0),
+            sign_digest_and_addr([9u8; 32], keccak_hash(&other).0),
+        ];
+        let error_string = format!(
+            "{:?}",
+            send_ixs(
+                &mut svm,
+                &payer,
+                &[
+                    secp256k1_verify_ix(&other_sigs, &other, 0),
+                    register.clone()
+                ],
+            )
+            .unwrap_err()
+        );
+        assert!(
+            error_string.contains("InsufficientBaseSignatures"),
+            "Expected InsufficientBaseSignatures error, got: {}",
+            error_string
+        );
+
+        // Base and partner approvals are both read from the precompile instruction
+        send_ixs(
+            &mut svm,
+            &payer,
+            &[
+                secp256k1_verify_ix(&[base, partner], &preimage, 0),
+                register,
+            ],
+        )
+        .expect("register_output_root should accept precompile-verified signatures");
+
+        let root_acc = svm
+            .get_account(&output_root_pda(base_block_number))
+            .unwrap();
+        let root = OutputRoot::try_deserialize(&mut &root_acc.data[..]).unwrap();
+        assert_eq!(root.root, output_root);
+    }
+
+    #[test]
+    fn test_secp256k1_precompile_uses_less_compute_than_syscall_for_16_signers() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let keys: Vec<[u8; 32]> = (1..=16u8).map(|i| [i; 32]).collect();
+        let (_, addrs): (Vec<_>, Vec<_>) = keys
+            .iter()
+            .map(|sk| make_eth_sig_and_addr(*sk, [0u8; 32], 0, 0))
+            .unzip();
+        set_base_oracle_signers(&mut svm, 16, &addrs);
+
+        let signatures_for = |output_root, base_block_number, total_leaf_count| {
+            let digest =
+                compute_output_root_message_hash(&output_root, base_block_number, total_leaf_count);
+            keys.iter()
+                .map(|sk| sign_digest_and_addr(*sk, digest))
+                .collect::<Vec<_>>()
+        };
+
+        // Syscall path: every signature is recovered by the program
+        let (output_root, base_block_number, total_leaf_count) = ([41u8; 32], 300, 6);
+        let signatures = signatures_for(output_root, base_block_number, total_leaf_count);
+        let syscall_meta = send_ixs(
+            &mut svm,
+            &payer,
+            &[
+                compute_unit_limit_ix(1_400_000),
+                register_ix(
+                    payer.pubkey(),
+                    bridge_pda,
+                    partner_cfg,
+                    0,
+                    None,
+                    output_root,
+                    base_block_number,
+                    total_leaf_count,
+                    signatures.iter().map(|(sig, _)| *sig).collect(),
+                ),
+            ],
+        )
+        .expect("syscall path should succeed");
+
+        // Precompile path: the program only inspects the verified Secp256k1 instruction
+        let (output_root, base_block_number, total_leaf_count) = ([42u8; 32], 600, 7);
+        let signatures = signatures_for(output_root, base_block_number, total_leaf_count);
+        let preimage =
+            output_root_message_preimage(output_root, base_block_number, total_leaf_count);
+        let precompile_meta = send_ixs(
+            &mut svm,
+            &payer,
+            &[
+                compute_unit_limit_ix(1_400_000),
+                secp256k1_verify_ix(&signatures, &preimage, 1),
+                register_ix(
+                    payer.pubkey(),
+                    bridge_pda,
+                    partner_cfg,
+                    0,
+                    Some(sysvar::instructions::ID),
+                    output_root,
+                    base_block_number,
+                    total_leaf_count,
+                    vec![],
+                ),
+            ],
+        )
+        .expect("precompile path should succeed");
+
+        assert!(
+            precompile_meta.compute_units_consumed * 4 < syscall_meta.compute_units_consumed,
+            "precompile path should use a fraction of the syscall path's compute"
+        );
+    }
+
+    #[test]
+    fn test_register_output_root_with_rotated_signer_epochs() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        // Epoch 1 activates at block 900; epoch 0 keeps being accepted until block 1200
+        let old_sk = [5u8; 32];
+        let new_sk = [6u8; 32];
+        let (_, old_addr) = make_eth_sig_and_addr(old_sk, [0u8; 32], 0, 0);
+        let (_, new_addr) = make_eth_sig_and_addr(new_sk, [0u8; 32], 0, 0);
+        let epoch = |epoch, activation_block_number, expiry_block_number, addr| BaseOracleSigners {
+            epoch,
+            activation_block_number,
+            expiry_block_number,
+            threshold: 1,
+            signers: vec![BaseOracleSigner {
+                key: OracleSignerKey::Evm(addr),
+                weight: 1,
+            }],
+        };
+        set_base_oracle_epoch(&mut svm, &epoch(0, 0, 1200, old_addr));
+        set_base_oracle_epoch(&mut svm, &epoch(1, 900, u64::MAX, new_addr));
+
+        let output_root = [14u8; 32];
+        let total_leaf_count = 3;
+        let register = |svm: &mut LiteSVM, epoch: u64, sk: [u8; 32], base_block_number: u64| {
+            let (sig, _) =
+                make_eth_sig_and_addr(sk, output_root, base_block_number, total_leaf_count);
+            send_register_for_epoch(
+                svm,
+                &payer,
+                bridge_pda,
+                partner_cfg,
+                epoch,
+                output_root,
+                base_block_number,
+                total_leaf_count,
+                vec![sig],
+            )
+        };
+
+        // Before activation only the old set is in charge
+        let err = register(&mut svm, 1, new_sk, 600).unwrap_err();
+        assert!(format!("{:?}", err).contains("BaseOracleEpochMismatch"));
+        register(&mut svm, 0, old_sk, 600).expect("old set should register before rotation");
+
+        // During the overlap both sets are accepted
+        register(&mut svm, 0, old_sk, 900).expect("old set should register during overlap");
+        register(&mut svm, 1, new_sk, 1200).expect("new set should register after activation");
+
+        // Once the overlap has elapsed the old set is rejected
+        let err = register(&mut svm, 0, old_sk, 1500).unwrap_err();
+        assert!(format!("{:?}", err).contains("BaseOracleEpochMismatch"));
+        register(&mut svm, 1, new_sk, 1500).expect("new set should register after overlap");
+    }
+
+    #[test]
+    fn test_register_output_root_with_eip712_attestation() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let output_root = [13u8; 32];
+        let base_block_number = 600;
+        let total_leaf_count = 4;
+        let attestation = eip712_attestation(AttestationFormat::Eip712);
+
+        let (typed_sig, addr) = sign_digest_and_addr(
+            [4u8; 32],
+            compute_typed_output_root_hash(
+                &attestation,
+                b"OutputRoot(bytes32 root,uint64 baseBlockNumber,uint64 totalLeafCount)",
+                &output_root,
+                base_block_number,
+                total_leaf_count,
+            ),
+        );
+        let (legacy_sig, _) =
+            make_eth_sig_and_addr([4u8; 32], output_root, base_block_number, total_leaf_count);
+        set_attestation_and_base_signers(&mut svm, bridge_pda, attestation, &[addr], 1);
+
+        // Legacy signatures are no longer accepted
+        let result = send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![legacy_sig],
+        );
+        let err_str = format!("{:?}", result.unwrap_err());
+        assert!(err_str.contains("InsufficientBaseSignatures"));
+
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![typed_sig],
+        )
+        .expect("register_output_root should succeed with an EIP-712 signature");
+    }
+
+    #[test]
+    fn test_register_output_root_accepts_both_formats_during_migration() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let output_root = [14u8; 32];
+        let base_block_number = 900;
+        let total_leaf_count = 6;
+        let attestation = eip712_attestation(AttestationFormat::LegacyAndEip712);
+
+        // One signer has migrated to EIP-712, the other still signs the legacy message
+        let (typed_sig, typed_addr) = sign_digest_and_addr(
+            [5u8; 32],
+            compute_typed_output_root_hash(
+                &attestation,
+                b"OutputRoot(bytes32 root,uint64 baseBlockNumber,uint64 totalLeafCount)",
+                &output_root,
+                base_block_number,
+                total_leaf_count,
+            ),
+        );
+        let (legacy_sig, legacy_addr) =
+            make_eth_sig_and_addr([6u8; 32], output_root, base_block_number, total_leaf_count);
+        set_attestation_and_base_signers(
+            &mut svm,
+            bridge_pda,
+            attestation,
+            &[typed_addr, legacy_addr],
+            2,
+        );
+
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![typed_sig, legacy_sig],
+        )
+        .expect("register_output_root should accept both formats during migration");
+    }
 }
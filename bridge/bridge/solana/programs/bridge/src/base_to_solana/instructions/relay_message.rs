@@ -2,80 +2,1086 @@ use anchor_lang::{
     prelude::*,
     solana_program::{self},
 };
+use anchor_spl::{
+    token::ID as TOKEN_PROGRAM_ID, token_2022::ID as TOKEN_2022_PROGRAM_ID,
+    token_interface::TokenAccount,
+};
 
 use crate::base_to_solana::{
-    constants::BRIDGE_CPI_AUTHORITY_SEED, state::IncomingMessage, Message, Transfer,
+    constants::{
+        BRIDGE_CPI_AUTHORITY_SEED, CPI_POLICY_SEED, INCOMING_MESSAGE_SEED, NONCE_BITMAP_SEED,
+        RATE_LIMIT_SEED,
+    },
+    state::{CpiPolicy, CpiPolicyConfig, IncomingMessage, MintRateLimit, NonceBitmap},
+    Ix, LegacyIncomingMessage, Message, Transfer,
+};
+use crate::common::{
+    bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_RELAY_MESSAGE, SOL_VAULT_SEED,
 };
-use crate::common::{bridge::Bridge, BRIDGE_SEED};
-use crate::BridgeError;
+use crate::{BridgeError, MessageRelayed, MessageStepRelayed};
 
 /// Accounts struct for the relay message instruction that executes cross-chain messages from Base to Solana.
 /// This instruction processes incoming messages that contain either pure instruction calls or token transfers
 /// with additional instructions. The message execution is performed through CPI calls using a bridge authority.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RelayMessage<'info> {
+    /// The account that pays for the nonce bitmap page if it does not exist yet.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     /// The incoming message account containing the cross-chain message to be executed.
     /// - Contains either a pure call message or a transfer message with additional instructions
     /// - Must be mutable to mark the message as executed after processing
-    /// - Prevents replay attacks by tracking execution status
-    #[account(mut)]
+    /// - Messages still in the legacy layout must be migrated with `migrate_incoming_message` first
+    #[account(
+        mut,
+        seeds = [INCOMING_MESSAGE_SEED, message.message_hash.as_ref()],
+        bump,
+        constraint = !LegacyIncomingMessage::is_legacy(&message.to_account_info())?
+            @ BridgeError::IncomingMessageNotMigrated
+    )]
     pub message: Account<'info, IncomingMessage>,
 
+    /// The replay protection page covering the message nonce.
+    /// - Uses PDA with NONCE_BITMAP_SEED and the page index of the nonce
+    /// - Created by the first relay of a nonce in the page
+    /// - Prevents replay attacks by tracking execution status per Base nonce
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + NonceBitmap::INIT_SPACE,
+        seeds = [NONCE_BITMAP_SEED, &NonceBitmap::page(message.nonce).to_le_bytes()],
+        bump
+    )]
+    pub nonce_bitmap: Account<'info, NonceBitmap>,
+
     /// The main bridge state account used to check pause status
     /// - Uses PDA with BRIDGE_SEED for deterministic address
     #[account(seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
+
+    /// The withdrawal rate limit PDA of the mint released by a transfer message.
+    /// - Required only for transfers of mints in `bridge.rate_limited_mints`, and must then be
+    ///   derived from `[RATE_LIMIT_SEED, mint]`
+    /// - Debited by the transfer amount; transfers of other mints may omit it
+    /// - Ignored for pure call messages
+    /// CHECK: Address and ownership are validated in the handler.
+    #[account(mut)]
+    pub rate_limit: Option<UncheckedAccount<'info>>,
+
+    /// The guardian-managed policy restricting the programs the message instructions can target.
+    /// - Must be derived from `[CPI_POLICY_SEED]`
+    /// - If the PDA has not been initialized, the default policy (an empty denylist) applies
+    /// CHECK: Address is validated by the seeds constraint and ownership in the handler.
+    #[account(seeds = [CPI_POLICY_SEED], bump)]
+    pub cpi_policy: UncheckedAccount<'info>,
+
+    /// System program required for creating the nonce bitmap page.
+    pub system_program: Program<'info, System>,
 }
 
 pub fn relay_message_handler<'a, 'info>(
     ctx: Context<'a, '_, 'info, 'info, RelayMessage<'info>>,
+    max_ixs: Option<u32>,
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    // Check if this instruction family is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_RELAY_MESSAGE)?;
 
     require!(!ctx.accounts.message.executed, BridgeError::AlreadyExecuted);
 
     let message = ctx.accounts.message.message.clone();
-    let (transfer, ixs) = match message {
-        Message::Call(ixs) => (None, ixs),
-        Message::Transfer { transfer, ixs } => (Some(transfer), ixs),
+    let sender = ctx.accounts.message.sender;
+    match message {
+        Message::Resumable { transfer, ixs } => {
+            // The first step consumes the nonce and finalizes the transfer
+            let first_ix = match ctx.accounts.message.cursor {
+                Some(cursor) => cursor,
+                None => {
+                    ctx.accounts
+                        .nonce_bitmap
+                        .consume(ctx.accounts.message.nonce)?;
+                    if let Some(transfer) = &transfer {
+                        finalize_transfer(
+                            transfer,
+                            &ctx.accounts.payer.key(),
+                            0,
+                            &ctx.accounts.bridge,
+                            ctx.accounts.rate_limit.as_deref(),
+                            ctx.remaining_accounts,
+                            ctx.program_id,
+                        )?;
+                    }
+                    0
+                }
+            };
+
+            let ix_count = ixs.len() as u32;
+            let next_ix = max_ixs.map_or(ix_count, |max_ixs| {
+                first_ix.saturating_add(max_ixs).min(ix_count)
+            });
+            // Continuation steps must make progress
+            require!(
+                next_ix > first_ix || ctx.accounts.message.cursor.is_none(),
+                BridgeError::EmptyRelayStep
+            );
+
+            ctx.accounts.message.cursor = Some(next_ix);
+            ctx.accounts.message.executed = next_ix == ix_count;
+
+            invoke_ixs(
+                ixs.into_iter()
+                    .skip(first_ix as usize)
+                    .take((next_ix - first_ix) as usize),
+                &sender,
+                &ctx.accounts.cpi_policy,
+                ctx.remaining_accounts,
+                ctx.program_id,
+            )?;
+
+            emit_cpi!(MessageStepRelayed {
+                incoming_message: ctx.accounts.message.key(),
+                first_ix,
+                next_ix,
+                ix_count,
+            });
+        }
+        message => {
+            ctx.accounts
+                .nonce_bitmap
+                .consume(ctx.accounts.message.nonce)?;
+
+            ctx.accounts.message.executed = true;
+
+            execute_message(
+                message,
+                &sender,
+                &ctx.accounts.payer.key(),
+                &ctx.accounts.bridge,
+                ctx.accounts.rate_limit.as_deref(),
+                &ctx.accounts.cpi_policy,
+                ctx.remaining_accounts,
+                ctx.program_id,
+            )?;
+        }
+    }
+
+    emit_cpi!(MessageRelayed {
+        incoming_message: ctx.accounts.message.key(),
+        sender,
+        nonce: ctx.accounts.message.nonce,
+        message_hash: ctx.accounts.message.message_hash,
+        executed: ctx.accounts.message.executed,
+    });
+
+    Ok(())
+}
+
+/// Executes a proven message payload in full: finalizes its transfer, if any, then runs all its
+/// instructions. Resumable messages are executed atomically as well.
+///
+/// `relayer` is the signer executing the message and receives the relayer fee of the transfer.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn execute_message<'info>(
+    message: Message,
+    sender: &[u8; 20],
+    relayer: &Pubkey,
+    bridge: &Bridge,
+    rate_limit: Option<&AccountInfo<'info>>,
+    cpi_policy: &AccountInfo<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let (transfer, ixs, relayer_fee) = match message {
+        Message::Call(ixs) => (None, ixs, 0),
+        Message::Transfer { transfer, ixs } => (Some(transfer), ixs, 0),
+        Message::Resumable { transfer, ixs } => (transfer, ixs, 0),
+        Message::TransferWithRelayerFee {
+            transfer,
+            ixs,
+            relayer_fee,
+        } => (Some(transfer), ixs, relayer_fee),
     };
 
     // Process the transfer if it exists
-    if let Some(transfer) = transfer {
-        match transfer {
-            Transfer::Sol(transfer) => transfer.finalize(ctx.remaining_accounts)?,
-            Transfer::Spl(transfer) => transfer.finalize(ctx.remaining_accounts)?,
-            Transfer::WrappedToken(transfer) => transfer.finalize(ctx.remaining_accounts)?,
-        };
+    if let Some(transfer) = &transfer {
+        finalize_transfer(
+            transfer,
+            relayer,
+            relayer_fee,
+            bridge,
+            rate_limit,
+            remaining_accounts,
+            program_id,
+        )?;
     }
 
-    ctx.accounts.message.executed = true;
+    invoke_ixs(ixs, sender, cpi_policy, remaining_accounts, program_id)
+}
+
+/// Releases the funds of a transfer after checking its mint pause and rate limit. The whole
+/// amount counts against the rate limit, including the part paid to the relayer.
+fn finalize_transfer<'info>(
+    transfer: &Transfer,
+    relayer: &Pubkey,
+    relayer_fee: u64,
+    bridge: &Bridge,
+    rate_limit: Option<&AccountInfo<'info>>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    program_id: &Pubkey,
+) -> Result<()> {
+    bridge
+        .pause
+        .require_mint_not_paused(&transfer.local_token())?;
+
+    debit_rate_limit(rate_limit, bridge, transfer, program_id)?;
+
+    match transfer {
+        Transfer::Sol(transfer) => transfer.finalize(remaining_accounts, relayer, relayer_fee),
+        Transfer::Spl(transfer) => transfer.finalize(remaining_accounts, relayer, relayer_fee),
+        Transfer::WrappedToken(transfer) => {
+            transfer.finalize(remaining_accounts, relayer, relayer_fee)
+        }
+    }
+}
+
+/// Runs instructions through CPIs signed by the bridge CPI authority derived from the message
+/// sender, after checking each target program against the CPI policy.
+fn invoke_ixs<'info>(
+    ixs: impl IntoIterator<Item = Ix>,
+    sender: &[u8; 20],
+    cpi_policy: &AccountInfo<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let mut ixs = ixs.into_iter().peekable();
+    if ixs.peek().is_none() {
+        return Ok(());
+    }
+
+    let cpi_policy = load_cpi_policy(cpi_policy, program_id)?;
+    let (sol_vault, _) = Pubkey::find_program_address(&[SOL_VAULT_SEED], program_id);
 
     // Derive the bridge CPI authority PDA tied to the message sender; used to sign all downstream CPIs.
-    let (_, bump) = Pubkey::find_program_address(
-        &[
-            BRIDGE_CPI_AUTHORITY_SEED,
-            ctx.accounts.message.sender.as_ref(),
-        ],
-        ctx.program_id,
-    );
+    let (_, bump) =
+        Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()], program_id);
 
-    let bridge_cpi_authority_seeds: &[&[u8]] = &[
-        BRIDGE_CPI_AUTHORITY_SEED,
-        ctx.accounts.message.sender.as_ref(),
-        &[bump],
-    ];
+    let bridge_cpi_authority_seeds: &[&[u8]] =
+        &[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref(), &[bump]];
 
     // Execute the provided downstream instructions via signed CPI
     for ix in ixs {
+        // The bridge program is denied whatever the policy
+        if ix.program_id == *program_id || !cpi_policy.is_allowed(&ix.program_id) {
+            msg!("CPI into {} rejected by the CPI policy", ix.program_id);
+            return err!(BridgeError::CpiProgramNotAllowed);
+        }
+        require_no_vault_access(&ix, &sol_vault, remaining_accounts)?;
+
         // NOTE: We always do a signed CPI even if the actual program CPIed into might not require the bridge authority signer.
         solana_program::program::invoke_signed(
             &ix.into(),
-            ctx.remaining_accounts,
+            remaining_accounts,
             &[bridge_cpi_authority_seeds],
         )?;
     }
 
     Ok(())
 }
+
+/// Rejects `ix` if it passes a bridge vault as signer or writable, whatever the CPI policy.
+fn require_no_vault_access(
+    ix: &Ix,
+    sol_vault: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    for account in ix.accounts.iter() {
+        if !account.is_signer && !account.is_writable {
+            continue;
+        }
+
+        let is_vault = account.pubkey == *sol_vault
+            || remaining_accounts
+                .iter()
+                .find(|info| info.key == &account.pubkey)
+                .is_some_and(is_token_vault);
+        if is_vault {
+            msg!(
+                "Relayed instruction passes the bridge vault {}",
+                account.pubkey
+            );
+            return err!(BridgeError::BridgeVaultNotAllowed);
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `info` is a token account that is its own authority, as the bridge token vaults are.
+/// Their address is also seeded by the remote token, which is unknown here, so any such account is
+/// treated as a vault.
+fn is_token_vault(info: &AccountInfo) -> bool {
+    if info.owner != &TOKEN_PROGRAM_ID && info.owner != &TOKEN_2022_PROGRAM_ID {
+        return false;
+    }
+    let Ok(data) = info.try_borrow_data() else {
+        return false;
+    };
+    TokenAccount::try_deserialize(&mut &data[..]).is_ok_and(|token| token.owner == *info.key)
+}
+
+/// Reads the CPI policy, falling back to the default one if the guardian has not configured it.
+fn load_cpi_policy(cpi_policy_info: &AccountInfo, program_id: &Pubkey) -> Result<CpiPolicyConfig> {
+    if cpi_policy_info.owner != program_id {
+        return Ok(CpiPolicyConfig::default());
+    }
+
+    let cpi_policy = CpiPolicy::try_deserialize(&mut &cpi_policy_info.data.borrow()[..])?;
+    Ok(cpi_policy.config)
+}
+
+/// Debits the transfer amount from the mint's rate limit, if the guardian configured one.
+/// The rate limit account is only required for mints recorded in `bridge.rate_limited_mints`.
+fn debit_rate_limit(
+    rate_limit_info: Option<&AccountInfo>,
+    bridge: &Bridge,
+    transfer: &Transfer,
+    program_id: &Pubkey,
+) -> Result<()> {
+    let mint = transfer.local_token();
+    if !bridge.rate_limited_mints.contains(&mint) {
+        return Ok(());
+    }
+
+    let rate_limit_info = rate_limit_info.ok_or(BridgeError::IncorrectRateLimitAccount)?;
+    let (rate_limit_pda, _) =
+        Pubkey::find_program_address(&[RATE_LIMIT_SEED, mint.as_ref()], program_id);
+    require_keys_eq!(
+        rate_limit_info.key(),
+        rate_limit_pda,
+        BridgeError::IncorrectRateLimitAccount
+    );
+    require_keys_eq!(
+        *rate_limit_info.owner,
+        *program_id,
+        BridgeError::IncorrectRateLimitAccount
+    );
+
+    let mut rate_limit = MintRateLimit::try_deserialize(&mut &rate_limit_info.data.borrow()[..])?;
+    rate_limit.consume(transfer.amount(), Clock::get()?.unix_timestamp)?;
+    rate_limit.try_serialize(&mut &mut rate_limit_info.data.borrow_mut()[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_instruction},
+        system_program, Discriminator, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer as _;
+
+    use crate::{
+        accounts,
+        base_to_solana::{hash_message, token::FinalizeBridgeSol, CpiPolicyMode, RateLimitConfig},
+        common::TOKEN_VAULT_SEED,
+        instruction::{
+            CloseIncomingMessage as CloseIncomingMessageIx, RelayMessage as RelayMessageIx,
+        },
+        solana_to_base::NATIVE_SOL_PUBKEY,
+        test_utils::{
+            assert_error, cpi_policy_pda, create_mock_token_account, event_authority_pda,
+            find_cpi_event, send, setup_bridge, SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const NONCE: u64 = 9;
+    const SENDER: [u8; 20] = [7u8; 20];
+    const LAMPORTS_PER_IX: u64 = 1_000;
+
+    fn nonce_bitmap_pda() -> Pubkey {
+        Pubkey::find_program_address(
+            &[NONCE_BITMAP_SEED, &NonceBitmap::page(NONCE).to_le_bytes()],
+            &ID,
+        )
+        .0
+    }
+
+    /// Funds the sender's bridge CPI authority and returns `count` instructions that each move
+    /// `LAMPORTS_PER_IX` from it to `recipient`
+    fn transfer_ixs(svm: &mut LiteSVM, recipient: Pubkey, count: usize) -> Vec<Ix> {
+        let cpi_authority =
+            Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, &SENDER], &ID).0;
+        svm.airdrop(&cpi_authority, 1_000_000_000).unwrap();
+        (0..count)
+            .map(|_| {
+                system_instruction::transfer(&cpi_authority, &recipient, LAMPORTS_PER_IX).into()
+            })
+            .collect()
+    }
+
+    /// Writes a proven incoming message holding `message`
+    fn write_incoming_message(svm: &mut LiteSVM, payer: Pubkey, message: Message) -> Pubkey {
+        let message_hash = hash_message(
+            &NONCE.to_be_bytes(),
+            &SENDER,
+            &message.try_to_vec().unwrap(),
+        );
+        let incoming_message =
+            Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &message_hash], &ID).0;
+        let mut data = Vec::new();
+        IncomingMessage {
+            sender: SENDER,
+            nonce: NONCE,
+            message_hash,
+            payer,
+            message,
+            cursor: None,
+            proven_at: 0,
+            executed: false,
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        svm.set_account(
+            incoming_message,
+            SvmAccount {
+                lamports: 10_000_000,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+        incoming_message
+    }
+
+    fn read_incoming_message(svm: &LiteSVM, incoming_message: &Pubkey) -> IncomingMessage {
+        let account = svm.get_account(incoming_message).unwrap();
+        IncomingMessage::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    fn relay_ix(
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        incoming_message: Pubkey,
+        recipient: Pubkey,
+        max_ixs: Option<u32>,
+    ) -> Instruction {
+        let mut accounts = accounts::RelayMessage {
+            payer: payer.pubkey(),
+            message: incoming_message,
+            nonce_bitmap: nonce_bitmap_pda(),
+            bridge: bridge_pda,
+            rate_limit: None,
+            cpi_policy: cpi_policy_pda(),
+            system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
+        }
+        .to_account_metas(None);
+        let cpi_authority =
+            Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, &SENDER], &ID).0;
+        accounts.extend([
+            AccountMeta::new(cpi_authority, false),
+            AccountMeta::new(recipient, false),
+        ]);
+
+        Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx { max_ixs }.data(),
+        }
+    }
+
+    #[test]
+    fn test_relay_resumable_message_in_steps() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let recipient = Pubkey::new_unique();
+        let ixs = transfer_ixs(&mut svm, recipient, 3);
+        let incoming_message = write_incoming_message(
+            &mut svm,
+            payer.pubkey(),
+            Message::Resumable {
+                transfer: None,
+                ixs,
+            },
+        );
+
+        // First step: consumes the nonce and runs a single instruction
+        let meta = send(
+            &mut svm,
+            &payer,
+            relay_ix(&payer, bridge_pda, incoming_message, recipient, Some(1)),
+        )
+        .expect("first relay step should succeed");
+        let step = find_cpi_event::<MessageStepRelayed>(&meta)
+            .expect("MessageStepRelayed event should be emitted");
+        assert_eq!(step.incoming_message, incoming_message);
+        assert_eq!((step.first_ix, step.next_ix, step.ix_count), (0, 1, 3));
+        let relayed = find_cpi_event::<MessageRelayed>(&meta)
+            .expect("MessageRelayed event should be emitted");
+        assert_eq!(relayed.nonce, NONCE);
+        assert_eq!(
+            relayed.message_hash,
+            read_incoming_message(&svm, &incoming_message).message_hash
+        );
+        assert!(!relayed.executed);
+
+        let message = read_incoming_message(&svm, &incoming_message);
+        assert_eq!(message.cursor, Some(1));
+        assert!(message.is_in_progress());
+        assert_eq!(svm.get_balance(&recipient).unwrap(), LAMPORTS_PER_IX);
+        let bitmap_account = svm.get_account(&nonce_bitmap_pda()).unwrap();
+        let bitmap = NonceBitmap::try_deserialize(&mut &bitmap_account.data[..]).unwrap();
+        assert!(bitmap.is_consumed(NONCE));
+
+        // A message in progress cannot be closed
+        let close_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::CloseIncomingMessage {
+                message: incoming_message,
+                nonce_bitmap: nonce_bitmap_pda(),
+                payer: payer.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: CloseIncomingMessageIx {}.data(),
+        };
+        assert_error(
+            send(&mut svm, &payer, close_ix.clone()),
+            "MessageNotExecuted",
+        );
+
+        // Continuation steps must run at least one instruction
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                relay_ix(&payer, bridge_pda, incoming_message, recipient, Some(0)),
+            ),
+            "EmptyRelayStep",
+        );
+
+        // Second step: resumes from the cursor and runs the remaining instructions
+        let meta = send(
+            &mut svm,
+            &payer,
+            relay_ix(&payer, bridge_pda, incoming_message, recipient, Some(10)),
+        )
+        .expect("second relay step should succeed");
+        let step = find_cpi_event::<MessageStepRelayed>(&meta)
+            .expect("MessageStepRelayed event should be emitted");
+        assert_eq!((step.first_ix, step.next_ix, step.ix_count), (1, 3, 3));
+
+        let message = read_incoming_message(&svm, &incoming_message);
+        assert_eq!(message.cursor, Some(3));
+        assert!(message.executed);
+        assert_eq!(svm.get_balance(&recipient).unwrap(), 3 * LAMPORTS_PER_IX);
+
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                relay_ix(&payer, bridge_pda, incoming_message, recipient, None),
+            ),
+            "AlreadyExecuted",
+        );
+        send(&mut svm, &payer, close_ix).expect("close should succeed once executed");
+    }
+
+    #[test]
+    fn test_relay_transfer_pays_relayer_fee() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        svm.airdrop(&sol_vault, 1_000_000_000).unwrap();
+        let rate_limit =
+            Pubkey::find_program_address(&[RATE_LIMIT_SEED, NATIVE_SOL_PUBKEY.as_ref()], &ID).0;
+        let to = Pubkey::new_unique();
+        let relayer_balance = |svm: &LiteSVM| svm.get_balance(&payer.pubkey()).unwrap();
+
+        let transfer_ix = |svm: &mut LiteSVM, relayer_fee: u64, fee_receiver: Pubkey| {
+            let incoming_message = write_incoming_message(
+                svm,
+                payer.pubkey(),
+                Message::TransferWithRelayerFee {
+                    transfer: Transfer::Sol(FinalizeBridgeSol {
+                        to,
+                        amount: 10_000_000,
+                    }),
+                    ixs: vec![],
+                    relayer_fee,
+                },
+            );
+            let mut accounts = accounts::RelayMessage {
+                payer: payer.pubkey(),
+                message: incoming_message,
+                nonce_bitmap: nonce_bitmap_pda(),
+                bridge: bridge_pda,
+                rate_limit: Some(rate_limit),
+                cpi_policy: cpi_policy_pda(),
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None);
+            accounts.extend([
+                AccountMeta::new(sol_vault, false),
+                AccountMeta::new(to, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new(fee_receiver, false),
+            ]);
+            Instruction {
+                program_id: ID,
+                accounts,
+                data: RelayMessageIx { max_ixs: None }.data(),
+            }
+        };
+
+        // The fee cannot exceed the transferred amount
+        let ix = transfer_ix(&mut svm, 10_000_001, payer.pubkey());
+        assert_error(send(&mut svm, &payer, ix), "RelayerFeeExceedsAmount");
+
+        // The fee can only be paid to the relayer executing the message
+        let ix = transfer_ix(&mut svm, 1_000_000, Pubkey::new_unique());
+        assert_error(send(&mut svm, &payer, ix), "IncorrectRelayerFeeReceiver");
+
+        let ix = transfer_ix(&mut svm, 1_000_000, payer.pubkey());
+        let balance_before = relayer_balance(&svm);
+        let meta = send(&mut svm, &payer, ix).expect("relay should succeed");
+
+        assert_eq!(svm.get_balance(&to).unwrap(), 9_000_000);
+        assert_eq!(svm.get_balance(&sol_vault).unwrap(), 990_000_000);
+        // The relayer also paid the nonce bitmap rent and the 5000 lamport signature fee
+        let bitmap_rent = svm.get_balance(&nonce_bitmap_pda()).unwrap();
+        assert_eq!(
+            relayer_balance(&svm),
+            balance_before + 1_000_000 - bitmap_rent - 5_000
+        );
+        assert!(find_cpi_event::<MessageRelayed>(&meta).is_some());
+    }
+
+    /// Returns a relay instruction for a new incoming message moving `amount` lamports out of the
+    /// SOL vault, passing `rate_limit` as the rate limit account
+    fn sol_transfer_ix(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        amount: u64,
+        rate_limit: Option<Pubkey>,
+    ) -> Instruction {
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let to = Pubkey::new_unique();
+        let incoming_message = write_incoming_message(
+            svm,
+            payer.pubkey(),
+            Message::Transfer {
+                transfer: Transfer::Sol(FinalizeBridgeSol { to, amount }),
+                ixs: vec![],
+            },
+        );
+        let mut accounts = accounts::RelayMessage {
+            payer: payer.pubkey(),
+            message: incoming_message,
+            nonce_bitmap: nonce_bitmap_pda(),
+            bridge: bridge_pda,
+            rate_limit,
+            cpi_policy: cpi_policy_pda(),
+            system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
+        }
+        .to_account_metas(None);
+        accounts.extend([
+            AccountMeta::new(sol_vault, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ]);
+        Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx { max_ixs: None }.data(),
+        }
+    }
+
+    fn rate_limit_pda(mint: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[RATE_LIMIT_SEED, mint.as_ref()], &ID).0
+    }
+
+    /// Writes a native SOL rate limit with `available` lamports left in the current window and
+    /// records native SOL as rate limited in the bridge
+    fn write_sol_rate_limit(svm: &mut LiteSVM, bridge_pda: Pubkey, available: u64) {
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge.rate_limited_mints.add(NATIVE_SOL_PUBKEY).unwrap();
+        bridge_account.data.clear();
+        bridge.try_serialize(&mut bridge_account.data).unwrap();
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+
+        let mut data = Vec::new();
+        MintRateLimit {
+            mint: NATIVE_SOL_PUBKEY,
+            config: RateLimitConfig {
+                capacity: 50_000_000,
+                refill_per_window: 1_000_000,
+                window_duration_seconds: 3_600,
+            },
+            available,
+            window_start_time: svm.get_sysvar::<Clock>().unix_timestamp,
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        svm.set_account(
+            rate_limit_pda(NATIVE_SOL_PUBKEY),
+            SvmAccount {
+                lamports: 10_000_000,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+    }
+
+    fn read_sol_rate_limit(svm: &LiteSVM) -> MintRateLimit {
+        let account = svm.get_account(&rate_limit_pda(NATIVE_SOL_PUBKEY)).unwrap();
+        MintRateLimit::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_relay_transfer_enforces_rate_limit() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        svm.airdrop(&sol_vault, 1_000_000_000).unwrap();
+        let rate_limit = Some(rate_limit_pda(NATIVE_SOL_PUBKEY));
+
+        write_sol_rate_limit(&mut svm, bridge_pda, 9_999_999);
+        let ix = sol_transfer_ix(&mut svm, &payer, bridge_pda, 10_000_000, rate_limit);
+        assert_error(
+            send(&mut svm, &payer, ix.clone()),
+            "WithdrawalRateLimitExceeded",
+        );
+        assert_eq!(read_sol_rate_limit(&svm).available, 9_999_999);
+
+        write_sol_rate_limit(&mut svm, bridge_pda, 10_000_000);
+        send(&mut svm, &payer, ix).expect("relay within the rate limit should succeed");
+        assert_eq!(read_sol_rate_limit(&svm).available, 0);
+    }
+
+    #[test]
+    fn test_relay_transfer_requires_rate_limit_account() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        svm.airdrop(&sol_vault, 1_000_000_000).unwrap();
+
+        // Transfers of a mint without a configured limit keep the account list without it
+        let ix = sol_transfer_ix(&mut svm, &payer, bridge_pda, 10_000_000, None);
+        send(&mut svm, &payer, ix).expect("relay of an unlimited mint should succeed");
+
+        // An uninitialized rate limit account is also accepted for an unlimited mint
+        let ix = sol_transfer_ix(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            10_000_000,
+            Some(rate_limit_pda(NATIVE_SOL_PUBKEY)),
+        );
+        send(&mut svm, &payer, ix).expect("relay without a configured limit should succeed");
+
+        write_sol_rate_limit(&mut svm, bridge_pda, 50_000_000);
+
+        // Once limited, transfers cannot skip the rate limit by omitting its account
+        let ix = sol_transfer_ix(&mut svm, &payer, bridge_pda, 10_000_000, None);
+        assert_error(send(&mut svm, &payer, ix), "IncorrectRateLimitAccount");
+
+        // Nor by passing the rate limit of another mint
+        let other_mint = rate_limit_pda(Pubkey::new_unique());
+        let ix = sol_transfer_ix(&mut svm, &payer, bridge_pda, 10_000_000, Some(other_mint));
+        assert_error(send(&mut svm, &payer, ix), "IncorrectRateLimitAccount");
+        assert_eq!(read_sol_rate_limit(&svm).available, 50_000_000);
+    }
+
+    /// Writes the guardian-managed CPI policy
+    fn write_cpi_policy(svm: &mut LiteSVM, config: CpiPolicyConfig) {
+        let mut data = Vec::new();
+        CpiPolicy { config }.try_serialize(&mut data).unwrap();
+        svm.set_account(
+            cpi_policy_pda(),
+            SvmAccount {
+                lamports: 10_000_000,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_relay_enforces_cpi_policy() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let recipient = Pubkey::new_unique();
+        let ixs = transfer_ixs(&mut svm, recipient, 1);
+        let incoming_message = write_incoming_message(&mut svm, payer.pubkey(), Message::Call(ixs));
+        let relay = |svm: &mut LiteSVM| {
+            send(
+                svm,
+                &payer,
+                relay_ix(&payer, bridge_pda, incoming_message, recipient, None),
+            )
+        };
+
+        // The policy account cannot be substituted
+        let mut ix = relay_ix(&payer, bridge_pda, incoming_message, recipient, None);
+        for meta in ix.accounts.iter_mut() {
+            if meta.pubkey == cpi_policy_pda() {
+                meta.pubkey = Pubkey::new_unique();
+            }
+        }
+        assert_error(send(&mut svm, &payer, ix), "ConstraintSeeds");
+
+        write_cpi_policy(
+            &mut svm,
+            CpiPolicyConfig {
+                mode: CpiPolicyMode::Denylist,
+                programs: vec![system_program::ID],
+            },
+        );
+        let result = relay(&mut svm);
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(error_string.contains("CpiProgramNotAllowed"));
+        // The error log names the rejected program
+        assert!(error_string.contains(&system_program::ID.to_string()));
+
+        write_cpi_policy(
+            &mut svm,
+            CpiPolicyConfig {
+                mode: CpiPolicyMode::Allowlist,
+                programs: vec![Pubkey::new_unique()],
+            },
+        );
+        assert_error(relay(&mut svm), "CpiProgramNotAllowed");
+
+        write_cpi_policy(
+            &mut svm,
+            CpiPolicyConfig {
+                mode: CpiPolicyMode::Allowlist,
+                programs: vec![system_program::ID],
+            },
+        );
+        relay(&mut svm).expect("relay to an allowlisted program should succeed");
+        assert_eq!(svm.get_balance(&recipient).unwrap(), LAMPORTS_PER_IX);
+    }
+
+    #[test]
+    fn test_relay_rejects_legacy_incoming_message() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let recipient = Pubkey::new_unique();
+        let message = Message::Call(transfer_ixs(&mut svm, recipient, 1));
+
+        // Written the way the legacy `prove_message` allocated and serialized it
+        let data = message.try_to_vec().unwrap();
+        let message_hash = hash_message(&NONCE.to_be_bytes(), &SENDER, &data);
+        let incoming_message =
+            Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &message_hash], &ID).0;
+        let mut account_data =
+            vec![0u8; DISCRIMINATOR_LEN + LegacyIncomingMessage::space(data.len())];
+        account_data[..DISCRIMINATOR_LEN].copy_from_slice(IncomingMessage::DISCRIMINATOR);
+        LegacyIncomingMessage {
+            sender: SENDER,
+            message,
+            executed: false,
+        }
+        .serialize(&mut &mut account_data[DISCRIMINATOR_LEN..])
+        .unwrap();
+        svm.set_account(
+            incoming_message,
+            SvmAccount {
+                lamports: 10_000_000,
+                data: account_data.clone(),
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let result = send(
+            &mut svm,
+            &payer,
+            relay_ix(&payer, bridge_pda, incoming_message, recipient, None),
+        );
+        assert!(result.is_err());
+
+        // The legacy contents are never read with the current layout
+        assert_eq!(
+            svm.get_account(&incoming_message).unwrap().data,
+            account_data
+        );
+        assert!(svm.get_balance(&recipient).is_none_or(|b| b == 0));
+    }
+
+    #[test]
+    fn test_relay_always_denies_bridge_program() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        write_cpi_policy(
+            &mut svm,
+            CpiPolicyConfig {
+                mode: CpiPolicyMode::Allowlist,
+                programs: vec![ID],
+            },
+        );
+
+        let incoming_message = write_incoming_message(
+            &mut svm,
+            payer.pubkey(),
+            Message::Call(vec![Ix {
+                program_id: ID,
+                accounts: vec![],
+                data: vec![],
+            }]),
+        );
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                relay_ix(
+                    &payer,
+                    bridge_pda,
+                    incoming_message,
+                    Pubkey::new_unique(),
+                    None,
+                ),
+            ),
+            "CpiProgramNotAllowed",
+        );
+    }
+
+    #[test]
+    fn test_relay_denies_writable_or_signing_bridge_vaults() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let cpi_authority =
+            Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, &SENDER], &ID).0;
+        svm.airdrop(&cpi_authority, 1_000_000_000).unwrap();
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        svm.airdrop(&sol_vault, 1_000_000_000).unwrap();
+
+        let mint = Pubkey::new_unique();
+        let token_vault =
+            Pubkey::find_program_address(&[TOKEN_VAULT_SEED, mint.as_ref(), &[1u8; 20]], &ID).0;
+        create_mock_token_account(&mut svm, token_vault, mint, token_vault, 1_000);
+
+        let relay = |svm: &mut LiteSVM, ix: Ix, vault: Pubkey| {
+            let incoming_message =
+                write_incoming_message(svm, payer.pubkey(), Message::Call(vec![ix]));
+            send(
+                svm,
+                &payer,
+                relay_ix(&payer, bridge_pda, incoming_message, vault, None),
+            )
+        };
+
+        // Vaults cannot be passed as writable, even to credit them
+        for vault in [sol_vault, token_vault] {
+            let ix = system_instruction::transfer(&cpi_authority, &vault, LAMPORTS_PER_IX).into();
+            let result = relay(&mut svm, ix, vault);
+            let error_string = format!("{:?}", result.unwrap_err());
+            assert!(error_string.contains("BridgeVaultNotAllowed"));
+            // The error log names the rejected vault
+            assert!(error_string.contains(&vault.to_string()));
+        }
+
+        // Nor as signer
+        let mut ix: Ix = system_instruction::transfer(&sol_vault, &cpi_authority, 1).into();
+        ix.accounts[0].is_writable = false;
+        assert_error(relay(&mut svm, ix, sol_vault), "BridgeVaultNotAllowed");
+
+        // A token account that is not its own authority is not a vault
+        let token_account = Pubkey::new_unique();
+        create_mock_token_account(&mut svm, token_account, mint, cpi_authority, 1_000);
+        let ix =
+            system_instruction::transfer(&cpi_authority, &token_account, LAMPORTS_PER_IX).into();
+        relay(&mut svm, ix, token_account).expect("relay crediting a token account should succeed");
+        assert_eq!(svm.get_balance(&token_account).unwrap(), LAMPORTS_PER_IX);
+    }
+
+    #[test]
+    fn test_relay_atomic_message_ignores_max_ixs() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let recipient = Pubkey::new_unique();
+        let ixs = transfer_ixs(&mut svm, recipient, 3);
+        let incoming_message = write_incoming_message(&mut svm, payer.pubkey(), Message::Call(ixs));
+
+        let meta = send(
+            &mut svm,
+            &payer,
+            relay_ix(&payer, bridge_pda, incoming_message, recipient, Some(1)),
+        )
+        .expect("relay should succeed");
+        assert!(find_cpi_event::<MessageStepRelayed>(&meta).is_none());
+
+        let message = read_incoming_message(&svm, &incoming_message);
+        assert!(message.executed);
+        assert_eq!(message.cursor, None);
+        assert_eq!(svm.get_balance(&recipient).unwrap(), 3 * LAMPORTS_PER_IX);
+    }
+}
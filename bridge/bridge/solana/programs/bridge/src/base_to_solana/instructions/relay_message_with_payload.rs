@@ -0,0 +1,528 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{
+    constants::{CPI_POLICY_SEED, NONCE_BITMAP_SEED},
+    execute_message, hash_message,
+    state::{IncomingMessageHash, NonceBitmap},
+    Message,
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_RELAY_MESSAGE};
+use crate::{BridgeError, MessageRelayed};
+
+/// Accounts struct for the relay_message_with_payload instruction that executes a message proven
+/// with `prove_message_hash`. The payload is provided inline and checked against the stored hash.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RelayMessageWithPayload<'info> {
+    /// The account that pays for the nonce bitmap page if it does not exist yet.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The incoming message hash account committing to the payload being relayed.
+    /// Must be mutable to mark the message as executed after processing.
+    #[account(mut)]
+    pub message: Account<'info, IncomingMessageHash>,
+
+    /// The replay protection page covering the message nonce.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + NonceBitmap::INIT_SPACE,
+        seeds = [NONCE_BITMAP_SEED, &NonceBitmap::page(message.nonce).to_le_bytes()],
+        bump
+    )]
+    pub nonce_bitmap: Account<'info, NonceBitmap>,
+
+    /// The main bridge state account used to check pause status
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The withdrawal rate limit PDA of the mint released by a transfer message.
+    /// Required only for transfers of mints in `bridge.rate_limited_mints`.
+    /// CHECK: Address and ownership are validated in the handler.
+    #[account(mut)]
+    pub rate_limit: Option<UncheckedAccount<'info>>,
+
+    /// The guardian-managed policy restricting the programs the message instructions can target.
+    /// CHECK: Address is validated by the seeds constraint and ownership in the handler.
+    #[account(seeds = [CPI_POLICY_SEED], bump)]
+    pub cpi_policy: UncheckedAccount<'info>,
+
+    /// System program required for creating the nonce bitmap page.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn relay_message_with_payload_handler<'a, 'info>(
+    ctx: Context<'a, '_, 'info, 'info, RelayMessageWithPayload<'info>>,
+    sender: [u8; 20],
+    data: Vec<u8>,
+) -> Result<()> {
+    // Check if this instruction family is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_RELAY_MESSAGE)?;
+
+    let message = verify_payload(&mut ctx.accounts.message, &sender, &data)?;
+    ctx.accounts
+        .nonce_bitmap
+        .consume(ctx.accounts.message.nonce)?;
+
+    execute_message(
+        message,
+        &sender,
+        &ctx.accounts.payer.key(),
+        &ctx.accounts.bridge,
+        ctx.accounts.rate_limit.as_deref(),
+        &ctx.accounts.cpi_policy,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    emit_cpi!(MessageRelayed {
+        incoming_message: ctx.accounts.message.key(),
+        sender,
+        nonce: ctx.accounts.message.nonce,
+        message_hash: ctx.accounts.message.message_hash,
+        executed: ctx.accounts.message.executed,
+    });
+
+    Ok(())
+}
+
+/// Checks the relayed payload against the proven message hash, marks the message as executed and
+/// returns the deserialized payload.
+pub(crate) fn verify_payload(
+    message: &mut IncomingMessageHash,
+    sender: &[u8; 20],
+    data: &[u8],
+) -> Result<Message> {
+    require!(!message.executed, BridgeError::AlreadyExecuted);
+    require!(
+        hash_message(&message.nonce.to_be_bytes(), sender, data) == message.message_hash,
+        BridgeError::InvalidMessageHash
+    );
+
+    message.executed = true;
+
+    Ok(Message::try_from_slice(data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SolMessage;
+    use solana_signer::Signer as _;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::{
+            constants::INCOMING_MESSAGE_SEED, state::OutputRoot, IncomingMessage, Ix,
+        },
+        instruction::{
+            AppendToProveBufferData, CloseIncomingMessageHash as CloseIncomingMessageHashIx,
+            InitializeProveBuffer, ProveMessage as ProveMessageIx,
+            ProveMessageHash as ProveMessageHashIx,
+            RelayMessageWithPayload as RelayMessageWithPayloadIx,
+            RelayMessageWithPayloadBuffered as RelayMessageWithPayloadBufferedIx,
+        },
+        test_utils::{
+            assert_error, cpi_policy_pda, event_authority_pda, find_cpi_event, send, setup_bridge,
+            write_output_root, SetupBridgeResult,
+        },
+        IncomingMessageClosed, MessageHashProven, ID,
+    };
+
+    const NONCE: u64 = 5;
+    const SENDER: [u8; 20] = [7u8; 20];
+
+    struct ProvenMessage {
+        message_hash: [u8; 32],
+        incoming_pda: Pubkey,
+        nonce_bitmap_pda: Pubkey,
+        output_root_pk: Pubkey,
+        data: Vec<u8>,
+    }
+
+    /// Registers a single-leaf output root committing to `message`
+    fn setup_message(svm: &mut LiteSVM, message: Message) -> ProvenMessage {
+        let data = message.try_to_vec().unwrap();
+        let message_hash = hash_message(&NONCE.to_be_bytes(), &SENDER, &data);
+
+        let output_root_pk = Keypair::new().pubkey();
+        write_output_root(
+            svm,
+            output_root_pk,
+            &OutputRoot {
+                root: message_hash,
+                total_leaf_count: 1,
+                base_block_number: 0,
+                payer: Pubkey::default(),
+                registered_at: 0,
+                invalidated: false,
+            },
+        );
+
+        ProvenMessage {
+            message_hash,
+            incoming_pda: Pubkey::find_program_address(
+                &[INCOMING_MESSAGE_SEED, &message_hash],
+                &ID,
+            )
+            .0,
+            nonce_bitmap_pda: Pubkey::find_program_address(
+                &[NONCE_BITMAP_SEED, &NonceBitmap::page(NONCE).to_le_bytes()],
+                &ID,
+            )
+            .0,
+            output_root_pk,
+            data,
+        }
+    }
+
+    fn prove_hash_ix(payer: &Keypair, bridge_pda: Pubkey, msg: &ProvenMessage) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::ProveMessageHash {
+                payer: payer.pubkey(),
+                output_root: msg.output_root_pk,
+                message: msg.incoming_pda,
+                bridge: bridge_pda,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: ProveMessageHashIx {
+                nonce: NONCE,
+                message_hash: msg.message_hash,
+                proof: vec![],
+            }
+            .data(),
+        }
+    }
+
+    fn prove_ix(payer: &Keypair, bridge_pda: Pubkey, msg: &ProvenMessage) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::ProveMessage {
+                payer: payer.pubkey(),
+                output_root: msg.output_root_pk,
+                message: msg.incoming_pda,
+                bridge: bridge_pda,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: ProveMessageIx {
+                nonce: NONCE,
+                sender: SENDER,
+                data: msg.data.clone(),
+                proof: vec![],
+                message_hash: msg.message_hash,
+            }
+            .data(),
+        }
+    }
+
+    fn relay_ix(
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        msg: &ProvenMessage,
+        sender: [u8; 20],
+        data: Vec<u8>,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::RelayMessageWithPayload {
+                payer: payer.pubkey(),
+                message: msg.incoming_pda,
+                nonce_bitmap: msg.nonce_bitmap_pda,
+                bridge: bridge_pda,
+                rate_limit: None,
+                cpi_policy: cpi_policy_pda(),
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: RelayMessageWithPayloadIx { sender, data }.data(),
+        }
+    }
+
+    /// Creates a prove buffer owned by `owner` holding `data`
+    fn write_prove_buffer(
+        svm: &mut LiteSVM,
+        bridge_pda: Pubkey,
+        owner: &Keypair,
+        data: &[u8],
+    ) -> Pubkey {
+        let prove_buffer = Keypair::new();
+        let init_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::InitializeProveBuffer {
+                payer: owner.pubkey(),
+                bridge: bridge_pda,
+                prove_buffer: prove_buffer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitializeProveBuffer {
+                max_data_len: data.len() as u64,
+                max_proof_len: 0,
+            }
+            .data(),
+        };
+        svm.expire_blockhash();
+        let tx = Transaction::new(
+            &[owner, &prove_buffer],
+            SolMessage::new(&[init_ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("initialize_prove_buffer should succeed");
+
+        let append_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::AppendToProveBufferData {
+                owner: owner.pubkey(),
+                prove_buffer: prove_buffer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: AppendToProveBufferData {
+                chunk: data.to_vec(),
+            }
+            .data(),
+        };
+        send(svm, owner, append_ix).expect("append data should succeed");
+
+        prove_buffer.pubkey()
+    }
+
+    #[test]
+    fn test_relay_message_with_payload_executes_hash_only_message() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let msg = setup_message(&mut svm, Message::Call(vec![]));
+
+        let meta = send(&mut svm, &payer, prove_hash_ix(&payer, bridge_pda, &msg))
+            .expect("prove_message_hash should succeed");
+        let event = find_cpi_event::<MessageHashProven>(&meta)
+            .expect("MessageHashProven event should be emitted");
+        assert_eq!(event.incoming_message, msg.incoming_pda);
+        assert_eq!(event.message_hash, msg.message_hash);
+        assert_eq!(event.nonce, NONCE);
+
+        let account = svm.get_account(&msg.incoming_pda).unwrap();
+        let proven = IncomingMessageHash::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(proven.nonce, NONCE);
+        assert_eq!(proven.payer, payer.pubkey());
+        assert_eq!(proven.message_hash, msg.message_hash);
+        assert!(!proven.executed);
+
+        // The same message cannot also be proven with its full payload
+        assert!(send(&mut svm, &payer, prove_ix(&payer, bridge_pda, &msg)).is_err());
+
+        let meta = send(
+            &mut svm,
+            &payer,
+            relay_ix(&payer, bridge_pda, &msg, SENDER, msg.data.clone()),
+        )
+        .expect("relay_message_with_payload should succeed");
+        let event = find_cpi_event::<MessageRelayed>(&meta)
+            .expect("MessageRelayed event should be emitted");
+        assert_eq!(event.incoming_message, msg.incoming_pda);
+        assert_eq!(event.sender, SENDER);
+        assert_eq!(event.nonce, NONCE);
+        assert_eq!(event.message_hash, msg.message_hash);
+        assert!(event.executed);
+
+        let account = svm.get_account(&msg.incoming_pda).unwrap();
+        let proven = IncomingMessageHash::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(proven.executed);
+        let bitmap_account = svm.get_account(&msg.nonce_bitmap_pda).unwrap();
+        let bitmap = NonceBitmap::try_deserialize(&mut &bitmap_account.data[..]).unwrap();
+        assert!(bitmap.is_consumed(NONCE));
+
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                relay_ix(&payer, bridge_pda, &msg, SENDER, msg.data.clone()),
+            ),
+            "AlreadyExecuted",
+        );
+    }
+
+    #[test]
+    fn test_relay_message_with_payload_rejects_mismatched_payload() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let msg = setup_message(&mut svm, Message::Call(vec![]));
+
+        send(&mut svm, &payer, prove_hash_ix(&payer, bridge_pda, &msg))
+            .expect("prove_message_hash should succeed");
+
+        let other_data = Message::Call(vec![Ix {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![],
+            data: vec![1, 2, 3],
+        }])
+        .try_to_vec()
+        .unwrap();
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                relay_ix(&payer, bridge_pda, &msg, SENDER, other_data),
+            ),
+            "InvalidMessageHash",
+        );
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                relay_ix(&payer, bridge_pda, &msg, [8u8; 20], msg.data.clone()),
+            ),
+            "InvalidMessageHash",
+        );
+
+        let account = svm.get_account(&msg.incoming_pda).unwrap();
+        let proven = IncomingMessageHash::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(!proven.executed);
+    }
+
+    #[test]
+    fn test_prove_message_hash_rent_does_not_depend_on_payload_size() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let large_call = Message::Call(vec![Ix {
+            program_id: Pubkey::new_unique(),
+            accounts: vec![],
+            data: vec![0xab; 800],
+        }]);
+
+        let small = setup_message(&mut svm, Message::Call(vec![]));
+        send(&mut svm, &payer, prove_hash_ix(&payer, bridge_pda, &small))
+            .expect("prove_message_hash should succeed");
+        let large = setup_message(&mut svm, large_call);
+        send(&mut svm, &payer, prove_hash_ix(&payer, bridge_pda, &large))
+            .expect("prove_message_hash should succeed");
+
+        let small_account = svm.get_account(&small.incoming_pda).unwrap();
+        let large_account = svm.get_account(&large.incoming_pda).unwrap();
+        assert_eq!(
+            large_account.data.len(),
+            DISCRIMINATOR_LEN + IncomingMessageHash::INIT_SPACE
+        );
+        assert_eq!(small_account.lamports, large_account.lamports);
+        assert!(
+            large_account.data.len() < DISCRIMINATOR_LEN + IncomingMessage::space(large.data.len())
+        );
+    }
+
+    #[test]
+    fn test_relay_message_with_payload_buffered_reads_payload_and_closes_buffer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let msg = setup_message(&mut svm, Message::Call(vec![]));
+
+        send(&mut svm, &payer, prove_hash_ix(&payer, bridge_pda, &msg))
+            .expect("prove_message_hash should succeed");
+        let prove_buffer = write_prove_buffer(&mut svm, bridge_pda, &payer, &msg.data);
+
+        let relay_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RelayMessageWithPayloadBuffered {
+                payer: payer.pubkey(),
+                message: msg.incoming_pda,
+                nonce_bitmap: msg.nonce_bitmap_pda,
+                bridge: bridge_pda,
+                rate_limit: None,
+                cpi_policy: cpi_policy_pda(),
+                owner: payer.pubkey(),
+                prove_buffer,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: RelayMessageWithPayloadBufferedIx { sender: SENDER }.data(),
+        };
+        send(&mut svm, &payer, relay_ix).expect("buffered relay should succeed");
+
+        let account = svm.get_account(&msg.incoming_pda).unwrap();
+        let proven = IncomingMessageHash::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(proven.executed);
+        assert!(svm
+            .get_account(&prove_buffer)
+            .is_none_or(|a| a.lamports == 0));
+    }
+
+    #[test]
+    fn test_close_incoming_message_hash_refunds_payer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let msg = setup_message(&mut svm, Message::Call(vec![]));
+        let close_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::CloseIncomingMessageHash {
+                message: msg.incoming_pda,
+                nonce_bitmap: msg.nonce_bitmap_pda,
+                payer: payer.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: CloseIncomingMessageHashIx {}.data(),
+        };
+
+        send(&mut svm, &payer, prove_hash_ix(&payer, bridge_pda, &msg))
+            .expect("prove_message_hash should succeed");
+
+        // Unexecuted messages cannot be closed
+        assert!(send(&mut svm, &payer, close_ix.clone()).is_err());
+
+        send(
+            &mut svm,
+            &payer,
+            relay_ix(&payer, bridge_pda, &msg, SENDER, msg.data.clone()),
+        )
+        .expect("relay_message_with_payload should succeed");
+
+        let meta = send(&mut svm, &payer, close_ix).expect("close should succeed");
+        let event = find_cpi_event::<IncomingMessageClosed>(&meta)
+            .expect("IncomingMessageClosed event should be emitted");
+        assert_eq!(event.incoming_message, msg.incoming_pda);
+        assert_eq!(event.nonce, NONCE);
+        assert!(svm
+            .get_account(&msg.incoming_pda)
+            .is_none_or(|a| a.lamports == 0));
+    }
+}
@@ -0,0 +1,305 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::state::OutputRoot;
+use crate::common::{bridge::Bridge, BRIDGE_SEED};
+use crate::{BridgeError, OutputRootRevoked};
+
+/// Accounts struct for the revoke_output_root instruction that lets the guardian reject an output
+/// root signed by a compromised oracle, regardless of its challenge window.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeOutputRoot<'info> {
+    /// The output root being revoked.
+    #[account(mut)]
+    pub output_root: Account<'info, OutputRoot>,
+
+    /// The main bridge state account holding the guardian and the latest registered block number.
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    /// - Must be mutable to roll back the base_block_number field
+    #[account(
+        mut,
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The guardian account authorized to revoke output roots
+    pub guardian: Signer<'info>,
+}
+
+pub fn revoke_output_root_handler(
+    ctx: Context<RevokeOutputRoot>,
+    roll_back_to: Option<u64>,
+) -> Result<()> {
+    let output_root = &mut ctx.accounts.output_root;
+    let bridge = &mut ctx.accounts.bridge;
+
+    // Roots already invalidated during their challenge window can still be rolled back
+    output_root.invalidated = true;
+
+    // Rolling back lets a corrected root be registered for the revoked checkpoint
+    if let Some(roll_back_to) = roll_back_to {
+        require!(
+            output_root.base_block_number == bridge.base_block_number,
+            BridgeError::OutputRootNotLatest
+        );
+        require!(
+            roll_back_to < output_root.base_block_number,
+            BridgeError::IncorrectBlockNumber
+        );
+        bridge.base_block_number = roll_back_to;
+    }
+
+    emit_cpi!(OutputRootRevoked {
+        output_root_account: ctx.accounts.output_root.key(),
+        base_block_number: ctx.accounts.output_root.base_block_number,
+        latest_base_block_number: ctx.accounts.bridge.base_block_number,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::solana_program::keccak::hash as keccak_hash;
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer as _;
+
+    use crate::{
+        accounts,
+        base_to_solana::{
+            constants::{INCOMING_MESSAGE_SEED, OUTPUT_ROOT_SEED},
+            Message as BridgeMessage,
+        },
+        instruction::{ProveMessage as ProveMessageIx, RevokeOutputRoot as RevokeOutputRootIx},
+        test_utils::{
+            assert_error, event_authority_pda, find_cpi_event, send, setup_bridge,
+            write_output_root, SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const LATEST_BLOCK_NUMBER: u64 = 900;
+    const NONCE: u64 = 0;
+    const SENDER: [u8; 20] = [7u8; 20];
+
+    struct TestRoot {
+        output_root_pk: Pubkey,
+        message_hash: [u8; 32],
+        data: Vec<u8>,
+    }
+
+    /// Writes a single-leaf output root at `base_block_number` and marks `LATEST_BLOCK_NUMBER` as
+    /// the latest registered block
+    fn setup_root(svm: &mut LiteSVM, bridge_pda: Pubkey, base_block_number: u64) -> TestRoot {
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge.base_block_number = LATEST_BLOCK_NUMBER;
+        let mut bridge_data = Vec::new();
+        bridge.try_serialize(&mut bridge_data).unwrap();
+        bridge_account.data = bridge_data;
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+
+        let data = BridgeMessage::Call(vec![]).try_to_vec().unwrap();
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(&NONCE.to_be_bytes());
+        preimage.extend_from_slice(&SENDER);
+        preimage.extend_from_slice(&data);
+        let message_hash = keccak_hash(&preimage).0;
+
+        let output_root_pk = Pubkey::find_program_address(
+            &[OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()],
+            &ID,
+        )
+        .0;
+        write_output_root(
+            svm,
+            output_root_pk,
+            &OutputRoot {
+                root: message_hash,
+                total_leaf_count: 1,
+                base_block_number,
+                payer: Pubkey::new_unique(),
+                registered_at: 0,
+                invalidated: false,
+            },
+        );
+
+        TestRoot {
+            output_root_pk,
+            message_hash,
+            data,
+        }
+    }
+
+    fn revoke_ix(
+        guardian: Pubkey,
+        bridge_pda: Pubkey,
+        root: &TestRoot,
+        roll_back_to: Option<u64>,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::RevokeOutputRoot {
+                output_root: root.output_root_pk,
+                bridge: bridge_pda,
+                guardian,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: RevokeOutputRootIx { roll_back_to }.data(),
+        }
+    }
+
+    fn prove_ix(payer: &Keypair, bridge_pda: Pubkey, root: &TestRoot) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::ProveMessage {
+                payer: payer.pubkey(),
+                output_root: root.output_root_pk,
+                message: Pubkey::find_program_address(
+                    &[INCOMING_MESSAGE_SEED, &root.message_hash],
+                    &ID,
+                )
+                .0,
+                bridge: bridge_pda,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: ProveMessageIx {
+                nonce: NONCE,
+                sender: SENDER,
+                data: root.data.clone(),
+                proof: vec![],
+                message_hash: root.message_hash,
+            }
+            .data(),
+        }
+    }
+
+    fn read_bridge(svm: &LiteSVM, bridge_pda: Pubkey) -> Bridge {
+        let account = svm.get_account(&bridge_pda).unwrap();
+        Bridge::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_revoked_root_cannot_be_proven_against() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+        let root = setup_root(&mut svm, bridge_pda, 600);
+
+        let meta = send(
+            &mut svm,
+            &guardian,
+            revoke_ix(guardian.pubkey(), bridge_pda, &root, None),
+        )
+        .expect("revocation should succeed");
+
+        let event = find_cpi_event::<OutputRootRevoked>(&meta)
+            .expect("OutputRootRevoked event should be emitted");
+        assert_eq!(event.output_root_account, root.output_root_pk);
+        assert_eq!(event.base_block_number, 600);
+        assert_eq!(event.latest_base_block_number, LATEST_BLOCK_NUMBER);
+        assert_eq!(
+            read_bridge(&svm, bridge_pda).base_block_number,
+            LATEST_BLOCK_NUMBER
+        );
+
+        let result = send(&mut svm, &payer, prove_ix(&payer, bridge_pda, &root));
+        assert_error(result, "InvalidatedOutputRoot");
+    }
+
+    #[test]
+    fn test_revoke_latest_root_rolls_back_block_number() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let root = setup_root(&mut svm, bridge_pda, LATEST_BLOCK_NUMBER);
+
+        let meta = send(
+            &mut svm,
+            &guardian,
+            revoke_ix(guardian.pubkey(), bridge_pda, &root, Some(600)),
+        )
+        .expect("revocation should succeed");
+
+        let event = find_cpi_event::<OutputRootRevoked>(&meta)
+            .expect("OutputRootRevoked event should be emitted");
+        assert_eq!(event.latest_base_block_number, 600);
+        assert_eq!(read_bridge(&svm, bridge_pda).base_block_number, 600);
+    }
+
+    #[test]
+    fn test_revoke_with_roll_back_of_older_root_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let root = setup_root(&mut svm, bridge_pda, 600);
+
+        let result = send(
+            &mut svm,
+            &guardian,
+            revoke_ix(guardian.pubkey(), bridge_pda, &root, Some(300)),
+        );
+        assert_error(result, "OutputRootNotLatest");
+    }
+
+    #[test]
+    fn test_revoke_with_forward_roll_back_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let root = setup_root(&mut svm, bridge_pda, LATEST_BLOCK_NUMBER);
+
+        let result = send(
+            &mut svm,
+            &guardian,
+            revoke_ix(
+                guardian.pubkey(),
+                bridge_pda,
+                &root,
+                Some(LATEST_BLOCK_NUMBER),
+            ),
+        );
+        assert_error(result, "IncorrectBlockNumber");
+    }
+
+    #[test]
+    fn test_revoke_with_non_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let root = setup_root(&mut svm, bridge_pda, 600);
+
+        let result = send(
+            &mut svm,
+            &payer,
+            revoke_ix(payer.pubkey(), bridge_pda, &root, None),
+        );
+        assert_error(result, "UnauthorizedConfigUpdate");
+    }
+}
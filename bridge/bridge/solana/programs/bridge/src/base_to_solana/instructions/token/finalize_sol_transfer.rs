@@ -18,12 +18,24 @@ pub struct FinalizeBridgeSol {
     pub to: Pubkey,
 
     /// The amount of SOL to transfer, denominated in lamports (1 SOL = 1_000_000_000 lamports).
-    /// This amount will be transferred from the SOL vault to the recipient.
+    /// This amount will be transferred from the SOL vault to the recipient, minus any relayer fee.
     pub amount: u64,
 }
 
 impl FinalizeBridgeSol {
-    pub fn finalize<'info>(&self, account_infos: &'info [AccountInfo<'info>]) -> Result<()> {
+    /// Releases `amount` from the SOL vault. When `relayer_fee` is non-zero, it is paid to the
+    /// `relayer` account following the system program and the remainder goes to `to`.
+    pub fn finalize<'info>(
+        &self,
+        account_infos: &'info [AccountInfo<'info>],
+        relayer: &Pubkey,
+        relayer_fee: u64,
+    ) -> Result<()> {
+        let to_amount = self
+            .amount
+            .checked_sub(relayer_fee)
+            .ok_or(BridgeError::RelayerFeeExceedsAmount)?;
+
         // Read the accounts in the expected order
         let mut iter = account_infos.iter();
         let sol_vault_info = next_account_info(&mut iter)?;
@@ -53,6 +65,28 @@ impl FinalizeBridgeSol {
             },
             seeds,
         );
-        system_program::transfer(cpi_ctx, self.amount)
+        system_program::transfer(cpi_ctx, to_amount)?;
+
+        if relayer_fee == 0 {
+            return Ok(());
+        }
+
+        // Pay the relayer fee to the relayer executing the message
+        let relayer_info = next_account_info(&mut iter)?;
+        require_keys_eq!(
+            relayer_info.key(),
+            *relayer,
+            BridgeError::IncorrectRelayerFeeReceiver
+        );
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            system_program_info.to_account_info(),
+            Transfer {
+                from: sol_vault_info.to_account_info(),
+                to: relayer_info.to_account_info(),
+            },
+            seeds,
+        );
+        system_program::transfer(cpi_ctx, relayer_fee)
     }
 }
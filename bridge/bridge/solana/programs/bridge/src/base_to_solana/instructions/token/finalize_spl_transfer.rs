@@ -26,12 +26,25 @@ pub struct FinalizeBridgeSpl {
 
     /// The amount to transfer, in base units of the mint (respecting mint decimals).
     /// `transfer_checked` enforces that the destination account's mint matches and
-    /// the decimals are correct.
+    /// the decimals are correct. Any relayer fee is taken out of this amount.
     pub amount: u64,
 }
 
 impl FinalizeBridgeSpl {
-    pub fn finalize<'info>(&self, account_infos: &'info [AccountInfo<'info>]) -> Result<()> {
+    /// Releases `amount` from the token vault. When `relayer_fee` is non-zero, it is paid to the
+    /// token account following the token program, which must be owned by `relayer`, and the
+    /// remainder goes to `to`.
+    pub fn finalize<'info>(
+        &self,
+        account_infos: &'info [AccountInfo<'info>],
+        relayer: &Pubkey,
+        relayer_fee: u64,
+    ) -> Result<()> {
+        let to_amount = self
+            .amount
+            .checked_sub(relayer_fee)
+            .ok_or(BridgeError::RelayerFeeExceedsAmount)?;
+
         // Deserialize the accounts
         let mut iter = account_infos.iter();
         let mint = InterfaceAccount::<Mint>::try_from(next_account_info(&mut iter)?)?;
@@ -89,7 +102,32 @@ impl FinalizeBridgeSpl {
             },
             seeds,
         );
-        token_interface::transfer_checked(cpi_ctx, self.amount, mint.decimals)?;
+        token_interface::transfer_checked(cpi_ctx, to_amount, mint.decimals)?;
+
+        if relayer_fee == 0 {
+            return Ok(());
+        }
+
+        // Pay the relayer fee to a token account of the relayer executing the message
+        let relayer_token_account =
+            InterfaceAccount::<TokenAccount>::try_from(next_account_info(&mut iter)?)?;
+        require_keys_eq!(
+            relayer_token_account.owner,
+            *relayer,
+            BridgeError::IncorrectRelayerFeeReceiver
+        );
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                mint: mint.to_account_info(),
+                from: token_vault.to_account_info(),
+                to: relayer_token_account.to_account_info(),
+                authority: token_vault.to_account_info(),
+            },
+            seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, relayer_fee, mint.decimals)?;
 
         Ok(())
     }
@@ -34,13 +34,26 @@ pub struct FinalizeBridgeWrappedToken {
     /// instruction.
     pub to: Pubkey,
 
-    /// The amount of wrapped tokens to mint to the recipient.
+    /// The amount of wrapped tokens to mint to the recipient, minus any relayer fee.
     /// The amount is specified in the token's smallest unit.
     pub amount: u64,
 }
 
 impl FinalizeBridgeWrappedToken {
-    pub fn finalize<'info>(&self, account_infos: &'info [AccountInfo<'info>]) -> Result<()> {
+    /// Mints `amount` wrapped tokens. When `relayer_fee` is non-zero, it is minted to the token
+    /// account following the token program, which must be owned by `relayer`, and the remainder
+    /// goes to `to`.
+    pub fn finalize<'info>(
+        &self,
+        account_infos: &'info [AccountInfo<'info>],
+        relayer: &Pubkey,
+        relayer_fee: u64,
+    ) -> Result<()> {
+        let to_amount = self
+            .amount
+            .checked_sub(relayer_fee)
+            .ok_or(BridgeError::RelayerFeeExceedsAmount)?;
+
         // Deserialize the accounts
         let mut iter = account_infos.iter();
         let mint = InterfaceAccount::<Mint>::try_from(next_account_info(&mut iter)?)?;
@@ -92,7 +105,31 @@ impl FinalizeBridgeWrappedToken {
             },
             seeds,
         );
-        token_interface::mint_to_checked(cpi_ctx, self.amount, mint.decimals)?;
+        token_interface::mint_to_checked(cpi_ctx, to_amount, mint.decimals)?;
+
+        if relayer_fee == 0 {
+            return Ok(());
+        }
+
+        // Mint the relayer fee to a token account of the relayer executing the message
+        let relayer_token_account =
+            InterfaceAccount::<TokenAccount>::try_from(next_account_info(&mut iter)?)?;
+        require_keys_eq!(
+            relayer_token_account.owner,
+            *relayer,
+            BridgeError::IncorrectRelayerFeeReceiver
+        );
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program_2022.to_account_info(),
+            MintToChecked {
+                mint: mint.to_account_info(),
+                to: relayer_token_account.to_account_info(),
+                authority: mint.to_account_info(),
+            },
+            seeds,
+        );
+        token_interface::mint_to_checked(cpi_ctx, relayer_fee, mint.decimals)?;
 
         Ok(())
     }
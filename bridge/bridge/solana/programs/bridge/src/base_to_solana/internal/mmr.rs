@@ -44,8 +44,9 @@ pub fn verify_proof(
 
 /// Calculates the MMR root given a leaf, its proof, and the MMR structure.
 ///
-/// This function reconstructs the peaks of the MMR based on the provided leaf and its proof,
-/// then bags these peaks together to form the final MMR root.
+/// This function reconstructs the peak of the leaf's mountain from the intra-mountain siblings,
+/// then bags it with the other peaks from the proof to form the final MMR root. Mountains are
+/// derived arithmetically from the bits of `total_leaf_count`, so nothing is allocated.
 fn calculate_root_from_proof(
     proof: &[[u8; 32]],
     leaf_hash: &[u8; 32],
@@ -53,110 +54,165 @@ fn calculate_root_from_proof(
     total_leaf_count: u64,
 ) -> Result<[u8; 32]> {
     require!(total_leaf_count > 0, BridgeError::EmptyMmr);
+    require!(
+        leaf_idx < total_leaf_count,
+        BridgeError::LeafMountainNotFound
+    );
 
-    // 1. Determine the mountain structure and the leaf's mountain details.
-    let mut mountains: Vec<(u32, u64, bool)> = Vec::new(); // (height, num_leaves_in_mountain, is_leafs_mountain)
-    let mut temp_leaf_count = total_leaf_count;
-    let mut current_leaf_offset: u64 = 0; // Tracks leaves before the current mountain being considered
-    let mut leaf_s_mountain_details: Option<(u32, u64)> = None; // (height, leaf_idx_in_mountain)
+    // 1. Each set bit of `total_leaf_count` is a mountain, from the largest (leftmost) down. The
+    // leaf lives in the mountain of the highest bit where its index differs from the count.
+    let leaf_mountain_height = u64::BITS - 1 - (leaf_idx ^ total_leaf_count).leading_zeros();
 
-    let max_h = if total_leaf_count > 0 {
-        64 - total_leaf_count.leading_zeros() - 1
-    } else {
-        0
-    };
-
-    for h_idx in 0..=max_h {
-        let h = max_h - h_idx; // Iterate from largest height downwards
-        if (temp_leaf_count >> h) & 1 == 1 {
-            let leaves_in_this_mountain = 1u64 << h;
-            let is_leafs_m = leaf_idx >= current_leaf_offset
-                && leaf_idx < current_leaf_offset + leaves_in_this_mountain;
-            mountains.push((h, leaves_in_this_mountain, is_leafs_m));
-            if is_leafs_m {
-                leaf_s_mountain_details = Some((h, leaf_idx - current_leaf_offset));
-            }
+    // 2. Calculate the peak of the leaf's mountain.
+    require!(
+        leaf_mountain_height as usize <= proof.len(),
+        BridgeError::InsufficientProofElementsForIntraMountainPath
+    );
+    let (intra_mountain_proof, other_peaks) = proof.split_at(leaf_mountain_height as usize);
+    let leaf_mountain_peak_hash = intra_mountain_proof
+        .iter()
+        .fold(*leaf_hash, |hash, sibling| {
+            commutative_keccak256(hash, *sibling)
+        });
 
-            current_leaf_offset += leaves_in_this_mountain;
-            temp_leaf_count -= leaves_in_this_mountain;
+    // 3. Bag the peaks left-to-right; every other mountain contributes one peak from the proof.
+    let mut other_peaks = other_peaks.iter();
+    let mut root: Option<[u8; 32]> = None;
+    for height in (0..u64::BITS).rev() {
+        if (total_leaf_count >> height) & 1 == 0 {
+            continue;
         }
 
-        if temp_leaf_count == 0 {
-            break;
-        }
+        let peak_hash = if height == leaf_mountain_height {
+            leaf_mountain_peak_hash
+        } else {
+            *other_peaks.next().ok_or(error!(
+                BridgeError::InsufficientProofElementsForOtherMountainPeaks
+            ))?
+        };
+        root = Some(bag_peak(root, peak_hash));
     }
 
-    let (leaf_mountain_height, _leaf_idx_in_mountain) =
-        leaf_s_mountain_details.ok_or(error!(BridgeError::LeafMountainNotFound))?;
+    require!(
+        other_peaks.next().is_none(),
+        BridgeError::UnusedProofElementsRemaining
+    );
 
-    // 2. Calculate the peak of the leaf's mountain.
-    let mut current_computed_hash = *leaf_hash;
-    let mut proof_idx_offset = 0; // Tracks how many proof elements we've used for intra-mountain
+    root.ok_or(error!(BridgeError::NoPeaksFoundForNonEmptyMmr))
+}
 
+/// Verifies an MMR multiproof for several leaves at once.
+///
+/// Leaves are `(leaf_index, leaf_hash)` pairs in strictly increasing index order. For each
+/// mountain from left to right, the proof holds either:
+/// - the sibling hashes needed to rebuild the mountain's peak from the proven leaves it contains,
+///   level by level from the bottom and left-to-right within a level, when it contains any; or
+/// - the mountain's peak hash otherwise.
+///
+/// Siblings that are themselves derived from proven leaves are not part of the proof, so
+/// hashes shared by several leaves are only provided once.
+///
+/// Returns `Ok(())` if the proof is valid, or an error otherwise.
+pub fn verify_multiproof(
+    expected_root: &[u8; 32],
+    leaves: &[(u64, [u8; 32])],
+    proof: &[[u8; 32]],
+    total_leaf_count: u64,
+) -> Result<()> {
+    require!(!leaves.is_empty(), BridgeError::InvalidProof);
     require!(
-        leaf_mountain_height as usize <= proof.len() || leaf_mountain_height == 0,
-        BridgeError::InsufficientProofElementsForIntraMountainPath
+        leaves.windows(2).all(|pair| pair[0].0 < pair[1].0)
+            && leaves[leaves.len() - 1].0 < total_leaf_count,
+        BridgeError::InvalidProof
     );
 
-    for _h_climb in 0..leaf_mountain_height {
-        let sibling_hash = proof[proof_idx_offset];
-        proof_idx_offset += 1;
-        current_computed_hash = commutative_keccak256(current_computed_hash, sibling_hash);
-    }
-    let leaf_mountain_peak_hash = current_computed_hash;
+    let mut proof_idx = 0;
+    let mut root: Option<[u8; 32]> = None;
+    let mut remaining_leaves = leaves;
+    let mut mountain_offset: u64 = 0;
 
-    // 3. Collect all peak hashes (leaf's calculated peak + other peaks from proof).
-    let mut all_peak_hashes: Vec<[u8; 32]> = Vec::new();
-    let mut remaining_proof_idx = proof_idx_offset;
+    // Mountains from left (largest) to right (smallest)
+    for height in (0..u64::BITS).rev() {
+        if (total_leaf_count >> height) & 1 == 0 {
+            continue;
+        }
+        let mountain_size = 1u64 << height;
 
-    // Peaks are needed in left-to-right order for bagging.
-    // The `mountains` vector is already in left-to-right order.
-    for (_height, _num_leaves, is_leafs_m) in mountains.iter() {
-        if *is_leafs_m {
-            all_peak_hashes.push(leaf_mountain_peak_hash);
-        } else {
-            require!(
-                remaining_proof_idx < proof.len(),
+        let in_mountain = remaining_leaves
+            .iter()
+            .take_while(|(leaf_idx, _)| *leaf_idx < mountain_offset + mountain_size)
+            .count();
+        let (mountain_leaves, rest) = remaining_leaves.split_at(in_mountain);
+
+        let peak = if mountain_leaves.is_empty() {
+            let peak = proof.get(proof_idx).ok_or(error!(
                 BridgeError::InsufficientProofElementsForOtherMountainPeaks
-            );
+            ))?;
+            proof_idx += 1;
+            *peak
+        } else {
+            // Known nodes of the current level as (position within the level, hash)
+            let mut level: Vec<(u64, [u8; 32])> = mountain_leaves
+                .iter()
+                .map(|(leaf_idx, leaf_hash)| (leaf_idx - mountain_offset, *leaf_hash))
+                .collect();
 
-            all_peak_hashes.push(proof[remaining_proof_idx]);
-            remaining_proof_idx += 1;
-        }
+            for _ in 0..height {
+                let mut parents = Vec::with_capacity(level.len());
+                let mut i = 0;
+                while i < level.len() {
+                    let (position, hash) = level[i];
+                    let sibling = match level.get(i + 1) {
+                        Some((next_position, next_hash)) if *next_position == position ^ 1 => {
+                            i += 1;
+                            *next_hash
+                        }
+                        _ => {
+                            let sibling = proof.get(proof_idx).ok_or(error!(
+                                BridgeError::InsufficientProofElementsForIntraMountainPath
+                            ))?;
+                            proof_idx += 1;
+                            *sibling
+                        }
+                    };
+                    parents.push((position >> 1, commutative_keccak256(hash, sibling)));
+                    i += 1;
+                }
+                level = parents;
+            }
+
+            level[0].1
+        };
+
+        root = Some(bag_peak(root, peak));
+        remaining_leaves = rest;
+        mountain_offset += mountain_size;
     }
 
     require!(
-        remaining_proof_idx == proof.len(),
+        proof_idx == proof.len(),
         BridgeError::UnusedProofElementsRemaining
     );
+    require!(root == Some(*expected_root), BridgeError::InvalidProof);
 
-    // 4. Bag the peaks (left-to-right).
-    // `all_peak_hashes` is already in left-to-right mountain order.
-    if all_peak_hashes.is_empty() {
-        // Unreachable due to precondition `total_leaf_count > 0`; retained as a safeguard.
-        require!(
-            total_leaf_count == 0,
-            BridgeError::NoPeaksFoundForNonEmptyMmr
-        );
-
-        // For an empty MMR, the empty root is defined as `[0u8; 32]`.
-        return Ok([0u8; 32]);
-    }
+    Ok(())
+}
 
-    let mut current_root = all_peak_hashes[0]; // Start with the leftmost peak.
-    for peak_hash in all_peak_hashes.iter().skip(1) {
+/// Bags the next peak (left-to-right) onto the peaks bagged so far.
+fn bag_peak(bagged: Option<[u8; 32]>, peak_hash: [u8; 32]) -> [u8; 32] {
+    match bagged {
         // Bagging must be ORDERED (non-commutative) to bind each peak
         // to its mountain position/size.
-        current_root = ordered_keccak256(current_root, *peak_hash);
+        Some(left) => ordered_keccak256(left, peak_hash),
+        // Start with the leftmost peak.
+        None => peak_hash,
     }
-
-    Ok(current_root)
 }
 
 // Commutative Keccak256 of a pair of bytes32 by sorting the inputs first
 // and hashing their concatenation. Used for intra-mountain Merkle paths
 // where left/right orientation is not required.
-fn commutative_keccak256(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+pub(crate) fn commutative_keccak256(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
     if a < b {
         efficient_keccak256(&a, &b)
     } else {
@@ -166,14 +222,201 @@ fn commutative_keccak256(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
 
 // Ordered (non-commutative) Keccak256: left || right
 // Used for bagging peaks to bind the order/position of mountains.
-fn ordered_keccak256(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+pub(crate) fn ordered_keccak256(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
     efficient_keccak256(&left, &right)
 }
 
-// Efficient Keccak256 of the concatenation of two bytes32 values.
+// Efficient Keccak256 of the concatenation of two bytes32 values, hashed in place.
 fn efficient_keccak256(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
-    let mut data_to_hash = Vec::new();
-    data_to_hash.extend_from_slice(a);
-    data_to_hash.extend_from_slice(b);
-    keccak::hash(&data_to_hash).to_bytes()
+    keccak::hashv(&[a, b]).to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use hex_literal::hex;
+
+    use crate::test_utils::{mmr_multiproof, mmr_proof, mmr_root};
+
+    // Reference vectors built with the Base `MessageStorageLib` node layout over the leaves
+    // `[1; 32]..=[7; 32]`, and checked against the original `calculate_root_from_proof`.
+    const REFERENCE_ROOTS: [[u8; 32]; 7] = [
+        hex!("0101010101010101010101010101010101010101010101010101010101010101"),
+        hex!("346d8c96a2454213fcc0daff3c96ad0398148181b9fa6488f7ae2c0af5b20aa0"),
+        hex!("f8f23a80fd4d99d9d231122e1f115145412be3856b23abcc338903e32a80c4ef"),
+        hex!("0b242b9a6559f2d9f8563485a0697b746ec58ce879e0e5ac94d4c8a250723121"),
+        hex!("d802be44eace41be3723d5841012ef4cf0dac7567b8257c62824c761c3d4801c"),
+        hex!("efd37a14ca9eadb0928d7bd9ce452498e7cb9e783f07caf78fc907e7ff408b24"),
+        hex!("d35e38231a9182097a1a6c7f9b98740e696733ca99d64382da85640eb7e46d4d"),
+    ];
+    const NODE_LEAVES_2_3: [u8; 32] =
+        hex!("15812c763262dabc33411aff2c78af2cfcf55d57327737349ab4a7321a3dca59");
+    const NODE_LEAVES_0_3: [u8; 32] =
+        hex!("0b242b9a6559f2d9f8563485a0697b746ec58ce879e0e5ac94d4c8a250723121");
+    const NODE_LEAVES_4_5: [u8; 32] =
+        hex!("75e9139531d958d0d597c7a5d1c879cb3b852eb58b0dab15a98cc72735b69ac7");
+
+    fn reference_leaves(count: u8) -> Vec<[u8; 32]> {
+        (1..=count).map(|i| [i; 32]).collect()
+    }
+
+    fn leaves(count: u64) -> Vec<[u8; 32]> {
+        (0..count)
+            .map(|i| keccak::hash(&i.to_be_bytes()).0)
+            .collect()
+    }
+
+    fn proven(leaf_hashes: &[[u8; 32]], indices: &[u64]) -> Vec<(u64, [u8; 32])> {
+        indices
+            .iter()
+            .map(|i| (*i, leaf_hashes[*i as usize]))
+            .collect()
+    }
+
+    #[test]
+    fn test_reference_vectors() {
+        for count in 1..=7u8 {
+            assert_eq!(
+                mmr_root(&reference_leaves(count)),
+                REFERENCE_ROOTS[count as usize - 1],
+                "count {count}"
+            );
+        }
+
+        let root = REFERENCE_ROOTS[6];
+        let proofs: [(u64, Vec<[u8; 32]>); 3] = [
+            (0, vec![[2; 32], NODE_LEAVES_2_3, NODE_LEAVES_4_5, [7; 32]]),
+            (4, vec![[6; 32], NODE_LEAVES_0_3, [7; 32]]),
+            (6, vec![NODE_LEAVES_0_3, NODE_LEAVES_4_5]),
+        ];
+        for (index, proof) in proofs {
+            let leaf = [index as u8 + 1; 32];
+            verify_proof(&root, &leaf, &index, &proof, 7)
+                .unwrap_or_else(|e| panic!("leaf {index}: {e:?}"));
+        }
+
+        let leaves = [(0, [1; 32]), (1, [2; 32]), (4, [5; 32])];
+        let proof = [NODE_LEAVES_2_3, [6; 32], [7; 32]];
+        assert!(verify_multiproof(&root, &leaves, &proof, 7).is_ok());
+    }
+
+    #[test]
+    fn test_proof_verifies_every_leaf() {
+        for count in 1u64..=20 {
+            let leaf_hashes = leaves(count);
+            let root = mmr_root(&leaf_hashes);
+            for i in 0..count {
+                let proof = mmr_proof(&leaf_hashes, i);
+                verify_proof(&root, &leaf_hashes[i as usize], &i, &proof, count)
+                    .unwrap_or_else(|e| panic!("count {count}, leaf {i}: {e:?}"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_proof_rejects_invalid_input() {
+        let leaf_hashes = leaves(11);
+        let root = mmr_root(&leaf_hashes);
+        let proof = mmr_proof(&leaf_hashes, 9);
+        let leaf = leaf_hashes[9];
+
+        assert!(verify_proof(&root, &leaf, &9, &proof, 11).is_ok());
+        assert!(verify_proof(&root, &leaf_hashes[8], &9, &proof, 11).is_err());
+        assert!(verify_proof(&root, &leaf, &11, &proof, 11).is_err());
+
+        let err = calculate_root_from_proof(&proof[..0], &leaf, 9, 11).unwrap_err();
+        assert_eq!(
+            err,
+            BridgeError::InsufficientProofElementsForIntraMountainPath.into()
+        );
+        let err = calculate_root_from_proof(&proof[..proof.len() - 1], &leaf, 9, 11).unwrap_err();
+        assert_eq!(
+            err,
+            BridgeError::InsufficientProofElementsForOtherMountainPeaks.into()
+        );
+        let mut extra = proof.clone();
+        extra.push([0u8; 32]);
+        let err = calculate_root_from_proof(&extra, &leaf, 9, 11).unwrap_err();
+        assert_eq!(err, BridgeError::UnusedProofElementsRemaining.into());
+
+        // Empty MMR
+        assert!(verify_proof(&[0u8; 32], &[0u8; 32], &0, &[], 0).is_ok());
+        assert!(verify_proof(&[0u8; 32], &[0u8; 32], &0, &proof, 0).is_err());
+    }
+
+    #[test]
+    fn test_multiproof_verifies_leaf_subsets() {
+        for count in [1u64, 2, 5, 7, 8, 13] {
+            let leaf_hashes = leaves(count);
+            let root = mmr_root(&leaf_hashes);
+            let subsets: Vec<Vec<u64>> = vec![
+                vec![0],
+                vec![count - 1],
+                (0..count).collect(),
+                (0..count).step_by(2).collect(),
+                (0..count).step_by(3).collect(),
+            ];
+
+            for indices in subsets {
+                let proof = mmr_multiproof(&leaf_hashes, &indices);
+                verify_multiproof(&root, &proven(&leaf_hashes, &indices), &proof, count)
+                    .unwrap_or_else(|e| panic!("count {count}, indices {indices:?}: {e:?}"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_leaf_multiproof_matches_single_proof() {
+        let leaf_hashes = leaves(7);
+        let root = mmr_root(&leaf_hashes);
+        for i in 0..7u64 {
+            let proof = mmr_multiproof(&leaf_hashes, &[i]);
+            assert!(verify_multiproof(&root, &proven(&leaf_hashes, &[i]), &proof, 7).is_ok());
+
+            // Single leaves in the first mountain share the `verify_proof` layout
+            if i < 4 {
+                assert!(verify_proof(&root, &leaf_hashes[i as usize], &i, &proof, 7).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiproof_shares_siblings() {
+        let leaf_hashes = leaves(8);
+        // Leaves 0 and 1 are siblings, so only 2 + 4 siblings are needed instead of 3 + 3
+        let proof = mmr_multiproof(&leaf_hashes, &[0, 1]);
+        assert_eq!(proof.len(), 2);
+    }
+
+    #[test]
+    fn test_multiproof_rejects_invalid_input() {
+        let leaf_hashes = leaves(7);
+        let root = mmr_root(&leaf_hashes);
+        let indices = [1u64, 4, 6];
+        let proof = mmr_multiproof(&leaf_hashes, &indices);
+        let leaves = proven(&leaf_hashes, &indices);
+
+        // Wrong leaf hash
+        let mut tampered = leaves.clone();
+        tampered[1].1 = [9u8; 32];
+        assert!(verify_multiproof(&root, &tampered, &proof, 7).is_err());
+
+        // Unsorted or duplicate leaves
+        let unsorted = vec![leaves[1], leaves[0], leaves[2]];
+        assert!(verify_multiproof(&root, &unsorted, &proof, 7).is_err());
+        let duplicate = vec![leaves[0], leaves[0]];
+        assert!(verify_multiproof(&root, &duplicate, &proof, 7).is_err());
+
+        // Leaf past the end of the MMR
+        assert!(verify_multiproof(&root, &[(7, [0u8; 32])], &proof, 7).is_err());
+
+        // Missing or extra proof elements
+        assert!(verify_multiproof(&root, &leaves, &proof[1..], 7).is_err());
+        let mut extra = proof.clone();
+        extra.push([0u8; 32]);
+        assert!(verify_multiproof(&root, &leaves, &extra, 7).is_err());
+
+        assert!(verify_multiproof(&root, &[], &[], 7).is_err());
+    }
 }
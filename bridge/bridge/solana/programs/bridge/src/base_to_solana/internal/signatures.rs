@@ -1,7 +1,10 @@
-use crate::BridgeError;
+use crate::{common::bridge::AttestationConfig, BridgeError};
 use anchor_lang::{
     prelude::*,
-    solana_program::{keccak, secp256k1_recover::secp256k1_recover},
+    solana_program::{
+        ed25519_program, keccak, secp256k1_program, secp256k1_recover::secp256k1_recover,
+        sysvar::instructions::load_instruction_at_checked,
+    },
 };
 
 /// message = keccak256("\x19Ethereum Signed Message:\n" || len || (output_root || base_block_number_be || total_leaf_count_be))
@@ -16,6 +19,28 @@ pub fn compute_output_root_message_hash(
     message_bytes.extend_from_slice(&base_block_number.to_be_bytes());
     message_bytes.extend_from_slice(&total_leaf_count.to_be_bytes());
 
+    hash_eth_signed_message(&message_bytes)
+}
+
+/// message = keccak256("\x19Ethereum Signed Message:\n" || len || ("INVALIDATE_OUTPUT_ROOT" || output_root || base_block_number_be || total_leaf_count_be))
+pub fn compute_output_root_invalidation_hash(
+    output_root: &[u8; 32],
+    base_block_number: u64,
+    total_leaf_count: u64,
+) -> [u8; 32] {
+    let mut message_bytes = Vec::with_capacity(OUTPUT_ROOT_INVALIDATION_TAG.len() + 32 + 8 + 8);
+    message_bytes.extend_from_slice(OUTPUT_ROOT_INVALIDATION_TAG);
+    message_bytes.extend_from_slice(output_root);
+    message_bytes.extend_from_slice(&base_block_number.to_be_bytes());
+    message_bytes.extend_from_slice(&total_leaf_count.to_be_bytes());
+
+    hash_eth_signed_message(&message_bytes)
+}
+
+/// Tag prepended to invalidation attestations so they can never be mistaken for a registration
+const OUTPUT_ROOT_INVALIDATION_TAG: &[u8] = b"INVALIDATE_OUTPUT_ROOT";
+
+fn hash_eth_signed_message(message_bytes: &[u8]) -> [u8; 32] {
     // Apply the Ethereum signed message prefix per EIP-191
     // "\x19Ethereum Signed Message:\n" + len(message) + message
     let prefix: &[u8] = b"\x19Ethereum Signed Message:\n";
@@ -25,21 +50,135 @@ pub fn compute_output_root_message_hash(
         Vec::with_capacity(prefix.len() + len_dec_string.len() + message_bytes.len());
     prefixed.extend_from_slice(prefix);
     prefixed.extend_from_slice(len_dec_string.as_bytes());
-    prefixed.extend_from_slice(&message_bytes);
+    prefixed.extend_from_slice(message_bytes);
 
     keccak::hash(&prefixed).0
 }
 
-/// Recover unique 20-byte EVM addresses from signatures over the given message hash
+const EIP712_DOMAIN_TYPE: &[u8] =
+    b"EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)";
+const EIP712_DOMAIN_NAME: &[u8] = b"Base Solana Bridge";
+const EIP712_DOMAIN_VERSION: &[u8] = b"1";
+const OUTPUT_ROOT_TYPE: &[u8] =
+    b"OutputRoot(bytes32 root,uint64 baseBlockNumber,uint64 totalLeafCount)";
+const INVALIDATE_OUTPUT_ROOT_TYPE: &[u8] =
+    b"InvalidateOutputRoot(bytes32 root,uint64 baseBlockNumber,uint64 totalLeafCount)";
+
+/// Digests the oracles may have signed to attest to an output root, one per accepted format
+pub fn output_root_attestation_digests(
+    config: &AttestationConfig,
+    output_root: &[u8; 32],
+    base_block_number: u64,
+    total_leaf_count: u64,
+) -> Vec<[u8; 32]> {
+    let mut digests = Vec::with_capacity(2);
+    if config.accepts_legacy() {
+        digests.push(compute_output_root_message_hash(
+            output_root,
+            base_block_number,
+            total_leaf_count,
+        ));
+    }
+    if config.accepts_eip712() {
+        digests.push(compute_typed_output_root_hash(
+            config,
+            OUTPUT_ROOT_TYPE,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+        ));
+    }
+    digests
+}
+
+/// Digests the partner signers may have signed to invalidate an output root, one per accepted format
+pub fn output_root_invalidation_digests(
+    config: &AttestationConfig,
+    output_root: &[u8; 32],
+    base_block_number: u64,
+    total_leaf_count: u64,
+) -> Vec<[u8; 32]> {
+    let mut digests = Vec::with_capacity(2);
+    if config.accepts_legacy() {
+        digests.push(compute_output_root_invalidation_hash(
+            output_root,
+            base_block_number,
+            total_leaf_count,
+        ));
+    }
+    if config.accepts_eip712() {
+        digests.push(compute_typed_output_root_hash(
+            config,
+            INVALIDATE_OUTPUT_ROOT_TYPE,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+        ));
+    }
+    digests
+}
+
+/// message = keccak256("\x19\x01" || domainSeparator || keccak256(typeHash || output_root || base_block_number || total_leaf_count))
+///
+/// The domain binds the attestation to the Base chain id, and through its salt
+/// (`keccak256(solana_genesis_hash || program_id)`) to the Solana cluster and this program.
+pub fn compute_typed_output_root_hash(
+    config: &AttestationConfig,
+    struct_type: &[u8],
+    output_root: &[u8; 32],
+    base_block_number: u64,
+    total_leaf_count: u64,
+) -> [u8; 32] {
+    let struct_hash = keccak::hashv(&[
+        &keccak::hash(struct_type).0,
+        output_root,
+        &abi_encode_u64(base_block_number),
+        &abi_encode_u64(total_leaf_count),
+    ]);
+
+    keccak::hashv(&[
+        b"\x19\x01",
+        &eip712_domain_separator(config),
+        &struct_hash.0,
+    ])
+    .0
+}
+
+fn eip712_domain_separator(config: &AttestationConfig) -> [u8; 32] {
+    let salt = keccak::hashv(&[&config.solana_genesis_hash, crate::ID.as_ref()]);
+
+    keccak::hashv(&[
+        &keccak::hash(EIP712_DOMAIN_TYPE).0,
+        &keccak::hash(EIP712_DOMAIN_NAME).0,
+        &keccak::hash(EIP712_DOMAIN_VERSION).0,
+        &abi_encode_u64(config.base_chain_id),
+        &salt.0,
+    ])
+    .0
+}
+
+/// ABI-encodes an unsigned integer as a 32-byte big-endian word
+fn abi_encode_u64(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Recover unique 20-byte EVM addresses from signatures over any of the given message hashes.
+///
+/// Each signature is recovered against every hash; recovering against a digest that was not
+/// signed yields an unrelated address that will not match a configured signer.
 pub fn recover_unique_evm_addresses(
     signatures: &[[u8; 65]],
-    message_hash: &[u8; 32],
+    message_hashes: &[[u8; 32]],
 ) -> Result<Vec<[u8; 20]>> {
     let mut unique_signers: Vec<[u8; 20]> = Vec::new();
     for sig in signatures.iter() {
-        let recovered = recover_eth_address(sig, message_hash)?;
-        if !unique_signers.iter().any(|s| s == &recovered) {
-            unique_signers.push(recovered);
+        for message_hash in message_hashes.iter() {
+            let recovered = recover_eth_address(sig, message_hash)?;
+            if !unique_signers.iter().any(|s| s == &recovered) {
+                unique_signers.push(recovered);
+            }
         }
     }
     Ok(unique_signers)
@@ -67,3 +206,310 @@ pub fn recover_eth_address(signature: &[u8; 65], message_hash: &[u8; 32]) -> Res
     eth_pubkey_bytes.copy_from_slice(&h[12..]);
     Ok(eth_pubkey_bytes)
 }
+
+/// Size of the Ed25519 program instruction header (signature count and padding byte)
+const ED25519_HEADER_LEN: usize = 2;
+/// Size of one `Ed25519SignatureOffsets` entry (seven little-endian u16 fields)
+const ED25519_OFFSETS_LEN: usize = 14;
+/// Instruction index the Ed25519 program uses to reference its own instruction data
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Size of the Secp256k1 program instruction header (signature count)
+const SECP256K1_HEADER_LEN: usize = 1;
+/// Size of one `SecpSignatureOffsets` entry (u16, u8, u16, u8, u16, u16, u8)
+const SECP256K1_OFFSETS_LEN: usize = 11;
+
+/// Oracle signers whose signatures were checked by native signature verification programs
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PrecompileSigners {
+    /// EVM addresses verified by the Secp256k1 program
+    pub evm: Vec<[u8; 20]>,
+    /// Public keys verified by the Ed25519 program
+    pub ed25519: Vec<Pubkey>,
+}
+
+/// Collect unique signers that signed any of the given message hashes, as verified by Secp256k1
+/// and Ed25519 program instructions in the current transaction.
+///
+/// The runtime verifies every precompile instruction before the transaction executes, so its
+/// presence in the instructions sysvar proves the signature without running the recovery in the
+/// program. Only entries whose signature, signer key and message all live in the precompile
+/// instruction's own data are considered.
+pub fn verified_precompile_signers(
+    instructions: &AccountInfo,
+    message_hashes: &[[u8; 32]],
+) -> PrecompileSigners {
+    let mut signers = PrecompileSigners::default();
+    let mut index = 0;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions) {
+        if ix.program_id == secp256k1_program::ID {
+            for signer in secp256k1_signers_of(&ix.data, index, message_hashes) {
+                if !signers.evm.contains(&signer) {
+                    signers.evm.push(signer);
+                }
+            }
+        } else if ix.program_id == ed25519_program::ID {
+            for signer in ed25519_signers_of(&ix.data, message_hashes) {
+                if !signers.ed25519.contains(&signer) {
+                    signers.ed25519.push(signer);
+                }
+            }
+        }
+        index += 1;
+    }
+    signers
+}
+
+/// Parse the data of the Secp256k1 program instruction at `ix_index` and return the EVM
+/// addresses whose signed message hashes (keccak256 of the message) to one of `message_hashes`.
+/// Malformed or externally referenced entries are skipped.
+fn secp256k1_signers_of(
+    data: &[u8],
+    ix_index: usize,
+    message_hashes: &[[u8; 32]],
+) -> Vec<[u8; 20]> {
+    let (Some(&num_signatures), Ok(ix_index)) = (data.first(), u8::try_from(ix_index)) else {
+        return Vec::new();
+    };
+
+    (0..num_signatures as usize)
+        .filter_map(|i| {
+            let start = SECP256K1_HEADER_LEN + i * SECP256K1_OFFSETS_LEN;
+            let offsets = data.get(start..start + SECP256K1_OFFSETS_LEN)?;
+            let field = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]) as usize;
+
+            let (signature_ix, address_ix, message_ix) = (offsets[2], offsets[5], offsets[10]);
+            if signature_ix != ix_index || address_ix != ix_index || message_ix != ix_index {
+                return None;
+            }
+
+            let (message_offset, message_size) = (field(6), field(8));
+            let message = data.get(message_offset..message_offset + message_size)?;
+            if !message_hashes.contains(&keccak::hash(message).0) {
+                return None;
+            }
+
+            let address_offset = field(3);
+            data.get(address_offset..address_offset + 20)?
+                .try_into()
+                .ok()
+        })
+        .collect()
+}
+
+/// Parse the data of an Ed25519 program instruction and return the public keys whose signed
+/// message is one of `message_hashes`. Malformed or externally referenced entries are skipped.
+fn ed25519_signers_of(data: &[u8], message_hashes: &[[u8; 32]]) -> Vec<Pubkey> {
+    let Some(&num_signatures) = data.first() else {
+        return Vec::new();
+    };
+
+    (0..num_signatures as usize)
+        .filter_map(|i| {
+            let start = ED25519_HEADER_LEN + i * ED25519_OFFSETS_LEN;
+            let offsets = data.get(start..start + ED25519_OFFSETS_LEN)?;
+            let field = |n: usize| u16::from_le_bytes([offsets[2 * n], offsets[2 * n + 1]]);
+
+            let (signature_ix, pubkey_offset, pubkey_ix) = (field(1), field(2), field(3));
+            let (message_offset, message_size, message_ix) = (field(4), field(5), field(6));
+            if signature_ix != ED25519_CURRENT_INSTRUCTION
+                || pubkey_ix != ED25519_CURRENT_INSTRUCTION
+                || message_ix != ED25519_CURRENT_INSTRUCTION
+                || message_size != 32
+            {
+                return None;
+            }
+
+            let message_offset = message_offset as usize;
+            let message = data.get(message_offset..message_offset + 32)?;
+            if !message_hashes.iter().any(|hash| hash[..] == *message) {
+                return None;
+            }
+
+            let pubkey_offset = pubkey_offset as usize;
+            let pubkey = data.get(pubkey_offset..pubkey_offset + 32)?;
+            Pubkey::try_from(pubkey).ok()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::common::bridge::AttestationFormat;
+
+    fn config(format: AttestationFormat) -> AttestationConfig {
+        AttestationConfig {
+            format,
+            base_chain_id: 8453,
+            solana_genesis_hash: [1u8; 32],
+        }
+    }
+
+    #[test]
+    fn test_attestation_digests_follow_format() {
+        let root = [2u8; 32];
+        let legacy = compute_output_root_message_hash(&root, 600, 3);
+        let typed = compute_typed_output_root_hash(
+            &config(AttestationFormat::Eip712),
+            OUTPUT_ROOT_TYPE,
+            &root,
+            600,
+            3,
+        );
+
+        assert_eq!(
+            output_root_attestation_digests(&config(AttestationFormat::Legacy), &root, 600, 3),
+            vec![legacy]
+        );
+        assert_eq!(
+            output_root_attestation_digests(&config(AttestationFormat::Eip712), &root, 600, 3),
+            vec![typed]
+        );
+        assert_eq!(
+            output_root_attestation_digests(
+                &config(AttestationFormat::LegacyAndEip712),
+                &root,
+                600,
+                3
+            ),
+            vec![legacy, typed]
+        );
+    }
+
+    #[test]
+    fn test_typed_hash_is_domain_separated() {
+        let root = [2u8; 32];
+        let base = config(AttestationFormat::Eip712);
+        let digest = compute_typed_output_root_hash(&base, OUTPUT_ROOT_TYPE, &root, 600, 3);
+
+        let other_chain = AttestationConfig {
+            base_chain_id: 84532,
+            ..base.clone()
+        };
+        let other_cluster = AttestationConfig {
+            solana_genesis_hash: [9u8; 32],
+            ..base.clone()
+        };
+
+        assert_ne!(
+            digest,
+            compute_typed_output_root_hash(&other_chain, OUTPUT_ROOT_TYPE, &root, 600, 3)
+        );
+        assert_ne!(
+            digest,
+            compute_typed_output_root_hash(&other_cluster, OUTPUT_ROOT_TYPE, &root, 600, 3)
+        );
+        assert_ne!(
+            digest,
+            compute_typed_output_root_hash(&base, INVALIDATE_OUTPUT_ROOT_TYPE, &root, 600, 3)
+        );
+    }
+
+    /// Build Ed25519 program instruction data for one signature with every field inline
+    fn ed25519_data(pubkey: [u8; 32], message: &[u8], message_ix: u16) -> Vec<u8> {
+        let pubkey_offset = (ED25519_HEADER_LEN + ED25519_OFFSETS_LEN) as u16;
+        let signature_offset = pubkey_offset + 32;
+        let message_offset = signature_offset + 64;
+
+        let mut data = vec![1u8, 0];
+        for field in [
+            signature_offset,
+            ED25519_CURRENT_INSTRUCTION,
+            pubkey_offset,
+            ED25519_CURRENT_INSTRUCTION,
+            message_offset,
+            message.len() as u16,
+            message_ix,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(&pubkey);
+        data.extend_from_slice(&[0u8; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_ed25519_signers_of_matches_digest() {
+        let digest = [7u8; 32];
+        let data = ed25519_data([3u8; 32], &digest, ED25519_CURRENT_INSTRUCTION);
+
+        assert_eq!(
+            ed25519_signers_of(&data, &[[1u8; 32], digest]),
+            vec![Pubkey::new_from_array([3u8; 32])]
+        );
+        assert!(ed25519_signers_of(&data, &[[1u8; 32]]).is_empty());
+    }
+
+    #[test]
+    fn test_ed25519_signers_of_skips_external_or_malformed_entries() {
+        let digest = [7u8; 32];
+
+        // Message read from another instruction
+        let data = ed25519_data([3u8; 32], &digest, 0);
+        assert!(ed25519_signers_of(&data, &[digest]).is_empty());
+
+        // Message that is not a 32-byte digest
+        let data = ed25519_data([3u8; 32], &[7u8; 33], ED25519_CURRENT_INSTRUCTION);
+        assert!(ed25519_signers_of(&data, &[digest]).is_empty());
+
+        // Truncated instruction data
+        let data = ed25519_data([3u8; 32], &digest, ED25519_CURRENT_INSTRUCTION);
+        assert!(ed25519_signers_of(&data[..data.len() - 1], &[digest]).is_empty());
+        assert!(ed25519_signers_of(&[], &[digest]).is_empty());
+    }
+
+    /// Build Secp256k1 program instruction data for one signature with every field inline
+    fn secp256k1_data(address: [u8; 20], message: &[u8], ix_index: u8) -> Vec<u8> {
+        let address_offset = (SECP256K1_HEADER_LEN + SECP256K1_OFFSETS_LEN) as u16;
+        let signature_offset = address_offset + 20;
+        let message_offset = signature_offset + 65;
+
+        let mut data = vec![1u8];
+        data.extend_from_slice(&signature_offset.to_le_bytes());
+        data.push(ix_index);
+        data.extend_from_slice(&address_offset.to_le_bytes());
+        data.push(ix_index);
+        data.extend_from_slice(&message_offset.to_le_bytes());
+        data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+        data.push(ix_index);
+        data.extend_from_slice(&address);
+        data.extend_from_slice(&[0u8; 65]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_secp256k1_signers_of_matches_hashed_message() {
+        let message = b"attested preimage";
+        let digest = keccak::hash(message).0;
+        let data = secp256k1_data([3u8; 20], message, 1);
+
+        assert_eq!(
+            secp256k1_signers_of(&data, 1, &[[1u8; 32], digest]),
+            vec![[3u8; 20]]
+        );
+        assert!(secp256k1_signers_of(&data, 1, &[[1u8; 32]]).is_empty());
+    }
+
+    #[test]
+    fn test_secp256k1_signers_of_skips_external_or_malformed_entries() {
+        let message = b"attested preimage";
+        let digest = keccak::hash(message).0;
+
+        // Fields read from another instruction
+        let data = secp256k1_data([3u8; 20], message, 0);
+        assert!(secp256k1_signers_of(&data, 1, &[digest]).is_empty());
+
+        // Instruction index outside the range the Secp256k1 program can reference
+        let data = secp256k1_data([3u8; 20], message, 0);
+        assert!(secp256k1_signers_of(&data, 256, &[digest]).is_empty());
+
+        // Truncated instruction data
+        let data = secp256k1_data([3u8; 20], message, 1);
+        assert!(secp256k1_signers_of(&data[..data.len() - 1], 1, &[digest]).is_empty());
+        assert!(secp256k1_signers_of(&[], 1, &[digest]).is_empty());
+    }
+}
@@ -0,0 +1,382 @@
+/// Base oracle signer registry used to authorize output root registrations.
+///
+/// The registry lives in its own PDAs rather than on the `Bridge` account so that the committee
+/// can grow or shrink without a program upgrade or an account migration. The account is
+/// reallocated whenever a signer is added or removed.
+///
+/// Signer sets are versioned by epoch, one account per epoch (seeds `BASE_ORACLE_SIGNERS_SEED`
+/// and the little-endian epoch number). Each epoch covers the Base block numbers from its
+/// `activation_block_number` up to (excluding) its `expiry_block_number`. Starting the next
+/// epoch sets the expiry of the current one to the next activation plus an overlap, during which
+/// roots signed by either set are accepted.
+///
+/// How it is used:
+/// - The `register_output_root` instruction checks that the provided epoch covers the root's
+///   `base_block_number`, recovers unique EVM signer addresses from the provided signatures and
+///   collects the Ed25519 keys verified by Ed25519 program instructions in the transaction, then
+///   calls `BaseOracleSigners::approved_weight` to sum the weights of those keys that appear in
+///   this registry. Both kinds of signers count toward the same threshold.
+/// - The resulting weight is compared against `threshold`.
+///
+/// Notes:
+/// - Only the latest epoch (the one that never expires) can be updated.
+/// - Up to `MAX_BASE_ORACLE_SIGNER_COUNT` signers are supported to bound the lookup cost.
+/// - The signer manager updates the registry through `add_base_oracle_signer`,
+///   `remove_base_oracle_signer`, `replace_base_oracle_signer` and `set_base_oracle_threshold`.
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{DISCRIMINATOR_LEN, MAX_BASE_ORACLE_SIGNER_COUNT},
+    BridgeError,
+};
+
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct BaseOracleSigners {
+    /// Sequential number of this signer set, starting at 0
+    pub epoch: u64,
+    /// First Base block number covered by this signer set
+    pub activation_block_number: u64,
+    /// Base block number from which this signer set is no longer accepted;
+    /// `u64::MAX` for the latest epoch
+    pub expiry_block_number: u64,
+    /// Total weight of valid unique signatures required
+    pub threshold: u16,
+    /// Authorized signers
+    pub signers: Vec<BaseOracleSigner>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct BaseOracleSigner {
+    /// Key the signer attests with
+    pub key: OracleSignerKey,
+    /// Weight of the signer's approval
+    pub weight: u8,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AnchorSerialize,
+    AnchorDeserialize,
+    InitSpace,
+)]
+pub enum OracleSignerKey {
+    /// EVM address of a secp256k1 key, recovered from Ethereum-style signatures
+    Evm([u8; 20]),
+    /// Ed25519 public key, verified through the native Ed25519 program
+    Ed25519(Pubkey),
+}
+
+impl BaseOracleSigners {
+    /// Account size needed to hold `signer_count` signers of any kind
+    pub fn space(signer_count: usize) -> usize {
+        DISCRIMINATOR_LEN + 8 + 8 + 8 + 2 + 4 + signer_count * BaseOracleSigner::INIT_SPACE
+    }
+
+    /// Whether this is the latest epoch, the only one that can be updated or superseded
+    pub fn is_latest(&self) -> bool {
+        self.expiry_block_number == u64::MAX
+    }
+
+    /// Whether roots at `base_block_number` can be attested by this signer set
+    pub fn covers(&self, base_block_number: u64) -> bool {
+        self.activation_block_number <= base_block_number
+            && base_block_number < self.expiry_block_number
+    }
+
+    /// Fail with `BaseOracleEpochSuperseded` unless this is the latest epoch
+    pub fn require_latest(&self) -> Result<()> {
+        require!(self.is_latest(), BridgeError::BaseOracleEpochSuperseded);
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.signers.len() <= MAX_BASE_ORACLE_SIGNER_COUNT as usize,
+            BridgeError::TooManySigners
+        );
+
+        require!(
+            self.signers.iter().all(|s| s.weight > 0),
+            BridgeError::InvalidSignerWeight
+        );
+
+        require!(
+            self.threshold > 0 && self.threshold as u32 <= self.total_weight(),
+            BridgeError::InvalidThreshold
+        );
+
+        let mut keys: Vec<OracleSignerKey> = self.signers.iter().map(|s| s.key).collect();
+        keys.sort();
+        keys.dedup();
+        require!(
+            keys.len() == self.signers.len(),
+            BridgeError::DuplicateSigner
+        );
+
+        Ok(())
+    }
+
+    pub fn contains(&self, key: &OracleSignerKey) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Sum the weights of the authorized signers among the provided (unique) EVM addresses
+    /// and Ed25519 public keys
+    pub fn approved_weight(&self, evm_signers: &[[u8; 20]], ed25519_signers: &[Pubkey]) -> u32 {
+        let evm_keys = evm_signers.iter().map(|addr| OracleSignerKey::Evm(*addr));
+        let ed25519_keys = ed25519_signers
+            .iter()
+            .map(|pubkey| OracleSignerKey::Ed25519(*pubkey));
+
+        evm_keys
+            .chain(ed25519_keys)
+            .filter_map(|key| self.position(&key))
+            .map(|idx| self.signers[idx].weight as u32)
+            .sum()
+    }
+
+    pub fn total_weight(&self) -> u32 {
+        self.signers.iter().map(|s| s.weight as u32).sum()
+    }
+
+    /// Append `signer` to the registry
+    pub fn add(&mut self, signer: BaseOracleSigner) -> Result<()> {
+        require!(!self.contains(&signer.key), BridgeError::DuplicateSigner);
+        self.signers.push(signer);
+        Ok(())
+    }
+
+    /// Remove the signer attesting with `key` from the registry
+    pub fn remove(&mut self, key: &OracleSignerKey) -> Result<()> {
+        let idx = self.position(key).ok_or(BridgeError::SignerNotFound)?;
+        self.signers.remove(idx);
+        Ok(())
+    }
+
+    /// Swap the signer attesting with `key` for `signer`, keeping its position
+    pub fn replace(&mut self, key: &OracleSignerKey, signer: BaseOracleSigner) -> Result<()> {
+        let idx = self.position(key).ok_or(BridgeError::SignerNotFound)?;
+        require!(
+            signer.key == *key || !self.contains(&signer.key),
+            BridgeError::DuplicateSigner
+        );
+        self.signers[idx] = signer;
+        Ok(())
+    }
+
+    /// Supersede this epoch with the next one, activated at `activation_block_number`.
+    ///
+    /// This epoch stays accepted for `overlap_blocks` past the activation. The next epoch starts
+    /// with a copy of this signer set. The activation must be past `latest_block_number`, the
+    /// latest registered Base block number, and past the activation of this epoch.
+    pub fn start_next_epoch(
+        &mut self,
+        latest_block_number: u64,
+        activation_block_number: u64,
+        overlap_blocks: u64,
+    ) -> Result<Self> {
+        self.require_latest()?;
+        require!(
+            activation_block_number > latest_block_number
+                && activation_block_number > self.activation_block_number,
+            BridgeError::InvalidEpochActivation
+        );
+
+        self.expiry_block_number = activation_block_number.saturating_add(overlap_blocks);
+
+        Ok(Self {
+            epoch: self.epoch + 1,
+            activation_block_number,
+            expiry_block_number: u64::MAX,
+            threshold: self.threshold,
+            signers: self.signers.clone(),
+        })
+    }
+
+    fn position(&self, key: &OracleSignerKey) -> Option<usize> {
+        self.signers.iter().position(|s| s.key == *key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer(byte: u8, weight: u8) -> BaseOracleSigner {
+        BaseOracleSigner {
+            key: OracleSignerKey::Evm([byte; 20]),
+            weight,
+        }
+    }
+
+    fn ed25519_signer(byte: u8, weight: u8) -> BaseOracleSigner {
+        BaseOracleSigner {
+            key: OracleSignerKey::Ed25519(Pubkey::new_from_array([byte; 32])),
+            weight,
+        }
+    }
+
+    fn registry(threshold: u16, signers: Vec<BaseOracleSigner>) -> BaseOracleSigners {
+        BaseOracleSigners {
+            epoch: 0,
+            activation_block_number: 0,
+            expiry_block_number: u64::MAX,
+            threshold,
+            signers,
+        }
+    }
+
+    #[test]
+    fn test_space_fits_any_signer_kind() {
+        let mut data = Vec::new();
+        registry(2, vec![ed25519_signer(1, 1), ed25519_signer(2, 3)])
+            .try_serialize(&mut data)
+            .unwrap();
+        assert_eq!(data.len(), BaseOracleSigners::space(2));
+
+        let mut data = Vec::new();
+        registry(2, vec![signer(1, 1), signer(2, 3)])
+            .try_serialize(&mut data)
+            .unwrap();
+        assert!(data.len() < BaseOracleSigners::space(2));
+    }
+
+    #[test]
+    fn test_epoch_coverage() {
+        let mut signers = registry(1, vec![signer(1, 1)]);
+        assert!(signers.is_latest());
+        assert!(signers.covers(0));
+        assert!(signers.covers(u64::MAX - 1));
+
+        signers.activation_block_number = 100;
+        signers.expiry_block_number = 250;
+        assert!(!signers.is_latest());
+        assert_eq!(
+            signers.require_latest().unwrap_err(),
+            BridgeError::BaseOracleEpochSuperseded.into()
+        );
+        assert!(!signers.covers(99));
+        assert!(signers.covers(100));
+        assert!(signers.covers(249));
+        assert!(!signers.covers(250));
+    }
+
+    #[test]
+    fn test_approved_weight() {
+        let signers = registry(4, vec![signer(1, 1), signer(2, 3), signer(3, 2)]);
+        assert!(signers.validate().is_ok());
+
+        assert_eq!(signers.approved_weight(&[[2u8; 20]], &[]), 3);
+        assert_eq!(signers.approved_weight(&[[1u8; 20], [2u8; 20]], &[]), 4);
+        assert_eq!(signers.approved_weight(&[[3u8; 20], [9u8; 20]], &[]), 2);
+    }
+
+    #[test]
+    fn test_approved_weight_counts_both_signer_kinds() {
+        let signers = registry(3, vec![signer(1, 1), ed25519_signer(1, 2)]);
+        assert!(signers.validate().is_ok());
+
+        let ed25519_key = Pubkey::new_from_array([1u8; 32]);
+        assert_eq!(signers.approved_weight(&[[1u8; 20]], &[]), 1);
+        assert_eq!(signers.approved_weight(&[], &[ed25519_key]), 2);
+        assert_eq!(signers.approved_weight(&[[1u8; 20]], &[ed25519_key]), 3);
+        assert_eq!(
+            signers.approved_weight(&[], &[Pubkey::new_from_array([2u8; 32])]),
+            0
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_unreachable_threshold() {
+        let signers = vec![signer(1, 1), signer(2, 3), signer(3, 2)];
+        assert!(registry(6, signers.clone()).validate().is_ok());
+        assert_eq!(
+            registry(7, signers.clone()).validate().unwrap_err(),
+            BridgeError::InvalidThreshold.into()
+        );
+        assert_eq!(
+            registry(0, signers).validate().unwrap_err(),
+            BridgeError::InvalidThreshold.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_weight_and_duplicates() {
+        assert_eq!(
+            registry(1, vec![signer(1, 1), signer(2, 0)])
+                .validate()
+                .unwrap_err(),
+            BridgeError::InvalidSignerWeight.into()
+        );
+        assert_eq!(
+            registry(1, vec![signer(1, 1), signer(1, 1)])
+                .validate()
+                .unwrap_err(),
+            BridgeError::DuplicateSigner.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_too_many_signers() {
+        let signers = (0..=MAX_BASE_ORACLE_SIGNER_COUNT as u16)
+            .map(|i| {
+                let mut evm_address = [0u8; 20];
+                evm_address[18..].copy_from_slice(&i.to_be_bytes());
+                BaseOracleSigner {
+                    key: OracleSignerKey::Evm(evm_address),
+                    weight: 1,
+                }
+            })
+            .collect();
+        assert_eq!(
+            registry(1, signers).validate().unwrap_err(),
+            BridgeError::TooManySigners.into()
+        );
+    }
+
+    #[test]
+    fn test_add_remove_replace() {
+        let mut signers = registry(1, vec![signer(1, 1)]);
+
+        signers.add(signer(2, 2)).unwrap();
+        assert_eq!(
+            signers.add(signer(2, 1)).unwrap_err(),
+            BridgeError::DuplicateSigner.into()
+        );
+        assert_eq!(signers.total_weight(), 3);
+
+        signers
+            .replace(&OracleSignerKey::Evm([1; 20]), signer(3, 1))
+            .unwrap();
+        assert_eq!(
+            signers
+                .replace(&OracleSignerKey::Evm([1; 20]), signer(4, 1))
+                .unwrap_err(),
+            BridgeError::SignerNotFound.into()
+        );
+        assert_eq!(
+            signers
+                .replace(&OracleSignerKey::Evm([3; 20]), signer(2, 1))
+                .unwrap_err(),
+            BridgeError::DuplicateSigner.into()
+        );
+        signers
+            .replace(&OracleSignerKey::Evm([3; 20]), signer(3, 5))
+            .unwrap();
+        assert_eq!(signers.signers, vec![signer(3, 5), signer(2, 2)]);
+
+        signers.remove(&OracleSignerKey::Evm([3; 20])).unwrap();
+        assert_eq!(
+            signers.remove(&OracleSignerKey::Evm([3; 20])).unwrap_err(),
+            BridgeError::SignerNotFound.into()
+        );
+        assert_eq!(signers.signers, vec![signer(2, 2)]);
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::MAX_CPI_POLICY_PROGRAM_COUNT, BridgeError};
+
+/// Guardian-managed policy restricting the programs relayed Base messages can CPI into.
+///
+/// Lives at the `CPI_POLICY_SEED` PDA and is checked for every instruction executed by
+/// `relay_message`, `relay_message_with_payload` and `prove_and_relay_message`. Until the guardian
+/// creates it, the default config applies: an empty denylist. The bridge program is denied whatever
+/// the policy, as are instructions passing a bridge vault as signer or writable.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct CpiPolicy {
+    /// Guardian-controlled policy parameters
+    pub config: CpiPolicyConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct CpiPolicyConfig {
+    /// How `programs` is interpreted
+    pub mode: CpiPolicyMode,
+    /// Programs allowed or denied depending on `mode`
+    #[max_len(MAX_CPI_POLICY_PROGRAM_COUNT)]
+    pub programs: Vec<Pubkey>,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default,
+)]
+pub enum CpiPolicyMode {
+    /// Any program except the listed ones can be targeted
+    #[default]
+    Denylist,
+    /// Only the listed programs can be targeted
+    Allowlist,
+}
+
+impl CpiPolicyConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.programs.len() <= MAX_CPI_POLICY_PROGRAM_COUNT,
+            BridgeError::TooManyCpiPolicyPrograms
+        );
+
+        let mut programs = self.programs.clone();
+        programs.sort();
+        programs.dedup();
+        require!(
+            programs.len() == self.programs.len(),
+            BridgeError::DuplicateCpiPolicyProgram
+        );
+
+        Ok(())
+    }
+
+    /// Whether relayed instructions may target `program_id` under this policy
+    pub fn is_allowed(&self, program_id: &Pubkey) -> bool {
+        let listed = self.programs.contains(program_id);
+        match self.mode {
+            CpiPolicyMode::Denylist => !listed,
+            CpiPolicyMode::Allowlist => listed,
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::base_to_solana::{
     token::{FinalizeBridgeSol, FinalizeBridgeSpl, FinalizeBridgeWrappedToken},
     Ix,
 };
+use crate::solana_to_base::NATIVE_SOL_PUBKEY;
 
 /// Represents a cross-chain message sent from Base to Solana
 /// that is waiting to be processed or has already been executed.
@@ -21,12 +22,31 @@ pub struct IncomingMessage {
     /// This field does not restrict who can call the relay instruction.
     pub sender: [u8; 20],
 
+    /// The Base nonce of the message. Its bit in the matching `NonceBitmap` page is set
+    /// when the message is executed.
+    pub nonce: u64,
+
+    /// The hash of the message on Base, which also seeds this account's address.
+    pub message_hash: [u8; 32],
+
+    /// The account that paid the rent for this account when proving the message.
+    /// Receives the rent back when the executed message is closed.
+    pub payer: Pubkey,
+
     /// The actual message payload containing either instruction calls or token transfer data.
     /// This enum determines what type of operation will be executed on Solana.
     pub message: Message,
 
+    /// Execution cursor of `Message::Resumable` payloads: `None` until the first relay step, then
+    /// the index of the next instruction to execute. Unused by atomic messages.
+    pub cursor: Option<u32>,
+
+    /// Unix timestamp at which the message was proven. Once `protocol_config.bounce_delay_seconds`
+    /// have elapsed without it being relayed, its transfer can be bounced back to Base.
+    pub proven_at: i64,
+
     /// Flag indicating whether this message has been successfully executed on Solana.
-    /// Once set to true, the message cannot be executed again, preventing replay attacks.
+    /// Replay protection relies on the `NonceBitmap`, so the account can be closed once executed.
     pub executed: bool,
 }
 
@@ -35,13 +55,54 @@ impl IncomingMessage {
     ///
     /// Layout:
     /// - `sender`: 20 bytes
+    /// - `nonce`: 8 bytes
+    /// - `message_hash`: 32 bytes
+    /// - `payer`: 32 bytes
     /// - `message`: 4-byte length prefix + `data_len` bytes (Anchor-serialized `Message`)
+    /// - `cursor`: 1-byte option tag + 4 bytes
+    /// - `proven_at`: 8 bytes
     /// - `executed`: 1 byte
     pub fn space(data_len: usize) -> usize {
-        20 + (4 + data_len) + 1
+        20 + 8 + 32 + 32 + (4 + data_len) + (1 + 4) + 8 + 1
+    }
+
+    /// Returns whether a resumable message has started executing without completing yet.
+    /// Such a message must stay open until its remaining instructions are relayed.
+    pub fn is_in_progress(&self) -> bool {
+        self.cursor.is_some() && !self.executed
     }
 }
 
+/// A proven Base to Solana message that only commits to its payload hash.
+///
+/// Created by `prove_message_hash` at the same PDA as an `IncomingMessage`, so a message can only
+/// be proven once across both modes. The payload is supplied again when relaying and checked
+/// against `message_hash`, which keeps the rent constant whatever the payload size.
+///
+/// The sender is not recorded here: it is part of the message hash, so it is provided and
+/// verified along with the payload at relay time.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct IncomingMessageHash {
+    /// The Base nonce of the message. Its bit in the matching `NonceBitmap` page is set
+    /// when the message is executed.
+    pub nonce: u64,
+
+    /// The account that paid the rent for this account when proving the message.
+    /// Receives the rent back when the executed message is closed.
+    pub payer: Pubkey,
+
+    /// keccak256(nonce || sender || data) of the proven message.
+    pub message_hash: [u8; 32],
+
+    /// Unix timestamp at which the message was proven. Once `protocol_config.bounce_delay_seconds`
+    /// have elapsed without it being relayed, its transfer can be bounced back to Base.
+    pub proven_at: i64,
+
+    /// Flag indicating whether this message has been successfully executed on Solana.
+    pub executed: bool,
+}
+
 /// Defines the type of cross-chain operation being performed from Base to Solana.
 ///
 /// This enum encapsulates the two main categories of bridge operations:
@@ -61,6 +122,30 @@ pub enum Message {
         /// Additional Solana instructions to execute after the transfer is finalized
         ixs: Vec<Ix>,
     },
+
+    /// An optional transfer followed by instructions that may be executed over several
+    /// `relay_message` calls. The message author opts into non-atomic execution with this variant:
+    /// the transfer and a first batch of instructions run in one transaction, and later relays
+    /// continue from the execution cursor until all instructions have run.
+    Resumable {
+        /// The token transfer finalized by the first relay step, if any
+        transfer: Option<Transfer>,
+        /// Solana instructions to execute, in order, across one or more relay steps
+        ixs: Vec<Ix>,
+    },
+
+    /// A token transfer that pays part of the transferred amount to the relayer executing the
+    /// message, optionally followed by additional instructions. Kept separate from `Transfer` so
+    /// payloads encoded without a relayer fee keep decoding unchanged.
+    TransferWithRelayerFee {
+        /// The specific type of token transfer (SOL, SPL token, or wrapped token)
+        transfer: Transfer,
+        /// Additional Solana instructions to execute after the transfer is finalized
+        ixs: Vec<Ix>,
+        /// Portion of the transferred amount paid to the relayer, in base units of the mint.
+        /// The recipient receives the remainder.
+        relayer_fee: u64,
+    },
 }
 
 /// Specifies the type of token being finalized on Solana for a Base→Solana bridge
@@ -81,3 +166,93 @@ pub enum Transfer {
     /// Mints wrapped tokens on Solana to represent the Base asset.
     WrappedToken(FinalizeBridgeWrappedToken),
 }
+
+impl Transfer {
+    /// Returns the Solana mint released by this transfer (`NATIVE_SOL_PUBKEY` for native SOL).
+    pub fn local_token(&self) -> Pubkey {
+        match self {
+            Transfer::Sol(_) => NATIVE_SOL_PUBKEY,
+            Transfer::Spl(transfer) => transfer.local_token,
+            Transfer::WrappedToken(transfer) => transfer.local_token,
+        }
+    }
+
+    /// Returns the amount released by this transfer, in base units of the mint.
+    pub fn amount(&self) -> u64 {
+        match self {
+            Transfer::Sol(transfer) => transfer.amount,
+            Transfer::Spl(transfer) => transfer.amount,
+            Transfer::WrappedToken(transfer) => transfer.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    /// Encoded by `SVMBridgeLib` on Base for a 1000 lamport SOL transfer to `[0x11; 32]`
+    const TRANSFER: [u8; 46] = hex!(
+        "01001111111111111111111111111111111111111111111111111111111111111111e80300000000000000000000"
+    );
+    /// The same transfer paying a relayer fee of 10 lamports
+    const TRANSFER_WITH_RELAYER_FEE: [u8; 54] = hex!(
+        "03001111111111111111111111111111111111111111111111111111111111111111e803000000000000000000000a00000000000000"
+    );
+    /// A resumable message without a transfer or instructions
+    const RESUMABLE_CALL: [u8; 6] = hex!("020000000000");
+    /// The SOL transfer above as a resumable message
+    const RESUMABLE_TRANSFER: [u8; 47] = hex!(
+        "0201001111111111111111111111111111111111111111111111111111111111111111e80300000000000000000000"
+    );
+
+    #[test]
+    fn test_decode_base_encoded_messages() {
+        let Message::Transfer { transfer, ixs } = Message::try_from_slice(&TRANSFER).unwrap()
+        else {
+            panic!("expected Message::Transfer");
+        };
+        assert_eq!(transfer.amount(), 1000);
+        assert!(ixs.is_empty());
+
+        let Message::TransferWithRelayerFee {
+            transfer,
+            ixs,
+            relayer_fee,
+        } = Message::try_from_slice(&TRANSFER_WITH_RELAYER_FEE).unwrap()
+        else {
+            panic!("expected Message::TransferWithRelayerFee");
+        };
+        let Transfer::Sol(sol) = transfer else {
+            panic!("expected Transfer::Sol");
+        };
+        assert_eq!(sol.to, Pubkey::new_from_array([0x11; 32]));
+        assert_eq!(sol.amount, 1000);
+        assert!(ixs.is_empty());
+        assert_eq!(relayer_fee, 10);
+
+        let Message::Resumable { transfer, ixs } =
+            Message::try_from_slice(&RESUMABLE_CALL).unwrap()
+        else {
+            panic!("expected Message::Resumable");
+        };
+        assert!(transfer.is_none());
+        assert!(ixs.is_empty());
+
+        let Message::Resumable {
+            transfer: Some(transfer),
+            ixs,
+        } = Message::try_from_slice(&RESUMABLE_TRANSFER).unwrap()
+        else {
+            panic!("expected Message::Resumable with a transfer");
+        };
+        assert_eq!(transfer.amount(), 1000);
+        assert!(ixs.is_empty());
+
+        // A trailing relayer fee option on a plain transfer is rejected rather than ignored
+        let mut with_option = TRANSFER.to_vec();
+        with_option.push(0);
+        assert!(Message::try_from_slice(&with_option).is_err());
+    }
+}
@@ -1,9 +1,19 @@
+pub mod base_oracle_signers;
+pub mod cpi_policy;
 pub mod incoming_message;
+pub mod nonce_bitmap;
 pub mod output_root;
+pub mod pending_output_root;
 pub mod prove_buffer;
+pub mod rate_limit;
 pub mod signers;
 
+pub use base_oracle_signers::*;
+pub use cpi_policy::*;
 pub use incoming_message::*;
+pub use nonce_bitmap::*;
 pub use output_root::*;
+pub use pending_output_root::*;
 pub use prove_buffer::*;
+pub use rate_limit::*;
 pub use signers::*;
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::constants::{NONCE_BITMAP_PAGE_BITS, NONCE_BITMAP_PAGE_BYTES};
+use crate::BridgeError;
+
+/// Replay protection for Base → Solana messages, with one bit per Base nonce.
+///
+/// Nonces are split into pages of `NONCE_BITMAP_PAGE_BITS` bits, each stored in its own PDA
+/// derived from `[NONCE_BITMAP_SEED, page.to_le_bytes()]`. The relay instructions set the bit of a
+/// message's nonce when executing it, which lets the `IncomingMessage` account be closed
+/// afterwards without the message becoming replayable.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct NonceBitmap {
+    /// One bit per nonce in the page, least significant bit first
+    pub bits: [u8; NONCE_BITMAP_PAGE_BYTES],
+}
+
+impl NonceBitmap {
+    /// Returns the index of the page holding the bit of `nonce`
+    pub fn page(nonce: u64) -> u64 {
+        nonce / NONCE_BITMAP_PAGE_BITS
+    }
+
+    pub fn is_consumed(&self, nonce: u64) -> bool {
+        let (byte, mask) = Self::position(nonce);
+        self.bits[byte] & mask != 0
+    }
+
+    /// Mark `nonce` as consumed, failing with `AlreadyExecuted` if it already was
+    pub fn consume(&mut self, nonce: u64) -> Result<()> {
+        require!(!self.is_consumed(nonce), BridgeError::AlreadyExecuted);
+
+        let (byte, mask) = Self::position(nonce);
+        self.bits[byte] |= mask;
+        Ok(())
+    }
+
+    fn position(nonce: u64) -> (usize, u8) {
+        let bit = nonce % NONCE_BITMAP_PAGE_BITS;
+        ((bit / 8) as usize, 1 << (bit % 8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_bitmap() -> NonceBitmap {
+        NonceBitmap {
+            bits: [0; NONCE_BITMAP_PAGE_BYTES],
+        }
+    }
+
+    #[test]
+    fn test_page() {
+        assert_eq!(NonceBitmap::page(0), 0);
+        assert_eq!(NonceBitmap::page(NONCE_BITMAP_PAGE_BITS - 1), 0);
+        assert_eq!(NonceBitmap::page(NONCE_BITMAP_PAGE_BITS), 1);
+    }
+
+    #[test]
+    fn test_consume_sets_only_the_nonce_bit() {
+        let mut bitmap = new_bitmap();
+        let nonce = NONCE_BITMAP_PAGE_BITS * 3 + 13;
+
+        bitmap.consume(nonce).unwrap();
+
+        assert!(bitmap.is_consumed(nonce));
+        assert!(!bitmap.is_consumed(nonce - 1));
+        assert!(!bitmap.is_consumed(nonce + 1));
+        assert_eq!(bitmap.bits.iter().map(|b| b.count_ones()).sum::<u32>(), 1);
+    }
+
+    #[test]
+    fn test_consume_twice_fails() {
+        let mut bitmap = new_bitmap();
+
+        bitmap.consume(42).unwrap();
+        let err = bitmap.consume(42).unwrap_err();
+
+        assert_eq!(err, BridgeError::AlreadyExecuted.into());
+    }
+
+    #[test]
+    fn test_page_boundaries() {
+        let mut bitmap = new_bitmap();
+
+        bitmap.consume(0).unwrap();
+        bitmap.consume(NONCE_BITMAP_PAGE_BITS - 1).unwrap();
+
+        assert_eq!(bitmap.bits[0], 1);
+        assert_eq!(bitmap.bits[NONCE_BITMAP_PAGE_BYTES - 1], 0x80);
+    }
+}
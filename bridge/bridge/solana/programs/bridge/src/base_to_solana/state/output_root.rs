@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::BridgeError;
+
 /// Represents a cryptographic commitment to the set of Base L2 bridge messages
 /// at a specific Base block number.
 ///
@@ -12,6 +14,14 @@ use anchor_lang::prelude::*;
 /// 1. A trusted oracle registers output roots for specific Base blocks
 /// 2. Users prove their messages were included on Base using these roots and an MMR proof
 /// 3. Proven messages are then relayed and executed on Solana
+///
+/// A root only becomes provable once `protocol_config.root_finality_seconds` have elapsed since
+/// its registration. Until then the guardian or a quorum of partner signers can invalidate it.
+/// The guardian can also revoke a root at any time, after which a corrected root can be
+/// registered for the same Base block number.
+///
+/// Once a root is older than the retention horizon configured by the guardian, anyone can close
+/// it through `close_output_root`, returning the rent to the account that registered it.
 #[account]
 #[derive(InitSpace)]
 pub struct OutputRoot {
@@ -23,4 +33,37 @@ pub struct OutputRoot {
     /// was generated. This is crucial for determining the MMR structure and
     /// mountain configuration at the time of proof validation.
     pub total_leaf_count: u64,
+
+    /// The Base block number this output root was registered for.
+    pub base_block_number: u64,
+
+    /// The account that paid for this output root account; receives the rent when it is closed.
+    pub payer: Pubkey,
+
+    /// Unix timestamp at which this output root was registered.
+    pub registered_at: i64,
+
+    /// Whether this output root was invalidated or revoked; messages can no longer be proven
+    /// against it.
+    pub invalidated: bool,
+}
+
+impl OutputRoot {
+    /// Whether the challenge window of this root, which lasts `finality_seconds` after
+    /// registration, is still open at `now`
+    pub fn in_challenge_window(&self, finality_seconds: u64, now: i64) -> bool {
+        now < self
+            .registered_at
+            .saturating_add(finality_seconds.min(i64::MAX as u64) as i64)
+    }
+
+    /// Fail unless messages can be proven against this root at `now`
+    pub fn require_provable(&self, finality_seconds: u64, now: i64) -> Result<()> {
+        require!(!self.invalidated, BridgeError::InvalidatedOutputRoot);
+        require!(
+            !self.in_challenge_window(finality_seconds, now),
+            BridgeError::OutputRootNotFinalized
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{
+    constants::MAX_PENDING_OUTPUT_ROOT_SIGNER_COUNT, state::OracleSignerKey,
+};
+use crate::common::DISCRIMINATOR_LEN;
+use crate::BridgeError;
+
+/// An output root whose oracle attestations are collected across several transactions.
+///
+/// When the committee is too large for all signatures to fit in a single `register_output_root`
+/// call, oracle members or relayers submit them in batches through `add_output_root_signatures`.
+/// Each batch is recovered and deduplicated against the signers already collected. Once both the
+/// Base oracle and partner thresholds are met, `finalize_output_root` records the root in its
+/// `OutputRoot` account and closes this one.
+///
+/// A pending root that is not finalized before `expires_at` can be closed by anyone through
+/// `close_pending_output_root`, returning the rent to the account that created it.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct PendingOutputRoot {
+    /// The 32-byte MMR root being attested
+    pub output_root: [u8; 32],
+
+    /// The Base block number of the checkpoint
+    pub base_block_number: u64,
+
+    /// The total number of leaves in the MMR with this root
+    pub total_leaf_count: u64,
+
+    /// The account that paid for this account; receives the rent when it is closed.
+    pub payer: Pubkey,
+
+    /// Unix timestamp after which no more signatures are accepted and the account can be closed.
+    pub expires_at: i64,
+
+    /// Unique Base oracle and partner signers that attested to the root so far.
+    pub signers: Vec<OracleSignerKey>,
+}
+
+impl PendingOutputRoot {
+    /// Account size, sized up front for the largest possible set of attesting signers
+    pub const SPACE: usize = DISCRIMINATOR_LEN
+        + 32
+        + 8
+        + 8
+        + 32
+        + 8
+        + 4
+        + MAX_PENDING_OUTPUT_ROOT_SIGNER_COUNT as usize * OracleSignerKey::INIT_SPACE;
+
+    /// Whether the attestation window has closed at `now`
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Record `signer` unless it already attested
+    pub fn add_signer(&mut self, signer: OracleSignerKey) -> Result<()> {
+        if self.signers.contains(&signer) {
+            return Ok(());
+        }
+
+        require!(
+            self.signers.len() < MAX_PENDING_OUTPUT_ROOT_SIGNER_COUNT as usize,
+            BridgeError::TooManySigners
+        );
+        self.signers.push(signer);
+        Ok(())
+    }
+
+    /// EVM addresses among the collected signers
+    pub fn evm_signers(&self) -> Vec<[u8; 20]> {
+        self.signers
+            .iter()
+            .filter_map(|signer| match signer {
+                OracleSignerKey::Evm(addr) => Some(*addr),
+                OracleSignerKey::Ed25519(_) => None,
+            })
+            .collect()
+    }
+
+    /// Ed25519 public keys among the collected signers
+    pub fn ed25519_signers(&self) -> Vec<Pubkey> {
+        self.signers
+            .iter()
+            .filter_map(|signer| match signer {
+                OracleSignerKey::Ed25519(pubkey) => Some(*pubkey),
+                OracleSignerKey::Evm(_) => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending() -> PendingOutputRoot {
+        PendingOutputRoot {
+            output_root: [1u8; 32],
+            base_block_number: 300,
+            total_leaf_count: 4,
+            payer: Pubkey::new_unique(),
+            expires_at: 1_000,
+            signers: vec![],
+        }
+    }
+
+    #[test]
+    fn test_space_fits_max_signers() {
+        let mut pending = pending();
+        for i in 0..MAX_PENDING_OUTPUT_ROOT_SIGNER_COUNT {
+            pending
+                .add_signer(OracleSignerKey::Ed25519(Pubkey::new_from_array([i; 32])))
+                .unwrap();
+        }
+
+        let mut data = Vec::new();
+        pending.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), PendingOutputRoot::SPACE);
+
+        let err = pending
+            .add_signer(OracleSignerKey::Evm([1u8; 20]))
+            .unwrap_err();
+        assert_eq!(err, BridgeError::TooManySigners.into());
+    }
+
+    #[test]
+    fn test_add_signer_dedupes_and_splits_kinds() {
+        let mut pending = pending();
+        let ed25519 = Pubkey::new_unique();
+        pending.add_signer(OracleSignerKey::Evm([2u8; 20])).unwrap();
+        pending
+            .add_signer(OracleSignerKey::Ed25519(ed25519))
+            .unwrap();
+        pending.add_signer(OracleSignerKey::Evm([2u8; 20])).unwrap();
+
+        assert_eq!(pending.signers.len(), 2);
+        assert_eq!(pending.evm_signers(), vec![[2u8; 20]]);
+        assert_eq!(pending.ed25519_signers(), vec![ed25519]);
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let pending = pending();
+        assert!(!pending.is_expired(999));
+        assert!(pending.is_expired(1_000));
+    }
+}
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+
+use crate::BridgeError;
+
+/// Per-mint withdrawal rate limit applied when finalizing Base → Solana transfers.
+///
+/// Works as a token bucket: `available` is debited by every finalized transfer of `mint`
+/// and is refilled by `refill_per_window` for each elapsed window, up to `capacity`.
+/// Windows are tracked the same way as in `Eip1559`, by advancing `window_start_time`
+/// in whole multiples of the window duration.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct MintRateLimit {
+    /// The mint this limit applies to (`NATIVE_SOL_PUBKEY` for native SOL)
+    pub mint: Pubkey,
+    /// Guardian-controlled limit parameters
+    pub config: RateLimitConfig,
+    /// Amount that can still be withdrawn before the next refill (runtime state)
+    pub available: u64,
+    /// Unix timestamp when the current window started (runtime state)
+    pub window_start_time: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct RateLimitConfig {
+    /// Maximum amount that can be withdrawn in a burst, in base units of the mint
+    pub capacity: u64,
+    /// Amount credited back for every elapsed window, in base units of the mint
+    pub refill_per_window: u64,
+    /// Window duration in seconds
+    pub window_duration_seconds: u64,
+}
+
+impl RateLimitConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.window_duration_seconds > 0,
+            BridgeError::InvalidWindowDurationSeconds
+        );
+        Ok(())
+    }
+}
+
+impl MintRateLimit {
+    /// Set the limit parameters of `mint`, returning the previous ones if the limit existed.
+    /// A newly created limit starts full; on update the available amount is clamped to the new
+    /// capacity.
+    pub fn configure(
+        &mut self,
+        mint: Pubkey,
+        cfg: RateLimitConfig,
+        current_timestamp: i64,
+    ) -> Option<RateLimitConfig> {
+        if self.mint == Pubkey::default() {
+            *self = MintRateLimit {
+                mint,
+                available: cfg.capacity,
+                config: cfg,
+                window_start_time: current_timestamp,
+            };
+            return None;
+        }
+
+        // Settle the elapsed windows under the previous parameters before switching
+        self.refill(current_timestamp);
+
+        self.available = self.available.min(cfg.capacity);
+        Some(std::mem::replace(&mut self.config, cfg))
+    }
+
+    /// Credit `refill_per_window` for every window that expired since `window_start_time`
+    pub fn refill(&mut self, current_timestamp: i64) {
+        let expired_windows_count = self.expired_windows_count(current_timestamp);
+        if expired_windows_count == 0 {
+            return;
+        }
+
+        let refill = expired_windows_count.saturating_mul(self.config.refill_per_window);
+        self.available = self
+            .available
+            .saturating_add(refill)
+            .min(self.config.capacity);
+        self.window_start_time = self.window_start_time.saturating_add(
+            expired_windows_count.saturating_mul(self.config.window_duration_seconds) as i64,
+        );
+    }
+
+    /// Refill the bucket and debit `amount` from it, failing if the limit would be exceeded
+    pub fn consume(&mut self, amount: u64, current_timestamp: i64) -> Result<()> {
+        self.refill(current_timestamp);
+
+        self.available = self
+            .available
+            .checked_sub(amount)
+            .ok_or(BridgeError::WithdrawalRateLimitExceeded)?;
+
+        Ok(())
+    }
+
+    /// Check how many whole windows have elapsed since `window_start_time`
+    fn expired_windows_count(&self, current_timestamp: i64) -> u64 {
+        current_timestamp
+            .saturating_sub(self.window_start_time)
+            .max(0) as u64
+            / self.config.window_duration_seconds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limit(available: u64, window_start_time: i64) -> MintRateLimit {
+        MintRateLimit {
+            mint: Pubkey::new_unique(),
+            config: RateLimitConfig {
+                capacity: 1_000,
+                refill_per_window: 100,
+                window_duration_seconds: 60,
+            },
+            available,
+            window_start_time,
+        }
+    }
+
+    #[test]
+    fn test_consume_within_limit() {
+        let mut limit = rate_limit(1_000, 0);
+
+        limit.consume(400, 10).unwrap();
+        assert_eq!(limit.available, 600);
+
+        limit.consume(600, 20).unwrap();
+        assert_eq!(limit.available, 0);
+    }
+
+    #[test]
+    fn test_consume_over_limit_fails_without_debiting() {
+        let mut limit = rate_limit(500, 0);
+
+        let err = limit.consume(501, 10).unwrap_err();
+        assert_eq!(err, BridgeError::WithdrawalRateLimitExceeded.into());
+        assert_eq!(limit.available, 500);
+    }
+
+    #[test]
+    fn test_refill_per_expired_window() {
+        let mut limit = rate_limit(0, 1_000);
+
+        // Not a full window yet
+        limit.refill(1_059);
+        assert_eq!(limit.available, 0);
+        assert_eq!(limit.window_start_time, 1_000);
+
+        // Three windows elapsed
+        limit.refill(1_000 + 3 * 60 + 5);
+        assert_eq!(limit.available, 300);
+        assert_eq!(limit.window_start_time, 1_000 + 3 * 60);
+    }
+
+    #[test]
+    fn test_refill_is_capped_at_capacity() {
+        let mut limit = rate_limit(950, 0);
+
+        limit.refill(10 * 60);
+        assert_eq!(limit.available, 1_000);
+        assert_eq!(limit.window_start_time, 10 * 60);
+    }
+
+    #[test]
+    fn test_consume_after_refill() {
+        let mut limit = rate_limit(0, 0);
+
+        assert!(limit.consume(1, 59).is_err());
+        limit.consume(100, 60).unwrap();
+        assert_eq!(limit.available, 0);
+    }
+
+    #[test]
+    fn test_refill_ignores_clock_before_window_start() {
+        let mut limit = rate_limit(10, 1_000);
+
+        limit.refill(500);
+        assert_eq!(limit.available, 10);
+        assert_eq!(limit.window_start_time, 1_000);
+    }
+
+    #[test]
+    fn test_config_validate() {
+        let mut config = rate_limit(0, 0).config;
+        assert!(config.validate().is_ok());
+
+        config.window_duration_seconds = 0;
+        assert!(config.validate().is_err());
+    }
+}
@@ -10,11 +10,12 @@
 /// How it is used:
 /// - The `register_output_root` instruction recovers unique EVM signer
 ///   addresses from provided Secp256k1 signatures, then calls
-///   `Signers::count_approvals` to count how many of those addresses
+///   `Signers::approved_weight` to sum the weights of those addresses that
 ///   appear in this allowlist.
-/// - The resulting count is compared against
+/// - The resulting weight is compared against
 ///   `bridge.partner_oracle_config.required_threshold` to enforce that enough
-///   partner signers have approved the action.
+///   partner signers have approved the action. Weights are kept on the bridge
+///   in `PartnerOracleConfig`; signers without an explicit weight count as 1.
 ///
 /// Notes:
 /// - EVM addresses are stored as raw 20-byte values `[u8; 20]`.
@@ -22,6 +23,8 @@
 /// - Up to `MAX_SIGNER_COUNT` signers are supported to keep the account small and rent-cheap.
 use anchor_lang::prelude::*;
 
+use crate::common::bridge::PartnerOracleConfig;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Signers {
@@ -47,13 +50,14 @@ pub struct PartnerSigner {
 }
 
 impl Signers {
-    /// Count how many of the provided EVM addresses are authorized partner signers.
+    /// Sum the weights of the authorized partner signers among the provided EVM addresses.
     ///
     /// - `signers` should contain unique 20-byte addresses. The caller (e.g.
     ///   signature recovery) is expected to deduplicate beforehand to avoid
     ///   double counting.
-    /// - Returns the number of addresses present in this config's allowlist.
-    pub fn count_approvals(&self, signers: &[[u8; 20]]) -> u32 {
+    /// - Each matched signer contributes the weight `weights` assigns to its
+    ///   `evm_address`, including when it signed with its `new_evm_address`.
+    pub fn approved_weight(&self, signers: &[[u8; 20]], weights: &PartnerOracleConfig) -> u32 {
         let mut count: u32 = 0;
         // Track which indices of self.signers have already been matched so that
         // the same configured signer is not counted more than once (e.g. if
@@ -74,7 +78,7 @@ impl Signers {
 
                 if is_match_old || is_match_new {
                     matched_indices[idx] = true;
-                    count += 1;
+                    count += weights.weight_of(&configured.evm_address) as u32;
                     // Move to next provided signer. This ensures a single
                     // configured signer index cannot be matched more than once.
                     continue 'outer;
@@ -84,6 +88,14 @@ impl Signers {
 
         count
     }
+
+    /// Total weight of the partner signer set
+    pub fn total_weight(&self, weights: &PartnerOracleConfig) -> u32 {
+        self.signers
+            .iter()
+            .map(|s| weights.weight_of(&s.evm_address) as u32)
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -91,6 +103,8 @@ mod tests {
 
     use super::*;
 
+    use crate::common::bridge::PartnerSignerWeight;
+
     fn addr(byte: u8) -> [u8; 20] {
         [byte; 20]
     }
@@ -106,7 +120,10 @@ mod tests {
     fn returns_zero_when_no_configured_signers() {
         let cfg = Signers { signers: vec![] };
         let provided = [addr(1), addr(2)];
-        assert_eq!(cfg.count_approvals(&provided), 0);
+        assert_eq!(
+            cfg.approved_weight(&provided, &PartnerOracleConfig::default()),
+            0
+        );
     }
 
     #[test]
@@ -115,7 +132,10 @@ mod tests {
             signers: vec![signer(1, None)],
         };
         let provided: [[u8; 20]; 0] = [];
-        assert_eq!(cfg.count_approvals(&provided), 0);
+        assert_eq!(
+            cfg.approved_weight(&provided, &PartnerOracleConfig::default()),
+            0
+        );
     }
 
     #[test]
@@ -124,7 +144,10 @@ mod tests {
             signers: vec![signer(1, None)],
         };
         let provided = [addr(1)];
-        assert_eq!(cfg.count_approvals(&provided), 1);
+        assert_eq!(
+            cfg.approved_weight(&provided, &PartnerOracleConfig::default()),
+            1
+        );
     }
 
     #[test]
@@ -133,7 +156,10 @@ mod tests {
             signers: vec![signer(1, Some(2))],
         };
         let provided = [addr(2)];
-        assert_eq!(cfg.count_approvals(&provided), 1);
+        assert_eq!(
+            cfg.approved_weight(&provided, &PartnerOracleConfig::default()),
+            1
+        );
     }
 
     #[test]
@@ -142,7 +168,10 @@ mod tests {
             signers: vec![signer(1, Some(2))],
         };
         let provided = [addr(1), addr(2)];
-        assert_eq!(cfg.count_approvals(&provided), 1);
+        assert_eq!(
+            cfg.approved_weight(&provided, &PartnerOracleConfig::default()),
+            1
+        );
     }
 
     #[test]
@@ -151,7 +180,10 @@ mod tests {
             signers: vec![signer(1, None), signer(2, None), signer(3, Some(4))],
         };
         let provided = [addr(1), addr(4)];
-        assert_eq!(cfg.count_approvals(&provided), 2);
+        assert_eq!(
+            cfg.approved_weight(&provided, &PartnerOracleConfig::default()),
+            2
+        );
     }
 
     #[test]
@@ -160,7 +192,10 @@ mod tests {
             signers: vec![signer(1, None)],
         };
         let provided = [addr(9)];
-        assert_eq!(cfg.count_approvals(&provided), 0);
+        assert_eq!(
+            cfg.approved_weight(&provided, &PartnerOracleConfig::default()),
+            0
+        );
     }
 
     #[test]
@@ -169,6 +204,27 @@ mod tests {
             signers: vec![signer(1, None)],
         };
         let provided = [addr(1), addr(1)];
-        assert_eq!(cfg.count_approvals(&provided), 1);
+        assert_eq!(
+            cfg.approved_weight(&provided, &PartnerOracleConfig::default()),
+            1
+        );
+    }
+
+    #[test]
+    fn weighted_signers_sum_their_weights() {
+        let cfg = Signers {
+            signers: vec![signer(1, None), signer(2, Some(3)), signer(4, None)],
+        };
+        let mut weights = PartnerOracleConfig::default();
+        weights.weights[0] = PartnerSignerWeight {
+            evm_address: addr(2),
+            weight: 5,
+        };
+        weights.weight_count = 1;
+
+        // The rotated address carries the weight of the signer
+        assert_eq!(cfg.approved_weight(&[addr(1), addr(3)], &weights), 6);
+        assert_eq!(cfg.approved_weight(&[addr(4)], &weights), 1);
+        assert_eq!(cfg.total_weight(&weights), 7);
     }
 }
@@ -14,3 +14,43 @@ pub const WRAPPED_TOKEN_SEED: &[u8] = b"wrapped_token";
 pub const MAX_PARTNER_VALIDATOR_THRESHOLD: u8 = 5;
 #[constant]
 pub const MAX_SIGNER_COUNT: u8 = 16;
+#[constant]
+pub const MAX_BASE_ORACLE_SIGNER_COUNT: u8 = 128;
+#[constant]
+pub const MAX_PARTNER_SIGNER_WEIGHT_COUNT: u8 = 20;
+#[constant]
+pub const PENDING_CONFIG_CHANGE_SEED: &[u8] = b"pending_config_change";
+#[constant]
+pub const MAX_CONFIG_CHANGE_DELAY_SECONDS: u64 = 30 * 24 * 60 * 60; // 30 days
+#[constant]
+pub const MIGRATED_CONFIG_CHANGE_DELAY_SECONDS: u64 = 24 * 60 * 60; // 1 day
+#[constant]
+pub const MAX_ROOT_FINALITY_SECONDS: u64 = 7 * 24 * 60 * 60; // 7 days
+#[constant]
+pub const MIN_BOUNCE_DELAY_SECONDS: u64 = 24 * 60 * 60; // 1 day
+#[constant]
+pub const MAX_PAUSED_MINT_COUNT: u8 = 16;
+#[constant]
+pub const MAX_RATE_LIMITED_MINT_COUNT: u8 = 16;
+#[constant]
+pub const MAX_CPI_POLICY_PROGRAM_COUNT: usize = 32;
+
+// Pause flags, one bit per instruction family (see `PauseConfig.flags`)
+#[constant]
+pub const PAUSE_BRIDGE_SOL: u16 = 1 << 0;
+#[constant]
+pub const PAUSE_BRIDGE_SPL: u16 = 1 << 1;
+#[constant]
+pub const PAUSE_BRIDGE_WRAPPED_TOKEN: u16 = 1 << 2;
+#[constant]
+pub const PAUSE_BRIDGE_CALL: u16 = 1 << 3;
+#[constant]
+pub const PAUSE_WRAP_TOKEN: u16 = 1 << 4;
+#[constant]
+pub const PAUSE_REGISTER_OUTPUT_ROOT: u16 = 1 << 5;
+#[constant]
+pub const PAUSE_PROVE_MESSAGE: u16 = 1 << 6;
+#[constant]
+pub const PAUSE_RELAY_MESSAGE: u16 = 1 << 7;
+#[constant]
+pub const PAUSE_ALL: u16 = (1 << 8) - 1;
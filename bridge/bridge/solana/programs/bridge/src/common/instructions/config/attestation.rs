@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{AttestationConfig, SetBridgeConfigFromUpgradeAuthority},
+    ProtocolConfigUpdated,
+};
+
+/// Set the format of the oracle attestations accepted for output roots.
+///
+/// Switching to `LegacyAndEip712` lets the oracle signers migrate to EIP-712 typed data one by one;
+/// switching to `Eip712` then stops accepting the legacy format.
+/// Only the upgrade authority can call this function.
+pub fn set_attestation_config_handler(
+    ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+    cfg: AttestationConfig,
+) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.protocol_config.clone();
+
+    ctx.accounts.bridge.protocol_config.attestation = cfg;
+
+    ctx.accounts.bridge.protocol_config.validate()?;
+
+    emit_cpi!(ProtocolConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.protocol_config.clone(),
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{bpf_loader_upgradeable, instruction::Instruction},
+        InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::{bridge::Bridge, AttestationFormat},
+        instruction::SetAttestationConfig,
+        test_utils::*,
+        ID,
+    };
+
+    fn set_attestation_config_ix(
+        upgrade_authority: &Keypair,
+        bridge_pda: Pubkey,
+        cfg: AttestationConfig,
+    ) -> Instruction {
+        let (program_data_pda, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+
+        Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromUpgradeAuthority {
+                upgrade_authority: upgrade_authority.pubkey(),
+                bridge: bridge_pda,
+                program_data: program_data_pda,
+                program: ID,
+                event_authority: event_authority_pda(),
+            }
+            .to_account_metas(None),
+            data: SetAttestationConfig { cfg }.data(),
+        }
+    }
+
+    #[test]
+    fn test_set_attestation_config_with_upgrade_authority_succeeds() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let cfg = AttestationConfig {
+            format: AttestationFormat::LegacyAndEip712,
+            base_chain_id: 8453,
+            solana_genesis_hash: [1u8; 32],
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[set_attestation_config_ix(&payer, bridge_pda, cfg.clone())],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Transaction should succeed with upgrade authority");
+
+        let event = find_cpi_event::<ProtocolConfigUpdated>(&meta)
+            .expect("ProtocolConfigUpdated event should be emitted");
+        assert_eq!(event.after.attestation, cfg);
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.protocol_config.attestation, cfg);
+    }
+
+    #[test]
+    fn test_set_eip712_attestation_without_domain_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let cfg = AttestationConfig {
+            format: AttestationFormat::Eip712,
+            base_chain_id: 8453,
+            solana_genesis_hash: [0u8; 32],
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[set_attestation_config_ix(&payer, bridge_pda, cfg)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("InvalidAttestationDomain"),
+            "Expected InvalidAttestationDomain error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_set_attestation_config_with_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(
+                &[set_attestation_config_ix(
+                    &guardian,
+                    bridge_pda,
+                    AttestationConfig::default(),
+                )],
+                Some(&guardian.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+}
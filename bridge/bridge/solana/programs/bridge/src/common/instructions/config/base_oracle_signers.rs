@@ -1,141 +1,412 @@
 use anchor_lang::prelude::*;
 
-use crate::common::{BaseOracleConfig, SetBridgeConfigFromUpgradeAuthority};
+use crate::{
+    base_to_solana::{
+        constants::BASE_ORACLE_SIGNERS_SEED,
+        state::{BaseOracleSigner, BaseOracleSigners, OracleSignerKey},
+    },
+    common::{bridge::Bridge, SetBridgeConfigFromSignerManager, BRIDGE_SEED},
+    BaseOracleEpochStarted, BaseOracleSignersUpdated, BridgeError,
+};
+
+/// Accounts struct for rotating the Base oracle signer set into a new epoch
+/// Only the signer manager can start an epoch; it funds the new epoch account
+#[event_cpi]
+#[derive(Accounts)]
+pub struct StartBaseOracleEpoch<'info> {
+    /// The bridge account holding the signer manager role and the latest registered block number
+    #[account(
+        constraint = bridge.roles.signer_manager == signer_manager.key()
+            @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The latest Base oracle signer epoch, superseded by the new one
+    #[account(
+        mut,
+        constraint = current_base_oracle_signers.is_latest()
+            @ BridgeError::BaseOracleEpochSuperseded,
+        seeds = [BASE_ORACLE_SIGNERS_SEED, &current_base_oracle_signers.epoch.to_le_bytes()],
+        bump
+    )]
+    pub current_base_oracle_signers: Account<'info, BaseOracleSigners>,
+
+    /// The new Base oracle signer epoch, starting with a copy of the current signer set
+    #[account(
+        init,
+        payer = signer_manager,
+        space = BaseOracleSigners::space(current_base_oracle_signers.signers.len()),
+        seeds = [
+            BASE_ORACLE_SIGNERS_SEED,
+            &(current_base_oracle_signers.epoch + 1).to_le_bytes()
+        ],
+        bump
+    )]
+    pub next_base_oracle_signers: Account<'info, BaseOracleSigners>,
+
+    /// The signer manager account authorized to update the Base oracle signer set
+    #[account(mut)]
+    pub signer_manager: Signer<'info>,
+
+    /// System program required for creating the new epoch account
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for adding a signer to the Base oracle signer registry
+/// Only the signer manager can update the signer set; it funds the larger registry account
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddBaseOracleSigner<'info> {
+    /// The bridge account holding the signer manager role
+    #[account(
+        constraint = bridge.roles.signer_manager == signer_manager.key()
+            @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The latest Base oracle signer epoch, grown by one signer
+    #[account(
+        mut,
+        constraint = base_oracle_signers.is_latest() @ BridgeError::BaseOracleEpochSuperseded,
+        seeds = [BASE_ORACLE_SIGNERS_SEED, &base_oracle_signers.epoch.to_le_bytes()],
+        bump,
+        realloc = BaseOracleSigners::space(base_oracle_signers.signers.len() + 1),
+        realloc::payer = signer_manager,
+        realloc::zero = false
+    )]
+    pub base_oracle_signers: Account<'info, BaseOracleSigners>,
+
+    /// The signer manager account authorized to update the Base oracle signer set
+    #[account(mut)]
+    pub signer_manager: Signer<'info>,
+
+    /// System program required for reallocating the registry account
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for removing a signer from the Base oracle signer registry
+/// Only the signer manager can update the signer set; it receives the freed rent
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveBaseOracleSigner<'info> {
+    /// The bridge account holding the signer manager role
+    #[account(
+        constraint = bridge.roles.signer_manager == signer_manager.key()
+            @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The latest Base oracle signer epoch, shrunk by one signer
+    #[account(
+        mut,
+        constraint = base_oracle_signers.is_latest() @ BridgeError::BaseOracleEpochSuperseded,
+        seeds = [BASE_ORACLE_SIGNERS_SEED, &base_oracle_signers.epoch.to_le_bytes()],
+        bump,
+        realloc = BaseOracleSigners::space(base_oracle_signers.signers.len().saturating_sub(1)),
+        realloc::payer = signer_manager,
+        realloc::zero = false
+    )]
+    pub base_oracle_signers: Account<'info, BaseOracleSigners>,
+
+    /// The signer manager account authorized to update the Base oracle signer set
+    #[account(mut)]
+    pub signer_manager: Signer<'info>,
+
+    /// System program required for reallocating the registry account
+    pub system_program: Program<'info, System>,
+}
+
+/// Start a new Base oracle signer epoch covering roots from `activation_block_number` onwards.
+///
+/// The new epoch starts as a copy of the current signer set, so starting it does not change who
+/// can attest; the rotation itself is then staged on the new epoch with the add, remove and
+/// replace instructions before it activates. The current epoch stays accepted for
+/// `overlap_blocks` past the activation so roots signed just before the rotation can still be
+/// registered. Only the signer manager can call this function.
+pub fn start_base_oracle_epoch_handler(
+    ctx: Context<StartBaseOracleEpoch>,
+    activation_block_number: u64,
+    overlap_blocks: u64,
+) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let current = &mut ctx.accounts.current_base_oracle_signers;
+    let next = current.start_next_epoch(
+        ctx.accounts.bridge.base_block_number,
+        activation_block_number,
+        overlap_blocks,
+    )?;
+    let event = BaseOracleEpochStarted {
+        epoch: next.epoch,
+        activation_block_number,
+        previous_epoch_expiry_block_number: current.expiry_block_number,
+    };
+    *ctx.accounts.next_base_oracle_signers = next;
+
+    emit_cpi!(event);
+
+    Ok(())
+}
+
+/// Add a signer to the Base oracle signer registry.
+///
+/// The registry account is grown to fit the new signer. The resulting signer set must stay
+/// valid (unique addresses, non-zero weights). Only the signer manager can call this function.
+pub fn add_base_oracle_signer_handler(
+    ctx: Context<AddBaseOracleSigner>,
+    signer: BaseOracleSigner,
+) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let event = update_base_oracle_signers(&mut ctx.accounts.base_oracle_signers, |signers| {
+        signers.add(signer)
+    })?;
+    emit_cpi!(event);
+
+    Ok(())
+}
+
+/// Remove the signer attesting with `key` from the Base oracle signer registry.
+///
+/// The registry account is shrunk and the freed rent returned to the signer manager. Fails if
+/// the remaining signers can no longer reach the threshold; lower it first in that case.
+/// Only the signer manager can call this function.
+pub fn remove_base_oracle_signer_handler(
+    ctx: Context<RemoveBaseOracleSigner>,
+    key: OracleSignerKey,
+) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let event = update_base_oracle_signers(&mut ctx.accounts.base_oracle_signers, |signers| {
+        signers.remove(&key)
+    })?;
+    emit_cpi!(event);
+
+    Ok(())
+}
 
-/// Set or update the oracle signer configuration.
+/// Replace the signer attesting with `key` by `signer`.
 ///
-/// Updates the `oracle_signers` account with a new approval `threshold` and a
-/// new list of unique EVM signer addresses. This instruction is used to rotate
-/// oracle keys or adjust the required threshold for output root attestations.
-pub fn set_oracle_signers_handler(
-    ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
-    cfg: BaseOracleConfig,
+/// Used to rotate a single oracle key (or change its weight) without resizing the registry.
+/// Only the signer manager can call this function.
+pub fn replace_base_oracle_signer_handler(
+    ctx: Context<SetBridgeConfigFromSignerManager>,
+    key: OracleSignerKey,
+    signer: BaseOracleSigner,
 ) -> Result<()> {
-    cfg.validate()?;
-    ctx.accounts.bridge.base_oracle_config = cfg;
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let event = update_base_oracle_signers(&mut ctx.accounts.base_oracle_signers, |signers| {
+        signers.replace(&key, signer)
+    })?;
+    emit_cpi!(event);
+
     Ok(())
 }
 
+/// Set the total signer weight required to register an output root.
+/// Only the signer manager can call this function.
+pub fn set_base_oracle_threshold_handler(
+    ctx: Context<SetBridgeConfigFromSignerManager>,
+    threshold: u16,
+) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let event = update_base_oracle_signers(&mut ctx.accounts.base_oracle_signers, |signers| {
+        signers.threshold = threshold;
+        Ok(())
+    })?;
+    emit_cpi!(event);
+
+    Ok(())
+}
+
+fn update_base_oracle_signers(
+    signers: &mut BaseOracleSigners,
+    update: impl FnOnce(&mut BaseOracleSigners) -> Result<()>,
+) -> Result<BaseOracleSignersUpdated> {
+    let before = signers.clone();
+    update(signers)?;
+    signers.validate()?;
+
+    Ok(BaseOracleSignersUpdated {
+        before,
+        after: signers.clone(),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use anchor_lang::{
-        solana_program::{bpf_loader_upgradeable, instruction::Instruction},
+        solana_program::{instruction::Instruction, system_program},
         InstructionData,
     };
-    use solana_message::Message;
     use solana_signer::Signer;
-    use solana_transaction::Transaction;
 
     use crate::{
-        accounts, common::bridge::Bridge, instruction::SetOracleSigners, test_utils::*, ID,
-        MAX_SIGNER_COUNT,
+        accounts,
+        instruction::{
+            AddBaseOracleSigner as AddBaseOracleSignerIx,
+            RemoveBaseOracleSigner as RemoveBaseOracleSignerIx,
+            ReplaceBaseOracleSigner as ReplaceBaseOracleSignerIx,
+            SetBaseOracleThreshold as SetBaseOracleThresholdIx,
+            StartBaseOracleEpoch as StartBaseOracleEpochIx,
+        },
+        test_utils::*,
+        ID,
     };
 
-    /// Helper to create a BaseOracleConfig for testing
-    fn base_oracle_config(threshold: u8, signer_count: u8) -> BaseOracleConfig {
-        let mut signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        for i in 0..signer_count {
-            signers[i as usize] = [(i + 1); 20];
+    fn signer(byte: u8, weight: u8) -> BaseOracleSigner {
+        BaseOracleSigner {
+            key: OracleSignerKey::Evm([byte; 20]),
+            weight,
         }
-        BaseOracleConfig {
-            threshold,
-            signer_count,
-            signers,
+    }
+
+    fn add_ix(bridge_pda: Pubkey, signer_manager: Pubkey, signer: BaseOracleSigner) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::AddBaseOracleSigner {
+                bridge: bridge_pda,
+                base_oracle_signers: base_oracle_signers_pda(0),
+                signer_manager,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: AddBaseOracleSignerIx { signer }.data(),
         }
     }
 
-    #[test]
-    fn test_set_oracle_signers_with_upgrade_authority_succeeds() {
-        let SetupBridgeResult {
-            mut svm,
-            payer,
-            bridge_pda,
-            ..
-        } = setup_bridge();
+    fn remove_ix(bridge_pda: Pubkey, signer_manager: Pubkey, key: OracleSignerKey) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::RemoveBaseOracleSigner {
+                bridge: bridge_pda,
+                base_oracle_signers: base_oracle_signers_pda(0),
+                signer_manager,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: RemoveBaseOracleSignerIx { key }.data(),
+        }
+    }
 
-        // Find the ProgramData account created by deploy_upgradeable_program
-        let (program_data_pda, _) =
-            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+    fn start_epoch_ix(
+        bridge_pda: Pubkey,
+        signer_manager: Pubkey,
+        epoch: u64,
+        activation_block_number: u64,
+        overlap_blocks: u64,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::StartBaseOracleEpoch {
+                bridge: bridge_pda,
+                current_base_oracle_signers: base_oracle_signers_pda(epoch),
+                next_base_oracle_signers: base_oracle_signers_pda(epoch + 1),
+                signer_manager,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: StartBaseOracleEpochIx {
+                activation_block_number,
+                overlap_blocks,
+            }
+            .data(),
+        }
+    }
 
-        // Build accounts - payer IS the upgrade authority
-        let accounts = accounts::SetBridgeConfigFromUpgradeAuthority {
-            upgrade_authority: payer.pubkey(),
+    fn signer_manager_accounts(bridge_pda: Pubkey, signer_manager: Pubkey) -> Vec<AccountMeta> {
+        accounts::SetBridgeConfigFromSignerManager {
             bridge: bridge_pda,
-            program_data: program_data_pda,
+            base_oracle_signers: base_oracle_signers_pda(0),
+            signer_manager,
+            event_authority: event_authority_pda(),
             program: ID,
         }
-        .to_account_metas(None);
-
-        // New valid config with 2 signers and threshold 2
-        let new_config = base_oracle_config(2, 2);
-
-        let ix = Instruction {
-            program_id: ID,
-            accounts,
-            data: SetOracleSigners { cfg: new_config }.data(),
-        };
-
-        let tx = Transaction::new(
-            &[&payer],
-            Message::new(&[ix], Some(&payer.pubkey())),
-            svm.latest_blockhash(),
-        );
+        .to_account_metas(None)
+    }
 
-        // Should succeed
-        svm.send_transaction(tx)
-            .expect("Transaction should succeed with upgrade authority");
+    fn base_oracle_signers(svm: &litesvm::LiteSVM) -> (usize, BaseOracleSigners) {
+        base_oracle_epoch(svm, 0)
+    }
 
-        // Verify the config was updated
-        let bridge_account = svm.get_account(&bridge_pda).unwrap();
-        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
-        assert_eq!(bridge.base_oracle_config.threshold, 2);
-        assert_eq!(bridge.base_oracle_config.signer_count, 2);
+    fn base_oracle_epoch(svm: &litesvm::LiteSVM, epoch: u64) -> (usize, BaseOracleSigners) {
+        let account = svm.get_account(&base_oracle_signers_pda(epoch)).unwrap();
+        (
+            account.data.len(),
+            BaseOracleSigners::try_deserialize(&mut &account.data[..]).unwrap(),
+        )
     }
 
     #[test]
-    fn test_set_oracle_signers_with_guardian_fails() {
+    fn test_add_base_oracle_signer_grows_registry() {
         let SetupBridgeResult {
             mut svm,
-            guardian,
+            payer,
             bridge_pda,
             ..
         } = setup_bridge();
 
-        let (program_data_pda, _) =
-            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
-
-        // Build accounts - trying with GUARDIAN instead of upgrade authority
-        let accounts = accounts::SetBridgeConfigFromUpgradeAuthority {
-            upgrade_authority: guardian.pubkey(),
-            bridge: bridge_pda,
-            program_data: program_data_pda,
-            program: ID,
-        }
-        .to_account_metas(None);
+        let meta = send(
+            &mut svm,
+            &payer,
+            add_ix(bridge_pda, payer.pubkey(), signer(2, 3)),
+        )
+        .expect("Transaction should succeed with signer manager");
 
-        let new_config = base_oracle_config(2, 2);
+        let (len, registry) = base_oracle_signers(&svm);
+        assert_eq!(len, BaseOracleSigners::space(2));
+        assert_eq!(registry.signers, vec![signer(1, 1), signer(2, 3)]);
 
-        let ix = Instruction {
-            program_id: ID,
-            accounts,
-            data: SetOracleSigners { cfg: new_config }.data(),
-        };
+        let event = find_cpi_event::<BaseOracleSignersUpdated>(&meta).unwrap();
+        assert_eq!(event.before, BaseOracleSigners::test_new());
+        assert_eq!(event.after, registry);
+    }
 
-        let tx = Transaction::new(
-            &[&guardian],
-            Message::new(&[ix], Some(&guardian.pubkey())),
-            svm.latest_blockhash(),
-        );
+    #[test]
+    fn test_add_base_oracle_signer_with_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
 
-        // Should fail with UnauthorizedConfigUpdate
-        let result = svm.send_transaction(tx);
-        assert!(
-            result.is_err(),
-            "Expected transaction to fail when guardian tries to update config"
+        let result = send(
+            &mut svm,
+            &guardian,
+            add_ix(bridge_pda, guardian.pubkey(), signer(2, 1)),
         );
 
-        // Verify the specific error
         let error_string = format!("{:?}", result.unwrap_err());
         assert!(
             error_string.contains("UnauthorizedConfigUpdate"),
@@ -145,7 +416,7 @@ mod tests {
     }
 
     #[test]
-    fn test_set_oracle_signers_invalid_threshold_fails() {
+    fn test_add_duplicate_base_oracle_signer_fails() {
         let SetupBridgeResult {
             mut svm,
             payer,
@@ -153,40 +424,62 @@ mod tests {
             ..
         } = setup_bridge();
 
-        let (program_data_pda, _) =
-            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+        let result = send(
+            &mut svm,
+            &payer,
+            add_ix(bridge_pda, payer.pubkey(), signer(1, 2)),
+        );
 
-        let accounts = accounts::SetBridgeConfigFromUpgradeAuthority {
-            upgrade_authority: payer.pubkey(),
-            bridge: bridge_pda,
-            program_data: program_data_pda,
-            program: ID,
-        }
-        .to_account_metas(None);
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("DuplicateSigner"),
+            "Expected DuplicateSigner error, got: {}",
+            error_string
+        );
+    }
 
-        // Invalid config - threshold = 0
-        let new_config = base_oracle_config(0, 2);
+    #[test]
+    fn test_remove_base_oracle_signer_shrinks_registry() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
 
-        let ix = Instruction {
-            program_id: ID,
-            accounts,
-            data: SetOracleSigners { cfg: new_config }.data(),
-        };
+        send(
+            &mut svm,
+            &payer,
+            add_ix(bridge_pda, payer.pubkey(), signer(2, 1)),
+        )
+        .unwrap();
+        send(
+            &mut svm,
+            &payer,
+            remove_ix(bridge_pda, payer.pubkey(), OracleSignerKey::Evm([1u8; 20])),
+        )
+        .expect("Transaction should succeed with signer manager");
+
+        let (len, registry) = base_oracle_signers(&svm);
+        assert_eq!(len, BaseOracleSigners::space(1));
+        assert_eq!(registry.signers, vec![signer(2, 1)]);
+    }
 
-        let tx = Transaction::new(
-            &[&payer],
-            Message::new(&[ix], Some(&payer.pubkey())),
-            svm.latest_blockhash(),
-        );
+    #[test]
+    fn test_remove_base_oracle_signer_below_threshold_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
 
-        // Should fail with InvalidThreshold
-        let result = svm.send_transaction(tx);
-        assert!(
-            result.is_err(),
-            "Expected transaction to fail with invalid threshold"
+        let result = send(
+            &mut svm,
+            &payer,
+            remove_ix(bridge_pda, payer.pubkey(), OracleSignerKey::Evm([1u8; 20])),
         );
 
-        // Verify the specific error
         let error_string = format!("{:?}", result.unwrap_err());
         assert!(
             error_string.contains("InvalidThreshold"),
@@ -196,7 +489,7 @@ mod tests {
     }
 
     #[test]
-    fn test_set_oracle_signers_duplicate_signer_fails() {
+    fn test_replace_base_oracle_signer() {
         let SetupBridgeResult {
             mut svm,
             payer,
@@ -204,52 +497,142 @@ mod tests {
             ..
         } = setup_bridge();
 
-        let (program_data_pda, _) =
-            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
-
-        let accounts = accounts::SetBridgeConfigFromUpgradeAuthority {
-            upgrade_authority: payer.pubkey(),
-            bridge: bridge_pda,
-            program_data: program_data_pda,
-            program: ID,
-        }
-        .to_account_metas(None);
+        let ix = Instruction {
+            program_id: ID,
+            accounts: signer_manager_accounts(bridge_pda, payer.pubkey()),
+            data: ReplaceBaseOracleSignerIx {
+                key: OracleSignerKey::Evm([1u8; 20]),
+                signer: signer(3, 2),
+            }
+            .data(),
+        };
+        send(&mut svm, &payer, ix).expect("Transaction should succeed with signer manager");
 
-        // Invalid config - duplicate signers
-        let mut signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        signers[0] = [1u8; 20];
-        signers[1] = [1u8; 20]; // Same as signers[0]
+        let (len, registry) = base_oracle_signers(&svm);
+        assert_eq!(len, BaseOracleSigners::space(1));
+        assert_eq!(registry.signers, vec![signer(3, 2)]);
 
-        let new_config = BaseOracleConfig {
-            threshold: 2,
-            signer_count: 2,
-            signers,
+        // The replaced signer is no longer in the registry
+        let ix = Instruction {
+            program_id: ID,
+            accounts: signer_manager_accounts(bridge_pda, payer.pubkey()),
+            data: ReplaceBaseOracleSignerIx {
+                key: OracleSignerKey::Evm([1u8; 20]),
+                signer: signer(4, 1),
+            }
+            .data(),
         };
+        let error_string = format!("{:?}", send(&mut svm, &payer, ix).unwrap_err());
+        assert!(
+            error_string.contains("SignerNotFound"),
+            "Expected SignerNotFound error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_set_base_oracle_threshold_invalid_threshold_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
 
         let ix = Instruction {
             program_id: ID,
-            accounts,
-            data: SetOracleSigners { cfg: new_config }.data(),
+            accounts: signer_manager_accounts(bridge_pda, payer.pubkey()),
+            data: SetBaseOracleThresholdIx { threshold: 2 }.data(),
         };
+        let result = send(&mut svm, &payer, ix);
 
-        let tx = Transaction::new(
-            &[&payer],
-            Message::new(&[ix], Some(&payer.pubkey())),
-            svm.latest_blockhash(),
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("InvalidThreshold"),
+            "Expected InvalidThreshold error, got: {}",
+            error_string
         );
+    }
 
-        // Should fail with DuplicateSigner
-        let result = svm.send_transaction(tx);
+    #[test]
+    fn test_start_base_oracle_epoch() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let meta = send(
+            &mut svm,
+            &payer,
+            start_epoch_ix(bridge_pda, payer.pubkey(), 0, 600, 300),
+        )
+        .expect("Transaction should succeed with signer manager");
+
+        let event = find_cpi_event::<BaseOracleEpochStarted>(&meta).unwrap();
+        assert_eq!(event.epoch, 1);
+        assert_eq!(event.activation_block_number, 600);
+        assert_eq!(event.previous_epoch_expiry_block_number, 900);
+
+        let (_, previous) = base_oracle_epoch(&svm, 0);
+        assert_eq!(previous.expiry_block_number, 900);
+        let (_, next) = base_oracle_epoch(&svm, 1);
+        assert_eq!(
+            next,
+            BaseOracleSigners {
+                epoch: 1,
+                activation_block_number: 600,
+                ..BaseOracleSigners::test_new()
+            }
+        );
+
+        // The superseded epoch can no longer be updated; the new one can
+        let result = send(
+            &mut svm,
+            &payer,
+            add_ix(bridge_pda, payer.pubkey(), signer(2, 1)),
+        );
+        let error_string = format!("{:?}", result.unwrap_err());
         assert!(
-            result.is_err(),
-            "Expected transaction to fail with duplicate signers"
+            error_string.contains("BaseOracleEpochSuperseded"),
+            "Expected BaseOracleEpochSuperseded error, got: {}",
+            error_string
         );
 
-        // Verify the specific error
+        let mut ix = add_ix(bridge_pda, payer.pubkey(), signer(2, 1));
+        ix.accounts[1].pubkey = base_oracle_signers_pda(1);
+        send(&mut svm, &payer, ix).expect("Latest epoch should be updatable");
+        let (_, next) = base_oracle_epoch(&svm, 1);
+        assert_eq!(next.signers, vec![signer(1, 1), signer(2, 1)]);
+    }
+
+    #[test]
+    fn test_start_base_oracle_epoch_requires_later_activation() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        send(
+            &mut svm,
+            &payer,
+            start_epoch_ix(bridge_pda, payer.pubkey(), 0, 600, 0),
+        )
+        .unwrap();
+
+        // Activation must come after the current epoch's
+        let result = send(
+            &mut svm,
+            &payer,
+            start_epoch_ix(bridge_pda, payer.pubkey(), 1, 600, 0),
+        );
         let error_string = format!("{:?}", result.unwrap_err());
         assert!(
-            error_string.contains("DuplicateSigner"),
-            "Expected DuplicateSigner error, got: {}",
+            error_string.contains("InvalidEpochActivation"),
+            "Expected InvalidEpochActivation error, got: {}",
             error_string
         );
     }
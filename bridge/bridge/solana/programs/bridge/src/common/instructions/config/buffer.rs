@@ -1,14 +1,26 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::{common::SetBridgeConfigFromGuardian, BufferConfigUpdated};
 
 /// Set the maximum call buffer size
 pub fn set_max_call_buffer_size_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_size: u64,
 ) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.buffer_config.clone();
+
     ctx.accounts.bridge.buffer_config.max_call_buffer_size = new_size;
 
+    emit_cpi!(BufferConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.buffer_config.clone(),
+    });
+
     Ok(())
 }
 
@@ -25,7 +37,7 @@ mod tests {
         accounts,
         common::bridge::Bridge,
         instruction::SetMaxCallBufferSize as SetMaxCallBufferSizeIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        test_utils::{event_authority_pda, setup_bridge, SetupBridgeResult},
         ID,
     };
 
@@ -45,6 +57,8 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: guardian.pubkey(),
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -95,6 +109,8 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: fake_guardian.pubkey(), // Wrong guardian
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
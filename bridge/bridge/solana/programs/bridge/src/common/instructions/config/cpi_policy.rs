@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{constants::CPI_POLICY_SEED, CpiPolicy, CpiPolicyConfig},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    BridgeError, CpiPolicyUpdated,
+};
+
+/// Accounts struct for configuring the programs relayed messages can call
+/// Only the guardian can create or update the CPI policy
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCpiPolicy<'info> {
+    /// The guardian account authorized to update configuration; pays for the policy account
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The CPI policy account, created on first configuration
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        space = DISCRIMINATOR_LEN + CpiPolicy::INIT_SPACE,
+        seeds = [CPI_POLICY_SEED],
+        bump
+    )]
+    pub cpi_policy: Account<'info, CpiPolicy>,
+
+    /// System program required for creating the policy account
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the CPI policy applied to relayed messages
+/// A newly created account holds the default policy (an empty denylist) as its previous value
+pub fn set_cpi_policy_handler(ctx: Context<SetCpiPolicy>, cfg: CpiPolicyConfig) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+    cfg.validate()?;
+
+    let before = std::mem::replace(&mut ctx.accounts.cpi_policy.config, cfg.clone());
+
+    emit_cpi!(CpiPolicyUpdated { before, after: cfg });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts, base_to_solana::CpiPolicyMode, common::MAX_CPI_POLICY_PROGRAM_COUNT,
+        instruction::SetCpiPolicy as SetCpiPolicyIx, test_utils::*, ID,
+    };
+
+    fn send_set_cpi_policy(
+        svm: &mut litesvm::LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        cfg: CpiPolicyConfig,
+    ) -> std::result::Result<
+        litesvm::types::TransactionMetadata,
+        Box<litesvm::types::FailedTransactionMetadata>,
+    > {
+        let accounts = accounts::SetCpiPolicy {
+            guardian: guardian.pubkey(),
+            bridge: bridge_pda,
+            cpi_policy: cpi_policy_pda(),
+            system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: SetCpiPolicyIx { cfg }.data(),
+        };
+
+        svm.expire_blockhash();
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).map_err(Box::new)
+    }
+
+    #[test]
+    fn test_set_cpi_policy_success() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let cfg = CpiPolicyConfig {
+            mode: CpiPolicyMode::Allowlist,
+            programs: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        };
+        let meta = send_set_cpi_policy(&mut svm, &guardian, bridge_pda, cfg.clone())
+            .expect("Failed to create CPI policy");
+
+        let event = find_cpi_event::<CpiPolicyUpdated>(&meta)
+            .expect("CpiPolicyUpdated event should be emitted");
+        assert_eq!(event.before, CpiPolicyConfig::default());
+        assert_eq!(event.after, cfg);
+
+        // Switching to a denylist replaces the whole policy
+        let new_cfg = CpiPolicyConfig {
+            mode: CpiPolicyMode::Denylist,
+            programs: vec![Pubkey::new_unique()],
+        };
+        let meta = send_set_cpi_policy(&mut svm, &guardian, bridge_pda, new_cfg.clone())
+            .expect("Failed to update CPI policy");
+
+        let event = find_cpi_event::<CpiPolicyUpdated>(&meta)
+            .expect("CpiPolicyUpdated event should be emitted");
+        assert_eq!(event.before, cfg);
+        assert_eq!(event.after, new_cfg);
+
+        let account = svm.get_account(&cpi_policy_pda()).unwrap();
+        let policy = CpiPolicy::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(policy.config, new_cfg);
+    }
+
+    #[test]
+    fn test_set_cpi_policy_unauthorized() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), 1_000_000_000).unwrap();
+
+        assert_error(
+            send_set_cpi_policy(
+                &mut svm,
+                &fake_guardian,
+                bridge_pda,
+                CpiPolicyConfig::default(),
+            ),
+            "UnauthorizedConfigUpdate",
+        );
+    }
+
+    #[test]
+    fn test_set_cpi_policy_invalid_programs_fail() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let program = Pubkey::new_unique();
+        assert_error(
+            send_set_cpi_policy(
+                &mut svm,
+                &guardian,
+                bridge_pda,
+                CpiPolicyConfig {
+                    mode: CpiPolicyMode::Denylist,
+                    programs: vec![program, program],
+                },
+            ),
+            "DuplicateCpiPolicyProgram",
+        );
+
+        assert_error(
+            send_set_cpi_policy(
+                &mut svm,
+                &guardian,
+                bridge_pda,
+                CpiPolicyConfig {
+                    mode: CpiPolicyMode::Allowlist,
+                    programs: (0..=MAX_CPI_POLICY_PROGRAM_COUNT)
+                        .map(|_| Pubkey::new_unique())
+                        .collect(),
+                },
+            ),
+            "TooManyCpiPolicyPrograms",
+        );
+    }
+}
@@ -1,13 +1,26 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::{common::SetBridgeConfigFromGuardian, Eip1559ConfigUpdated};
 
 /// Set the minimum base fee parameter
 pub fn set_minimum_base_fee_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_fee: u64,
 ) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.eip1559.config.clone();
+
     ctx.accounts.bridge.eip1559.config.minimum_base_fee = new_fee;
+
+    emit_cpi!(Eip1559ConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.eip1559.config.clone(),
+    });
+
     Ok(())
 }
 
@@ -16,8 +29,21 @@ pub fn set_window_duration_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_duration: u64,
 ) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.eip1559.config.clone();
+
     ctx.accounts.bridge.eip1559.config.window_duration_seconds = new_duration;
     ctx.accounts.bridge.eip1559.config.validate()?;
+
+    emit_cpi!(Eip1559ConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.eip1559.config.clone(),
+    });
+
     Ok(())
 }
 
@@ -26,7 +52,20 @@ pub fn set_gas_target_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_target: u64,
 ) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.eip1559.config.clone();
+
     ctx.accounts.bridge.eip1559.config.target = new_target;
+
+    emit_cpi!(Eip1559ConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.eip1559.config.clone(),
+    });
+
     Ok(())
 }
 
@@ -35,7 +74,20 @@ pub fn set_adjustment_denominator_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_denominator: u64,
 ) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.eip1559.config.clone();
+
     ctx.accounts.bridge.eip1559.config.denominator = new_denominator;
     ctx.accounts.bridge.eip1559.config.validate()?;
+
+    emit_cpi!(Eip1559ConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.eip1559.config.clone(),
+    });
+
     Ok(())
 }
@@ -1,13 +1,26 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::{common::SetBridgeConfigFromGuardian, GasConfigUpdated};
 
 /// Set the gas cost scaler
 pub fn set_gas_cost_scaler_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_scaler: u64,
 ) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.gas_config.clone();
+
     ctx.accounts.bridge.gas_config.gas_cost_scaler = new_scaler;
+
+    emit_cpi!(GasConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.gas_config.clone(),
+    });
+
     Ok(())
 }
 
@@ -16,8 +29,21 @@ pub fn set_gas_cost_scaler_dp_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_dp: u64,
 ) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.gas_config.clone();
+
     ctx.accounts.bridge.gas_config.gas_cost_scaler_dp = new_dp;
     ctx.accounts.bridge.gas_config.validate()?;
+
+    emit_cpi!(GasConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.gas_config.clone(),
+    });
+
     Ok(())
 }
 
@@ -26,7 +52,20 @@ pub fn set_gas_fee_receiver_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_receiver: Pubkey,
 ) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.gas_config.clone();
+
     ctx.accounts.bridge.gas_config.gas_fee_receiver = new_receiver;
+
+    emit_cpi!(GasConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.gas_config.clone(),
+    });
+
     Ok(())
 }
 
@@ -35,6 +74,19 @@ pub fn set_gas_per_call_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_val: u64,
 ) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.gas_config.clone();
+
     ctx.accounts.bridge.gas_config.gas_per_call = new_val;
+
+    emit_cpi!(GasConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.gas_config.clone(),
+    });
+
     Ok(())
 }
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    base_to_solana::{constants::BASE_ORACLE_SIGNERS_SEED, state::BaseOracleSigners},
     common::{bridge::Bridge, BRIDGE_SEED},
     program::Bridge as BridgeProgram,
     BridgeError,
@@ -27,8 +28,24 @@ pub use base_oracle_signers::*;
 pub mod partner_config;
 pub use partner_config::*;
 
+pub mod rate_limit;
+pub use rate_limit::*;
+
+pub mod cpi_policy;
+pub use cpi_policy::*;
+
+pub mod timelock;
+pub use timelock::*;
+
+pub mod roles;
+pub use roles::*;
+
+pub mod attestation;
+pub use attestation::*;
+
 /// Accounts struct for non-sensitive bridge configuration setter instructions
 /// Only the guardian can update these parameters
+#[event_cpi]
 #[derive(Accounts)]
 pub struct SetBridgeConfigFromGuardian<'info> {
     /// The bridge account containing configuration
@@ -44,6 +61,52 @@ pub struct SetBridgeConfigFromGuardian<'info> {
     pub guardian: Signer<'info>,
 }
 
+/// Accounts struct for pause setter instructions
+/// The pauser and the guardian can pause; only the guardian can lift a pause
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetBridgeConfigFromPauser<'info> {
+    /// The bridge account containing the pause state
+    #[account(
+        mut,
+        constraint = authority.key() == bridge.roles.pauser || authority.key() == bridge.guardian
+            @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The pauser or guardian account
+    pub authority: Signer<'info>,
+}
+
+/// Accounts struct for Base oracle signer set updates that keep the registry size
+/// Only the signer manager can update the signer set
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetBridgeConfigFromSignerManager<'info> {
+    /// The bridge account holding the signer manager role
+    #[account(
+        constraint = bridge.roles.signer_manager == signer_manager.key()
+            @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The latest Base oracle signer epoch
+    #[account(
+        mut,
+        constraint = base_oracle_signers.is_latest() @ BridgeError::BaseOracleEpochSuperseded,
+        seeds = [BASE_ORACLE_SIGNERS_SEED, &base_oracle_signers.epoch.to_le_bytes()],
+        bump
+    )]
+    pub base_oracle_signers: Account<'info, BaseOracleSigners>,
+
+    /// The signer manager account authorized to update the Base oracle signer set
+    pub signer_manager: Signer<'info>,
+}
+
 /// Accounts struct for sensitive bridge configuration setter instructions
 /// Only the upgrade authority can update these parameters
 #[derive(Accounts)]
@@ -60,4 +123,10 @@ pub struct SetBridgeConfigFromUpgradeAuthority<'info> {
 
     #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ BridgeError::IncorrectBridgeProgram)]
     pub program: Program<'info, BridgeProgram>,
+
+    /// Event authority used to sign the self-CPI that logs config change events.
+    /// Declared manually (rather than via `#[event_cpi]`) as `program` is already part of this struct.
+    /// CHECK: Only the event authority PDA can sign the self-CPI.
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: AccountInfo<'info>,
 }
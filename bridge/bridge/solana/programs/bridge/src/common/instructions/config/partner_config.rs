@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::common::{PartnerOracleConfig, SetBridgeConfigFromUpgradeAuthority};
+use crate::{
+    common::{PartnerOracleConfig, SetBridgeConfigFromUpgradeAuthority},
+    PartnerOracleConfigUpdated,
+};
 
 /// Set or update the oracle signer configuration.
 ///
@@ -11,8 +14,21 @@ pub fn set_partner_config_handler(
     ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
     partner_cfg: PartnerOracleConfig,
 ) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
     partner_cfg.validate()?;
-    ctx.accounts.bridge.partner_oracle_config = partner_cfg;
+
+    let before = ctx.accounts.bridge.partner_oracle_config.clone();
+    ctx.accounts.bridge.partner_oracle_config = partner_cfg.clone();
+
+    emit_cpi!(PartnerOracleConfigUpdated {
+        before,
+        after: partner_cfg,
+    });
+
     Ok(())
 }
 
@@ -50,12 +66,14 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            event_authority: event_authority_pda(),
         }
         .to_account_metas(None);
 
         // New valid config
         let new_config = PartnerOracleConfig {
             required_threshold: 3,
+            ..Default::default()
         };
 
         let ix = Instruction {
@@ -98,11 +116,13 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            event_authority: event_authority_pda(),
         }
         .to_account_metas(None);
 
         let new_config = PartnerOracleConfig {
             required_threshold: 3,
+            ..Default::default()
         };
 
         let ix = Instruction {
@@ -150,12 +170,14 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            event_authority: event_authority_pda(),
         }
         .to_account_metas(None);
 
         // Invalid config - threshold too high (> MAX_PARTNER_VALIDATOR_THRESHOLD = 5)
         let new_config = PartnerOracleConfig {
             required_threshold: 6,
+            ..Default::default()
         };
 
         let ix = Instruction {
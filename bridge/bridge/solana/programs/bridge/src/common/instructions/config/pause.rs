@@ -1,14 +1,64 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::{
+    common::{SetBridgeConfigFromPauser, PAUSE_ALL},
+    BridgeError, MintPauseUpdated, PauseFlagsUpdated,
+};
 
-/// Set the pause status of the bridge
-/// Only the guardian can call this function
+/// Pause or unpause every instruction family of the bridge at once
+/// The pauser or the guardian can pause; only the guardian can unpause
 pub fn set_pause_status_handler(
-    ctx: Context<SetBridgeConfigFromGuardian>,
+    ctx: Context<SetBridgeConfigFromPauser>,
     paused: bool,
 ) -> Result<()> {
-    ctx.accounts.bridge.paused = paused;
+    let flags = if paused { PAUSE_ALL } else { 0 };
+    set_pause_flags_handler(ctx, flags)
+}
+
+/// Set the bitmask of paused instruction families (see the `PAUSE_*` constants)
+/// The pauser or the guardian can add flags; only the guardian can clear them
+pub fn set_pause_flags_handler(ctx: Context<SetBridgeConfigFromPauser>, flags: u16) -> Result<()> {
+    let before = ctx.accounts.bridge.pause.flags;
+
+    if before & !flags != 0 {
+        require_guardian(&ctx)?;
+    }
+
+    ctx.accounts.bridge.pause.flags = flags;
+    ctx.accounts.bridge.pause.validate()?;
+
+    emit_cpi!(PauseFlagsUpdated {
+        before,
+        after: flags,
+    });
+
+    Ok(())
+}
+
+/// Pause or unpause both outgoing and incoming transfers of a single mint
+/// The pauser or the guardian can pause; only the guardian can unpause
+pub fn set_mint_pause_handler(
+    ctx: Context<SetBridgeConfigFromPauser>,
+    mint: Pubkey,
+    paused: bool,
+) -> Result<()> {
+    if !paused {
+        require_guardian(&ctx)?;
+    }
+
+    ctx.accounts.bridge.pause.set_mint_paused(mint, paused)?;
+
+    emit_cpi!(MintPauseUpdated { mint, paused });
+
+    Ok(())
+}
+
+fn require_guardian(ctx: &Context<SetBridgeConfigFromPauser>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.authority.key(),
+        ctx.accounts.bridge.guardian,
+        BridgeError::UnauthorizedUnpause
+    );
     Ok(())
 }
 
@@ -25,7 +75,7 @@ mod tests {
         accounts,
         common::bridge::Bridge,
         instruction::SetPauseStatus as SetPauseStatusIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        test_utils::{event_authority_pda, find_cpi_event, setup_bridge, SetupBridgeResult},
         ID,
     };
 
@@ -42,9 +92,11 @@ mod tests {
         let new_paused = true;
 
         // Build the instruction accounts
-        let accounts = accounts::SetBridgeConfigFromGuardian {
+        let accounts = accounts::SetBridgeConfigFromPauser {
             bridge: bridge_pda,
-            guardian: guardian.pubkey(),
+            authority: guardian.pubkey(),
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -62,17 +114,23 @@ mod tests {
             svm.latest_blockhash(),
         );
 
-        svm.send_transaction(tx)
+        let meta = svm
+            .send_transaction(tx)
             .expect("Failed to send set_pause_status transaction");
 
+        // Verify the pause status change was logged
+        let event = find_cpi_event::<PauseFlagsUpdated>(&meta)
+            .expect("PauseFlagsUpdated event should be emitted");
+        assert_eq!(event.before, 0);
+        assert_eq!(event.after, PAUSE_ALL);
+
         // Verify the pause status was updated
         let bridge_account = svm.get_account(&bridge_pda).unwrap();
         let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
 
         assert_eq!(
-            bridge_data.paused, new_paused,
-            "Pause status should be updated to {}",
-            new_paused
+            bridge_data.pause.flags, PAUSE_ALL,
+            "All instruction families should be paused"
         );
     }
 
@@ -92,9 +150,11 @@ mod tests {
         let new_paused = true;
 
         // Build the instruction accounts with fake guardian
-        let accounts = accounts::SetBridgeConfigFromGuardian {
+        let accounts = accounts::SetBridgeConfigFromPauser {
             bridge: bridge_pda,
-            guardian: fake_guardian.pubkey(), // Wrong guardian
+            authority: fake_guardian.pubkey(), // Neither pauser nor guardian
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
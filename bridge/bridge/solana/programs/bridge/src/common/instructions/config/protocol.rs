@@ -1,12 +1,19 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::{common::SetBridgeConfigFromGuardian, ProtocolConfigUpdated};
 
 /// Set the block interval requirement
 pub fn set_block_interval_requirement_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_interval: u64,
 ) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.protocol_config.clone();
+
     ctx.accounts
         .bridge
         .protocol_config
@@ -14,6 +21,86 @@ pub fn set_block_interval_requirement_handler(
 
     ctx.accounts.bridge.protocol_config.validate()?;
 
+    emit_cpi!(ProtocolConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.protocol_config.clone(),
+    });
+
+    Ok(())
+}
+
+/// Set the number of Base blocks output roots are retained before they can be closed
+pub fn set_output_root_retention_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_retention_blocks: u64,
+) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.protocol_config.clone();
+
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .output_root_retention_blocks = new_retention_blocks;
+
+    ctx.accounts.bridge.protocol_config.validate()?;
+
+    emit_cpi!(ProtocolConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.protocol_config.clone(),
+    });
+
+    Ok(())
+}
+
+/// Set the challenge window during which freshly registered output roots cannot be proven against
+pub fn set_root_finality_seconds_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_finality_seconds: u64,
+) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.protocol_config.clone();
+
+    ctx.accounts.bridge.protocol_config.root_finality_seconds = new_finality_seconds;
+
+    ctx.accounts.bridge.protocol_config.validate()?;
+
+    emit_cpi!(ProtocolConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.protocol_config.clone(),
+    });
+
+    Ok(())
+}
+
+/// Set the delay after which unrelayed messages can be bounced back to Base
+pub fn set_bounce_delay_seconds_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_delay_seconds: u64,
+) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+
+    let before = ctx.accounts.bridge.protocol_config.clone();
+
+    ctx.accounts.bridge.protocol_config.bounce_delay_seconds = new_delay_seconds;
+
+    ctx.accounts.bridge.protocol_config.validate()?;
+
+    emit_cpi!(ProtocolConfigUpdated {
+        before,
+        after: ctx.accounts.bridge.protocol_config.clone(),
+    });
+
     Ok(())
 }
 
@@ -30,7 +117,7 @@ mod tests {
         accounts,
         common::bridge::Bridge,
         instruction::SetBlockIntervalRequirement as SetBlockIntervalRequirementIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        test_utils::{event_authority_pda, setup_bridge, SetupBridgeResult},
         ID,
     };
 
@@ -50,6 +137,8 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: guardian.pubkey(),
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -100,6 +189,8 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: fake_guardian.pubkey(), // Wrong guardian
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
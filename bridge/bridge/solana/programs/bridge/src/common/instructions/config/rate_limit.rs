@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{constants::RATE_LIMIT_SEED, MintRateLimit, RateLimitConfig},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    BridgeError, MintRateLimitUpdated,
+};
+
+/// Accounts struct for configuring the withdrawal rate limit of a mint
+/// Only the guardian can create or update rate limits
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct SetMintRateLimit<'info> {
+    /// The guardian account authorized to update configuration; pays for the rate limit account
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian; records `mint` as rate limited
+    #[account(
+        mut,
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The rate limit account for `mint`, created on first configuration
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        space = DISCRIMINATOR_LEN + MintRateLimit::INIT_SPACE,
+        seeds = [RATE_LIMIT_SEED, mint.as_ref()],
+        bump
+    )]
+    pub rate_limit: Account<'info, MintRateLimit>,
+
+    /// System program required for creating the rate limit account
+    pub system_program: Program<'info, System>,
+}
+
+/// Create or update the withdrawal rate limit for a mint
+/// A newly created limit starts full; on update the available amount is clamped to the new capacity
+pub fn set_mint_rate_limit_handler(
+    ctx: Context<SetMintRateLimit>,
+    mint: Pubkey,
+    cfg: RateLimitConfig,
+) -> Result<()> {
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .require_no_config_timelock()?;
+    cfg.validate()?;
+    ctx.accounts.bridge.rate_limited_mints.add(mint)?;
+
+    let before = ctx
+        .accounts
+        .rate_limit
+        .configure(mint, cfg.clone(), Clock::get()?.unix_timestamp);
+
+    emit_cpi!(MintRateLimitUpdated {
+        mint,
+        before,
+        after: cfg,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts, common::bridge::RateLimitedMints,
+        instruction::SetMintRateLimit as SetMintRateLimitIx, solana_to_base::NATIVE_SOL_PUBKEY,
+        test_utils::*, ID,
+    };
+
+    fn send_set_mint_rate_limit(
+        svm: &mut litesvm::LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        mint: Pubkey,
+        cfg: RateLimitConfig,
+    ) -> std::result::Result<
+        litesvm::types::TransactionMetadata,
+        Box<litesvm::types::FailedTransactionMetadata>,
+    > {
+        let rate_limit = Pubkey::find_program_address(&[RATE_LIMIT_SEED, mint.as_ref()], &ID).0;
+
+        let accounts = accounts::SetMintRateLimit {
+            guardian: guardian.pubkey(),
+            bridge: bridge_pda,
+            rate_limit,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: SetMintRateLimitIx { mint, cfg }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).map_err(Box::new)
+    }
+
+    fn read_rate_limited_mints(svm: &litesvm::LiteSVM, bridge_pda: Pubkey) -> RateLimitedMints {
+        let account = svm.get_account(&bridge_pda).unwrap();
+        Bridge::try_deserialize(&mut &account.data[..])
+            .unwrap()
+            .rate_limited_mints
+    }
+
+    #[test]
+    fn test_set_mint_rate_limit_success() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let mint = NATIVE_SOL_PUBKEY;
+        let cfg = RateLimitConfig {
+            capacity: 1_000,
+            refill_per_window: 100,
+            window_duration_seconds: 3_600,
+        };
+
+        send_set_mint_rate_limit(&mut svm, &guardian, bridge_pda, mint, cfg.clone())
+            .expect("Failed to create rate limit");
+
+        let rate_limit_pda = Pubkey::find_program_address(&[RATE_LIMIT_SEED, mint.as_ref()], &ID).0;
+        let account = svm.get_account(&rate_limit_pda).unwrap();
+        let rate_limit = MintRateLimit::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(rate_limit.mint, mint);
+        assert_eq!(rate_limit.config, cfg);
+        assert_eq!(rate_limit.available, cfg.capacity);
+        assert_eq!(read_rate_limited_mints(&svm, bridge_pda).mint_count, 1);
+
+        // Lowering the capacity clamps the available amount
+        let new_cfg = RateLimitConfig {
+            capacity: 500,
+            ..cfg.clone()
+        };
+        svm.expire_blockhash();
+        let meta = send_set_mint_rate_limit(&mut svm, &guardian, bridge_pda, mint, new_cfg.clone())
+            .expect("Failed to update rate limit");
+
+        let event = find_cpi_event::<MintRateLimitUpdated>(&meta)
+            .expect("MintRateLimitUpdated event should be emitted");
+        assert_eq!(event.before, Some(cfg));
+        assert_eq!(event.after, new_cfg);
+
+        let account = svm.get_account(&rate_limit_pda).unwrap();
+        let rate_limit = MintRateLimit::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(rate_limit.config, new_cfg);
+        assert_eq!(rate_limit.available, 500);
+
+        // Updating an existing limit does not register the mint twice
+        let rate_limited_mints = read_rate_limited_mints(&svm, bridge_pda);
+        assert_eq!(rate_limited_mints.mint_count, 1);
+        assert!(rate_limited_mints.contains(&mint));
+    }
+
+    #[test]
+    fn test_set_mint_rate_limit_unauthorized() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), 1_000_000_000).unwrap();
+
+        let result = send_set_mint_rate_limit(
+            &mut svm,
+            &fake_guardian,
+            bridge_pda,
+            Pubkey::new_unique(),
+            RateLimitConfig {
+                capacity: 1_000,
+                refill_per_window: 100,
+                window_duration_seconds: 3_600,
+            },
+        );
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_set_mint_rate_limit_zero_window_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let result = send_set_mint_rate_limit(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            Pubkey::new_unique(),
+            RateLimitConfig {
+                capacity: 1_000,
+                refill_per_window: 100,
+                window_duration_seconds: 0,
+            },
+        );
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("InvalidWindowDurationSeconds"),
+            "Expected InvalidWindowDurationSeconds error, got: {}",
+            error_string
+        );
+    }
+}
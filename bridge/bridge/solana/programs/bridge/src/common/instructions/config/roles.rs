@@ -0,0 +1,240 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{Roles, SetBridgeConfigFromUpgradeAuthority},
+    RolesUpdated,
+};
+
+/// Assign the pauser and signer manager roles.
+/// Only the upgrade authority can call this function.
+pub fn set_roles_handler(
+    ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+    roles: Roles,
+) -> Result<()> {
+    let before = ctx.accounts.bridge.roles.clone();
+    ctx.accounts.bridge.roles = roles.clone();
+
+    emit_cpi!(RolesUpdated {
+        before,
+        after: roles,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{bpf_loader_upgradeable, instruction::Instruction},
+        InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::bridge::Bridge,
+        instruction::{SetMintPause, SetPauseStatus, SetRoles},
+        test_utils::*,
+        ID,
+    };
+
+    fn set_roles_ix(upgrade_authority: &Keypair, bridge_pda: Pubkey, roles: Roles) -> Instruction {
+        let (program_data_pda, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+
+        Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromUpgradeAuthority {
+                upgrade_authority: upgrade_authority.pubkey(),
+                bridge: bridge_pda,
+                program_data: program_data_pda,
+                program: ID,
+                event_authority: event_authority_pda(),
+            }
+            .to_account_metas(None),
+            data: SetRoles { roles }.data(),
+        }
+    }
+
+    fn pause_accounts(authority: &Keypair, bridge_pda: Pubkey) -> Vec<AccountMeta> {
+        accounts::SetBridgeConfigFromPauser {
+            bridge: bridge_pda,
+            authority: authority.pubkey(),
+            event_authority: event_authority_pda(),
+            program: ID,
+        }
+        .to_account_metas(None)
+    }
+
+    #[test]
+    fn test_set_roles_with_upgrade_authority_succeeds() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let roles = Roles {
+            pauser: Pubkey::new_unique(),
+            signer_manager: Pubkey::new_unique(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[set_roles_ix(&payer, bridge_pda, roles.clone())],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Transaction should succeed with upgrade authority");
+
+        let event =
+            find_cpi_event::<RolesUpdated>(&meta).expect("RolesUpdated event should be emitted");
+        assert_eq!(event.after, roles);
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.roles, roles);
+    }
+
+    #[test]
+    fn test_set_roles_with_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(
+                &[set_roles_ix(&guardian, bridge_pda, Roles::default())],
+                Some(&guardian.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_pauser_can_pause_but_not_unpause() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+
+        let pauser = Keypair::new();
+        svm.airdrop(&pauser.pubkey(), 1_000_000_000).unwrap();
+        let mint = Pubkey::new_unique();
+
+        // Hand the pauser role to a dedicated key
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[set_roles_ix(
+                    &payer,
+                    bridge_pda,
+                    Roles {
+                        pauser: pauser.pubkey(),
+                        signer_manager: payer.pubkey(),
+                    },
+                )],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Failed to set roles");
+
+        // The pauser can pause the bridge and a mint
+        let tx = Transaction::new(
+            &[&pauser],
+            Message::new(
+                &[
+                    Instruction {
+                        program_id: ID,
+                        accounts: pause_accounts(&pauser, bridge_pda),
+                        data: SetPauseStatus { new_paused: true }.data(),
+                    },
+                    Instruction {
+                        program_id: ID,
+                        accounts: pause_accounts(&pauser, bridge_pda),
+                        data: SetMintPause { mint, paused: true }.data(),
+                    },
+                ],
+                Some(&pauser.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Pauser should be able to pause");
+
+        // The pauser cannot lift the pause
+        for data in [
+            SetPauseStatus { new_paused: false }.data(),
+            SetMintPause {
+                mint,
+                paused: false,
+            }
+            .data(),
+        ] {
+            svm.expire_blockhash();
+            let tx = Transaction::new(
+                &[&pauser],
+                Message::new(
+                    &[Instruction {
+                        program_id: ID,
+                        accounts: pause_accounts(&pauser, bridge_pda),
+                        data,
+                    }],
+                    Some(&pauser.pubkey()),
+                ),
+                svm.latest_blockhash(),
+            );
+            let error_string = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+            assert!(
+                error_string.contains("UnauthorizedUnpause"),
+                "Expected UnauthorizedUnpause error, got: {}",
+                error_string
+            );
+        }
+
+        // The guardian can
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(
+                &[Instruction {
+                    program_id: ID,
+                    accounts: pause_accounts(&guardian, bridge_pda),
+                    data: SetPauseStatus { new_paused: false }.data(),
+                }],
+                Some(&guardian.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Guardian should be able to unpause");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.pause.flags, 0);
+        assert!(bridge.pause.is_mint_paused(&mint));
+    }
+}
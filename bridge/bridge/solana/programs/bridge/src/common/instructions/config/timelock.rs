@@ -0,0 +1,1295 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{
+        constants::{BASE_ORACLE_SIGNERS_SEED, CPI_POLICY_SEED, RATE_LIMIT_SEED},
+        state::{BaseOracleSigners, CpiPolicy, CpiPolicyConfig, MintRateLimit, RateLimitConfig},
+    },
+    common::{
+        bridge::Bridge, create_pda_account, ConfigChange, ConfigChangeAuthority,
+        PendingConfigChange, BRIDGE_SEED, DISCRIMINATOR_LEN, PENDING_CONFIG_CHANGE_SEED,
+    },
+    program::Bridge as BridgeProgram,
+    BridgeError, ConfigChangeCancelled, ConfigChangeExecuted, ConfigChangeQueued,
+};
+
+/// Accounts struct for queuing a config change through the timelock
+/// Only the guardian can queue changes; Base oracle signer changes require the signer manager and
+/// oracle configuration changes the upgrade authority
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(pending_change_salt: [u8; 32])]
+pub struct QueueConfigChange<'info> {
+    /// The guardian account authorized to update configuration; pays for the pending change account
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account containing configuration
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The pending change account holding the queued change until it is executed or cancelled
+    #[account(
+        init,
+        payer = guardian,
+        space = DISCRIMINATOR_LEN + PendingConfigChange::INIT_SPACE,
+        seeds = [PENDING_CONFIG_CHANGE_SEED, pending_change_salt.as_ref()],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    /// System program required for creating the pending change account
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for queuing a Base oracle signer change, which requires the signer manager
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(pending_change_salt: [u8; 32])]
+pub struct QueueConfigChangeFromSignerManager<'info> {
+    /// The signer manager account; pays for the pending change account
+    #[account(mut)]
+    pub signer_manager: Signer<'info>,
+
+    /// The bridge account holding the signer manager role
+    #[account(
+        constraint = bridge.roles.signer_manager == signer_manager.key()
+            @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The pending change account holding the queued change until it is executed or cancelled
+    #[account(
+        init,
+        payer = signer_manager,
+        space = DISCRIMINATOR_LEN + PendingConfigChange::INIT_SPACE,
+        seeds = [PENDING_CONFIG_CHANGE_SEED, pending_change_salt.as_ref()],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    /// System program required for creating the pending change account
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for queuing a config change that requires the upgrade authority
+#[derive(Accounts)]
+#[instruction(pending_change_salt: [u8; 32])]
+pub struct QueueConfigChangeFromUpgradeAuthority<'info> {
+    /// The upgrade authority account; pays for the pending change account
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+
+    /// The bridge account containing configuration
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The pending change account holding the queued change until it is executed or cancelled
+    #[account(
+        init,
+        payer = upgrade_authority,
+        space = DISCRIMINATOR_LEN + PendingConfigChange::INIT_SPACE,
+        seeds = [PENDING_CONFIG_CHANGE_SEED, pending_change_salt.as_ref()],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ BridgeError::UnauthorizedConfigUpdate)]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ BridgeError::IncorrectBridgeProgram)]
+    pub program: Program<'info, BridgeProgram>,
+
+    /// System program required for creating the pending change account
+    pub system_program: Program<'info, System>,
+
+    /// Event authority used to sign the self-CPI that logs config change events.
+    /// Declared manually (rather than via `#[event_cpi]`) as `program` is already part of this struct.
+    /// CHECK: Only the event authority PDA can sign the self-CPI.
+    #[account(seeds = [b"__event_authority"], bump)]
+    pub event_authority: AccountInfo<'info>,
+}
+
+/// Accounts struct for executing a queued config change once its `eta` has passed
+/// Anyone can execute a ready change
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    /// The bridge account containing configuration
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The pending change account; closed after execution
+    #[account(mut, has_one = proposer, close = proposer)]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    /// The account that queued the change; receives the pending change rent back
+    /// CHECK: Checked against `pending_change.proposer`.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// The latest Base oracle signer epoch; only required for Base oracle signer changes
+    #[account(
+        mut,
+        seeds = [BASE_ORACLE_SIGNERS_SEED, &base_oracle_signers.epoch.to_le_bytes()],
+        bump
+    )]
+    pub base_oracle_signers: Option<Account<'info, BaseOracleSigners>>,
+
+    /// The next Base oracle signer epoch; only required for epoch starts, which create it
+    /// CHECK: Address is validated against the latest epoch in the handler.
+    #[account(mut)]
+    pub next_base_oracle_signers: Option<UncheckedAccount<'info>>,
+
+    /// Funds the accounts created by the change; only required for changes that create one
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// The withdrawal rate limit account of the mint; only required for rate limit changes.
+    /// Created if the mint is not rate limited yet.
+    /// CHECK: Address is validated against the change in the handler.
+    #[account(mut)]
+    pub rate_limit: Option<UncheckedAccount<'info>>,
+
+    /// The CPI policy account; only required for CPI policy changes.
+    /// Created if the guardian has not configured the policy yet.
+    /// CHECK: Address is validated in the handler.
+    #[account(mut)]
+    pub cpi_policy: Option<UncheckedAccount<'info>>,
+
+    /// System program; only required for changes that create an account
+    pub system_program: Option<Program<'info, System>>,
+}
+
+/// Accounts struct for cancelling a queued config change
+/// Only the guardian can cancel changes
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    /// The guardian account authorized to cancel config changes
+    pub guardian: Signer<'info>,
+
+    /// The bridge account containing configuration
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The pending change account; closed on cancellation
+    #[account(mut, has_one = proposer, close = proposer)]
+    pub pending_change: Account<'info, PendingConfigChange>,
+
+    /// The account that queued the change; receives the pending change rent back
+    /// CHECK: Checked against `pending_change.proposer`.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+/// Queue a config change that can be executed after `protocol_config.config_change_delay_seconds`
+pub fn queue_config_change_handler(
+    ctx: Context<QueueConfigChange>,
+    _pending_change_salt: [u8; 32],
+    change: ConfigChange,
+) -> Result<()> {
+    require!(
+        change.authority() == ConfigChangeAuthority::Guardian,
+        BridgeError::UnauthorizedConfigUpdate
+    );
+
+    let event = queue_config_change_internal(
+        &ctx.accounts.bridge,
+        &mut ctx.accounts.pending_change,
+        ctx.accounts.guardian.key(),
+        change,
+    )?;
+    emit_cpi!(event);
+
+    Ok(())
+}
+
+/// Queue a config change from the signer manager; required for Base oracle signer changes
+pub fn queue_config_change_from_signer_manager_handler(
+    ctx: Context<QueueConfigChangeFromSignerManager>,
+    _pending_change_salt: [u8; 32],
+    change: ConfigChange,
+) -> Result<()> {
+    require!(
+        change.authority() == ConfigChangeAuthority::SignerManager,
+        BridgeError::UnauthorizedConfigUpdate
+    );
+
+    let event = queue_config_change_internal(
+        &ctx.accounts.bridge,
+        &mut ctx.accounts.pending_change,
+        ctx.accounts.signer_manager.key(),
+        change,
+    )?;
+    emit_cpi!(event);
+
+    Ok(())
+}
+
+/// Queue a config change from the upgrade authority; required for oracle configuration changes
+pub fn queue_config_change_from_upgrade_authority_handler(
+    ctx: Context<QueueConfigChangeFromUpgradeAuthority>,
+    _pending_change_salt: [u8; 32],
+    change: ConfigChange,
+) -> Result<()> {
+    require!(
+        change.authority() == ConfigChangeAuthority::UpgradeAuthority,
+        BridgeError::UnauthorizedConfigUpdate
+    );
+
+    let event = queue_config_change_internal(
+        &ctx.accounts.bridge,
+        &mut ctx.accounts.pending_change,
+        ctx.accounts.upgrade_authority.key(),
+        change,
+    )?;
+    emit_cpi!(event);
+
+    Ok(())
+}
+
+/// Apply a queued config change once its `eta` has passed
+pub fn execute_config_change_handler(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.pending_change.eta,
+        BridgeError::ConfigChangeNotReady
+    );
+
+    let change = ctx.accounts.pending_change.change.clone();
+    if let ConfigChange::MintRateLimit { mint, cfg } = &change {
+        execute_mint_rate_limit_change(ctx.accounts, *mint, cfg.clone())?;
+    } else if let ConfigChange::CpiPolicy(cfg) = &change {
+        execute_cpi_policy_change(ctx.accounts, cfg.clone())?;
+    } else if let ConfigChange::StartBaseOracleEpoch {
+        activation_block_number,
+        overlap_blocks,
+    } = &change
+    {
+        execute_start_base_oracle_epoch_change(
+            ctx.accounts,
+            *activation_block_number,
+            *overlap_blocks,
+        )?;
+    } else if change.updates_base_oracle_signers() {
+        let base_oracle_signers = ctx
+            .accounts
+            .base_oracle_signers
+            .as_mut()
+            .ok_or(BridgeError::MissingBaseOracleSigners)?;
+        base_oracle_signers.require_latest()?;
+        change.apply_to_base_oracle_signers(base_oracle_signers)?;
+
+        // The pending change rent, refunded to the proposer, covers a larger registry
+        resize_base_oracle_signers(
+            base_oracle_signers,
+            &ctx.accounts.pending_change.to_account_info(),
+        )?;
+    } else {
+        change.apply(&mut ctx.accounts.bridge)?;
+    }
+
+    emit_cpi!(ConfigChangeExecuted {
+        pending_change: ctx.accounts.pending_change.key(),
+        change,
+    });
+
+    Ok(())
+}
+
+/// Discard a queued config change
+pub fn cancel_config_change_handler(ctx: Context<CancelConfigChange>) -> Result<()> {
+    emit_cpi!(ConfigChangeCancelled {
+        pending_change: ctx.accounts.pending_change.key(),
+        change: ctx.accounts.pending_change.change.clone(),
+    });
+
+    Ok(())
+}
+
+/// Create or update the withdrawal rate limit of `mint`
+fn execute_mint_rate_limit_change(
+    accounts: &mut ExecuteConfigChange,
+    mint: Pubkey,
+    cfg: RateLimitConfig,
+) -> Result<()> {
+    accounts.bridge.rate_limited_mints.add(mint)?;
+
+    let accounts = &*accounts;
+    let rate_limit = accounts
+        .rate_limit
+        .as_ref()
+        .ok_or(BridgeError::MissingConfigChangeAccount)?;
+    let mut state: MintRateLimit = load_or_create(
+        rate_limit,
+        &[RATE_LIMIT_SEED, mint.as_ref()],
+        DISCRIMINATOR_LEN + MintRateLimit::INIT_SPACE,
+        accounts,
+    )?;
+
+    state.configure(mint, cfg, Clock::get()?.unix_timestamp);
+    state.try_serialize(&mut &mut rate_limit.try_borrow_mut_data()?[..])
+}
+
+/// Create or replace the CPI policy applied to relayed messages
+fn execute_cpi_policy_change(accounts: &ExecuteConfigChange, cfg: CpiPolicyConfig) -> Result<()> {
+    let cpi_policy = accounts
+        .cpi_policy
+        .as_ref()
+        .ok_or(BridgeError::MissingConfigChangeAccount)?;
+    let mut state: CpiPolicy = load_or_create(
+        cpi_policy,
+        &[CPI_POLICY_SEED],
+        DISCRIMINATOR_LEN + CpiPolicy::INIT_SPACE,
+        accounts,
+    )?;
+
+    state.config = cfg;
+    state.try_serialize(&mut &mut cpi_policy.try_borrow_mut_data()?[..])
+}
+
+/// Supersede the latest Base oracle signer epoch, creating the next one
+fn execute_start_base_oracle_epoch_change(
+    accounts: &mut ExecuteConfigChange,
+    activation_block_number: u64,
+    overlap_blocks: u64,
+) -> Result<()> {
+    let current = accounts
+        .base_oracle_signers
+        .as_ref()
+        .ok_or(BridgeError::MissingBaseOracleSigners)?;
+    current.require_latest()?;
+    let next_info = accounts
+        .next_base_oracle_signers
+        .as_ref()
+        .ok_or(BridgeError::MissingConfigChangeAccount)?
+        .to_account_info();
+    load_or_create::<BaseOracleSigners>(
+        &next_info,
+        &[BASE_ORACLE_SIGNERS_SEED, &(current.epoch + 1).to_le_bytes()],
+        BaseOracleSigners::space(current.signers.len()),
+        accounts,
+    )?;
+
+    let latest_block_number = accounts.bridge.base_block_number;
+    let current = accounts
+        .base_oracle_signers
+        .as_mut()
+        .ok_or(BridgeError::MissingBaseOracleSigners)?;
+    let next =
+        current.start_next_epoch(latest_block_number, activation_block_number, overlap_blocks)?;
+    next.try_serialize(&mut &mut next_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+/// Read the account of type `T` at the PDA derived from `seeds`. If it does not exist yet, it is
+/// created with `space` bytes funded by the executor, and reads as all zeroes.
+fn load_or_create<'info, T: AccountDeserialize + Discriminator>(
+    account: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    space: usize,
+    accounts: &ExecuteConfigChange<'info>,
+) -> Result<T> {
+    let (expected, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(
+        account.key(),
+        expected,
+        anchor_lang::error::ErrorCode::ConstraintSeeds
+    );
+
+    if *account.owner != crate::ID {
+        let (Some(payer), Some(system_program)) = (&accounts.payer, &accounts.system_program)
+        else {
+            return err!(BridgeError::MissingConfigChangeAccount);
+        };
+        let bump = [bump];
+        let signer_seeds = [seeds, &[&bump[..]]].concat();
+        create_pda_account(
+            &payer.to_account_info(),
+            account,
+            &system_program.to_account_info(),
+            &[&signer_seeds],
+            space,
+        )?;
+        account.try_borrow_mut_data()?[..DISCRIMINATOR_LEN].copy_from_slice(T::DISCRIMINATOR);
+    }
+
+    T::try_deserialize(&mut &account.try_borrow_data()?[..])
+}
+
+/// Resize the Base oracle signer registry to fit its signers, moving the rent difference
+/// from or to `funder`. Both accounts are owned by this program.
+fn resize_base_oracle_signers(
+    base_oracle_signers: &Account<BaseOracleSigners>,
+    funder: &AccountInfo,
+) -> Result<()> {
+    let info = base_oracle_signers.to_account_info();
+    let new_len = BaseOracleSigners::space(base_oracle_signers.signers.len());
+    let new_rent = Rent::get()?.minimum_balance(new_len);
+    let current = info.lamports();
+
+    if new_rent > current {
+        let funder_lamports = funder
+            .lamports()
+            .checked_sub(new_rent - current)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        **funder.try_borrow_mut_lamports()? = funder_lamports;
+    } else {
+        **funder.try_borrow_mut_lamports()? += current - new_rent;
+    }
+    **info.try_borrow_mut_lamports()? = new_rent;
+
+    info.realloc(new_len, false)?;
+    Ok(())
+}
+
+fn queue_config_change_internal(
+    bridge: &Bridge,
+    pending_change: &mut Account<PendingConfigChange>,
+    proposer: Pubkey,
+    change: ConfigChange,
+) -> Result<ConfigChangeQueued> {
+    // Reject changes that would leave the bridge with an invalid configuration right away.
+    // Base oracle signer changes are validated against the registry when executed.
+    if !change.updates_base_oracle_signers() {
+        change.apply(&mut bridge.clone())?;
+    }
+
+    let eta = Clock::get()?
+        .unix_timestamp
+        .saturating_add(bridge.protocol_config.config_change_delay_seconds as i64);
+
+    **pending_change = PendingConfigChange {
+        proposer,
+        eta,
+        change: change.clone(),
+    };
+
+    Ok(ConfigChangeQueued {
+        pending_change: pending_change.key(),
+        proposer,
+        change,
+        eta,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{
+            instruction::Instruction, native_token::LAMPORTS_PER_SOL, system_program,
+        },
+        InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_signer::Signer;
+
+    use crate::{
+        accounts,
+        base_to_solana::state::{BaseOracleSigner, CpiPolicyMode, OracleSignerKey},
+        instruction::{
+            CancelConfigChange as CancelConfigChangeIx,
+            ExecuteConfigChange as ExecuteConfigChangeIx, QueueConfigChange as QueueConfigChangeIx,
+            QueueConfigChangeFromSignerManager as QueueConfigChangeFromSignerManagerIx,
+            QueueConfigChangeFromUpgradeAuthority as QueueConfigChangeFromUpgradeAuthorityIx,
+            SetCpiPolicy as SetCpiPolicyIx, SetGasPerCall as SetGasPerCallIx,
+            SetMintRateLimit as SetMintRateLimitIx, StartBaseOracleEpoch as StartBaseOracleEpochIx,
+        },
+        test_utils::*,
+        ID,
+    };
+
+    const DELAY: u64 = 3_600;
+    const TEST_TIMESTAMP: i64 = 1747440000;
+
+    fn pending_change_pda(salt: [u8; 32]) -> Pubkey {
+        Pubkey::find_program_address(&[PENDING_CONFIG_CHANGE_SEED, salt.as_ref()], &ID).0
+    }
+
+    fn queue_ix(
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        salt: [u8; 32],
+        change: ConfigChange,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::QueueConfigChange {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                pending_change: pending_change_pda(salt),
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: QueueConfigChangeIx {
+                pending_change_salt: salt,
+                change,
+            }
+            .data(),
+        }
+    }
+
+    /// Accounts executing a change that only updates the bridge account
+    fn execute_accounts(
+        bridge_pda: Pubkey,
+        salt: [u8; 32],
+        proposer: Pubkey,
+    ) -> accounts::ExecuteConfigChange {
+        accounts::ExecuteConfigChange {
+            bridge: bridge_pda,
+            pending_change: pending_change_pda(salt),
+            proposer,
+            base_oracle_signers: None,
+            next_base_oracle_signers: None,
+            payer: None,
+            rate_limit: None,
+            cpi_policy: None,
+            system_program: None,
+            event_authority: event_authority_pda(),
+            program: ID,
+        }
+    }
+
+    fn execute_ix(bridge_pda: Pubkey, salt: [u8; 32], proposer: Pubkey) -> Instruction {
+        execute_ix_with_accounts(execute_accounts(bridge_pda, salt, proposer))
+    }
+
+    fn execute_ix_with_accounts(accounts: accounts::ExecuteConfigChange) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts.to_account_metas(None),
+            data: ExecuteConfigChangeIx {}.data(),
+        }
+    }
+
+    fn queue_from_signer_manager_ix(
+        signer_manager: &Keypair,
+        bridge_pda: Pubkey,
+        salt: [u8; 32],
+        change: ConfigChange,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::QueueConfigChangeFromSignerManager {
+                signer_manager: signer_manager.pubkey(),
+                bridge: bridge_pda,
+                pending_change: pending_change_pda(salt),
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: QueueConfigChangeFromSignerManagerIx {
+                pending_change_salt: salt,
+                change,
+            }
+            .data(),
+        }
+    }
+
+    fn queue_from_upgrade_authority_ix(
+        upgrade_authority: &Keypair,
+        bridge_pda: Pubkey,
+        salt: [u8; 32],
+        change: ConfigChange,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::QueueConfigChangeFromUpgradeAuthority {
+                upgrade_authority: upgrade_authority.pubkey(),
+                bridge: bridge_pda,
+                pending_change: pending_change_pda(salt),
+                program_data: Pubkey::find_program_address(
+                    &[ID.as_ref()],
+                    &anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+                )
+                .0,
+                program: ID,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+            }
+            .to_account_metas(None),
+            data: QueueConfigChangeFromUpgradeAuthorityIx {
+                pending_change_salt: salt,
+                change,
+            }
+            .data(),
+        }
+    }
+
+    /// Sets up a bridge with the config change timelock enabled
+    fn setup_timelocked_bridge() -> SetupBridgeResult {
+        let mut result = setup_bridge();
+
+        let mut bridge_account = result.svm.get_account(&result.bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge.protocol_config.config_change_delay_seconds = DELAY;
+        let mut new_data = Vec::new();
+        bridge.try_serialize(&mut new_data).unwrap();
+        bridge_account.data = new_data;
+        result
+            .svm
+            .set_account(result.bridge_pda, bridge_account)
+            .unwrap();
+
+        result
+    }
+
+    /// Assigns the signer manager role to a new funded account, distinct from the upgrade authority
+    fn set_signer_manager(svm: &mut litesvm::LiteSVM, bridge_pda: Pubkey) -> Keypair {
+        let signer_manager = Keypair::new();
+        svm.airdrop(&signer_manager.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge.roles.signer_manager = signer_manager.pubkey();
+        let mut new_data = Vec::new();
+        bridge.try_serialize(&mut new_data).unwrap();
+        bridge_account.data = new_data;
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+
+        signer_manager
+    }
+
+    fn gas_per_call(svm: &litesvm::LiteSVM, bridge_pda: Pubkey) -> u64 {
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        Bridge::try_deserialize(&mut &bridge_account.data[..])
+            .unwrap()
+            .gas_config
+            .gas_per_call
+    }
+
+    #[test]
+    fn test_queue_and_execute_config_change() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            payer,
+            bridge_pda,
+        } = setup_timelocked_bridge();
+        let salt = [1u8; 32];
+
+        let meta = send(
+            &mut svm,
+            &guardian,
+            queue_ix(&guardian, bridge_pda, salt, ConfigChange::GasPerCall(42)),
+        )
+        .expect("Failed to queue config change");
+
+        let event = find_cpi_event::<ConfigChangeQueued>(&meta)
+            .expect("ConfigChangeQueued event should be emitted");
+        assert_eq!(event.eta, TEST_TIMESTAMP + DELAY as i64);
+        assert_eq!(event.change, ConfigChange::GasPerCall(42));
+
+        // Executing before the eta fails
+        let result = send(
+            &mut svm,
+            &payer,
+            execute_ix(bridge_pda, salt, guardian.pubkey()),
+        );
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("ConfigChangeNotReady"),
+            "Expected ConfigChangeNotReady error, got: {}",
+            error_string
+        );
+
+        // Anyone can execute once the delay has elapsed
+        mock_clock(&mut svm, TEST_TIMESTAMP + DELAY as i64);
+        send(
+            &mut svm,
+            &payer,
+            execute_ix(bridge_pda, salt, guardian.pubkey()),
+        )
+        .expect("Failed to execute config change");
+
+        assert_eq!(gas_per_call(&svm, bridge_pda), 42);
+        assert!(svm
+            .get_account(&pending_change_pda(salt))
+            .is_none_or(|a| a.lamports == 0));
+    }
+
+    #[test]
+    fn test_cancel_config_change() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            payer,
+            bridge_pda,
+        } = setup_timelocked_bridge();
+        let salt = [2u8; 32];
+        let before = gas_per_call(&svm, bridge_pda);
+
+        send(
+            &mut svm,
+            &guardian,
+            queue_ix(&guardian, bridge_pda, salt, ConfigChange::GasPerCall(42)),
+        )
+        .expect("Failed to queue config change");
+
+        let cancel_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::CancelConfigChange {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                pending_change: pending_change_pda(salt),
+                proposer: guardian.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: CancelConfigChangeIx {}.data(),
+        };
+        send(&mut svm, &guardian, cancel_ix).expect("Failed to cancel config change");
+
+        // The cancelled change can no longer be executed
+        mock_clock(&mut svm, TEST_TIMESTAMP + DELAY as i64);
+        let result = send(
+            &mut svm,
+            &payer,
+            execute_ix(bridge_pda, salt, guardian.pubkey()),
+        );
+        assert!(result.is_err());
+        assert_eq!(gas_per_call(&svm, bridge_pda), before);
+    }
+
+    #[test]
+    fn test_queue_oracle_config_change_requires_upgrade_authority() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_timelocked_bridge();
+
+        let result = send(
+            &mut svm,
+            &guardian,
+            queue_ix(
+                &guardian,
+                bridge_pda,
+                [3u8; 32],
+                ConfigChange::PartnerOracleConfig(Default::default()),
+            ),
+        );
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_queue_paths_only_accept_changes_of_their_role() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            payer,
+            bridge_pda,
+        } = setup_timelocked_bridge();
+        let signer_manager = set_signer_manager(&mut svm, bridge_pda);
+        let signer_change = ConfigChange::BaseOracleThreshold(1);
+        let oracle_change = ConfigChange::PartnerOracleConfig(Default::default());
+        let guardian_change = ConfigChange::GasPerCall(42);
+
+        // Base oracle signer changes are only accepted from the signer manager
+        assert_error(
+            send(
+                &mut svm,
+                &guardian,
+                queue_ix(&guardian, bridge_pda, [10u8; 32], signer_change.clone()),
+            ),
+            "UnauthorizedConfigUpdate",
+        );
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                queue_from_upgrade_authority_ix(&payer, bridge_pda, [11u8; 32], signer_change),
+            ),
+            "UnauthorizedConfigUpdate",
+        );
+
+        // The signer manager cannot queue other changes
+        for (salt, change) in [([12u8; 32], oracle_change), ([13u8; 32], guardian_change)] {
+            assert_error(
+                send(
+                    &mut svm,
+                    &signer_manager,
+                    queue_from_signer_manager_ix(&signer_manager, bridge_pda, salt, change),
+                ),
+                "UnauthorizedConfigUpdate",
+            );
+        }
+
+        // Only the signer manager can use the signer manager path
+        assert_error(
+            send(
+                &mut svm,
+                &guardian,
+                queue_from_signer_manager_ix(
+                    &guardian,
+                    bridge_pda,
+                    [14u8; 32],
+                    ConfigChange::BaseOracleThreshold(1),
+                ),
+            ),
+            "UnauthorizedConfigUpdate",
+        );
+
+        // The upgrade authority cannot queue guardian changes
+        assert_error(
+            send(
+                &mut svm,
+                &payer,
+                queue_from_upgrade_authority_ix(
+                    &payer,
+                    bridge_pda,
+                    [15u8; 32],
+                    ConfigChange::GasPerCall(42),
+                ),
+            ),
+            "UnauthorizedConfigUpdate",
+        );
+
+        send(
+            &mut svm,
+            &payer,
+            queue_from_upgrade_authority_ix(
+                &payer,
+                bridge_pda,
+                [16u8; 32],
+                ConfigChange::PartnerOracleConfig(Default::default()),
+            ),
+        )
+        .expect("Upgrade authority should be able to queue oracle configuration changes");
+    }
+
+    #[test]
+    fn test_queue_and_execute_base_oracle_signer_change() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_timelocked_bridge();
+        let signer_manager = set_signer_manager(&mut svm, bridge_pda);
+        let salt = [4u8; 32];
+        let signer = BaseOracleSigner {
+            key: OracleSignerKey::Evm([2u8; 20]),
+            weight: 1,
+        };
+
+        send(
+            &mut svm,
+            &signer_manager,
+            queue_from_signer_manager_ix(
+                &signer_manager,
+                bridge_pda,
+                salt,
+                ConfigChange::AddBaseOracleSigner(signer),
+            ),
+        )
+        .expect("Failed to queue config change");
+        mock_clock(&mut svm, TEST_TIMESTAMP + DELAY as i64);
+
+        // The registry must be passed to apply a Base oracle signer change
+        let result = send(
+            &mut svm,
+            &payer,
+            execute_ix(bridge_pda, salt, signer_manager.pubkey()),
+        );
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("MissingBaseOracleSigners"),
+            "Expected MissingBaseOracleSigners error, got: {}",
+            error_string
+        );
+
+        let ix = execute_ix_with_accounts(accounts::ExecuteConfigChange {
+            base_oracle_signers: Some(base_oracle_signers_pda(0)),
+            ..execute_accounts(bridge_pda, salt, signer_manager.pubkey())
+        });
+        send(&mut svm, &payer, ix).expect("Failed to execute config change");
+
+        let registry_account = svm.get_account(&base_oracle_signers_pda(0)).unwrap();
+        assert_eq!(registry_account.data.len(), BaseOracleSigners::space(2));
+        assert_eq!(
+            registry_account.lamports,
+            svm.minimum_balance_for_rent_exemption(BaseOracleSigners::space(2))
+        );
+        let registry = BaseOracleSigners::try_deserialize(&mut &registry_account.data[..]).unwrap();
+        assert_eq!(registry.signers[1], signer);
+        assert!(svm
+            .get_account(&pending_change_pda(salt))
+            .is_none_or(|account| account.lamports == 0));
+    }
+
+    #[test]
+    fn test_queue_and_execute_start_base_oracle_epoch() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_timelocked_bridge();
+        let signer_manager = set_signer_manager(&mut svm, bridge_pda);
+        let salt = [6u8; 32];
+
+        send(
+            &mut svm,
+            &signer_manager,
+            queue_from_signer_manager_ix(
+                &signer_manager,
+                bridge_pda,
+                salt,
+                ConfigChange::StartBaseOracleEpoch {
+                    activation_block_number: 1_000,
+                    overlap_blocks: 50,
+                },
+            ),
+        )
+        .expect("Failed to queue config change");
+        mock_clock(&mut svm, TEST_TIMESTAMP + DELAY as i64);
+
+        // Creating the next epoch requires its account, a payer and the system program
+        let without_next_epoch = execute_ix_with_accounts(accounts::ExecuteConfigChange {
+            base_oracle_signers: Some(base_oracle_signers_pda(0)),
+            payer: Some(payer.pubkey()),
+            system_program: Some(system_program::ID),
+            ..execute_accounts(bridge_pda, salt, signer_manager.pubkey())
+        });
+        assert_error(
+            send(&mut svm, &payer, without_next_epoch),
+            "MissingConfigChangeAccount",
+        );
+
+        let ix = execute_ix_with_accounts(accounts::ExecuteConfigChange {
+            base_oracle_signers: Some(base_oracle_signers_pda(0)),
+            next_base_oracle_signers: Some(base_oracle_signers_pda(1)),
+            payer: Some(payer.pubkey()),
+            system_program: Some(system_program::ID),
+            ..execute_accounts(bridge_pda, salt, signer_manager.pubkey())
+        });
+        send(&mut svm, &payer, ix).expect("Failed to execute config change");
+
+        let current_account = svm.get_account(&base_oracle_signers_pda(0)).unwrap();
+        let current = BaseOracleSigners::try_deserialize(&mut &current_account.data[..]).unwrap();
+        assert_eq!(current.expiry_block_number, 1_050);
+
+        let next_account = svm.get_account(&base_oracle_signers_pda(1)).unwrap();
+        assert_eq!(next_account.owner, ID);
+        let next = BaseOracleSigners::try_deserialize(&mut &next_account.data[..]).unwrap();
+        assert_eq!(next.epoch, 1);
+        assert_eq!(next.activation_block_number, 1_000);
+        assert_eq!(next.expiry_block_number, u64::MAX);
+        assert_eq!(next.threshold, current.threshold);
+        assert_eq!(next.signers, current.signers);
+    }
+
+    #[test]
+    fn test_start_base_oracle_epoch_fails_when_timelocked() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_timelocked_bridge();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::StartBaseOracleEpoch {
+                bridge: bridge_pda,
+                current_base_oracle_signers: base_oracle_signers_pda(0),
+                next_base_oracle_signers: base_oracle_signers_pda(1),
+                signer_manager: payer.pubkey(),
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: StartBaseOracleEpochIx {
+                activation_block_number: 1_000,
+                overlap_blocks: 50,
+            }
+            .data(),
+        };
+
+        assert_error(send(&mut svm, &payer, ix), "ConfigChangeTimelocked");
+    }
+
+    #[test]
+    fn test_queue_and_execute_mint_rate_limit_change() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            payer,
+            bridge_pda,
+        } = setup_timelocked_bridge();
+        let salt = [5u8; 32];
+        let mint = Pubkey::new_unique();
+        let rate_limit = Pubkey::find_program_address(&[RATE_LIMIT_SEED, mint.as_ref()], &ID).0;
+        let cfg = RateLimitConfig {
+            capacity: 1_000,
+            refill_per_window: 100,
+            window_duration_seconds: 3_600,
+        };
+
+        // Invalid limits are rejected when queued
+        let invalid = ConfigChange::MintRateLimit {
+            mint,
+            cfg: RateLimitConfig {
+                window_duration_seconds: 0,
+                ..cfg.clone()
+            },
+        };
+        assert_error(
+            send(
+                &mut svm,
+                &guardian,
+                queue_ix(&guardian, bridge_pda, salt, invalid),
+            ),
+            "InvalidWindowDurationSeconds",
+        );
+
+        let change = ConfigChange::MintRateLimit {
+            mint,
+            cfg: cfg.clone(),
+        };
+        send(
+            &mut svm,
+            &guardian,
+            queue_ix(&guardian, bridge_pda, salt, change),
+        )
+        .expect("Failed to queue config change");
+        mock_clock(&mut svm, TEST_TIMESTAMP + DELAY as i64);
+
+        // Creating the rate limit account requires a payer and the system program
+        let without_payer = execute_ix_with_accounts(accounts::ExecuteConfigChange {
+            rate_limit: Some(rate_limit),
+            ..execute_accounts(bridge_pda, salt, guardian.pubkey())
+        });
+        assert_error(
+            send(&mut svm, &payer, without_payer),
+            "MissingConfigChangeAccount",
+        );
+
+        // The rate limit account must belong to the mint of the change
+        let other_mint = Pubkey::new_unique();
+        let wrong_account = execute_ix_with_accounts(accounts::ExecuteConfigChange {
+            payer: Some(payer.pubkey()),
+            rate_limit: Some(
+                Pubkey::find_program_address(&[RATE_LIMIT_SEED, other_mint.as_ref()], &ID).0,
+            ),
+            system_program: Some(system_program::ID),
+            ..execute_accounts(bridge_pda, salt, guardian.pubkey())
+        });
+        assert_error(send(&mut svm, &payer, wrong_account), "ConstraintSeeds");
+
+        let ix = execute_ix_with_accounts(accounts::ExecuteConfigChange {
+            payer: Some(payer.pubkey()),
+            rate_limit: Some(rate_limit),
+            system_program: Some(system_program::ID),
+            ..execute_accounts(bridge_pda, salt, guardian.pubkey())
+        });
+        send(&mut svm, &payer, ix).expect("Failed to execute config change");
+
+        let account = svm.get_account(&rate_limit).unwrap();
+        assert_eq!(account.owner, ID);
+        let limit = MintRateLimit::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(limit.mint, mint);
+        assert_eq!(limit.config, cfg);
+        assert_eq!(limit.available, cfg.capacity);
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert!(bridge.rate_limited_mints.contains(&mint));
+    }
+
+    #[test]
+    fn test_set_mint_rate_limit_fails_when_timelocked() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_timelocked_bridge();
+        let mint = Pubkey::new_unique();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetMintRateLimit {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                rate_limit: Pubkey::find_program_address(&[RATE_LIMIT_SEED, mint.as_ref()], &ID).0,
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: SetMintRateLimitIx {
+                mint,
+                cfg: RateLimitConfig {
+                    capacity: 1_000,
+                    refill_per_window: 100,
+                    window_duration_seconds: 3_600,
+                },
+            }
+            .data(),
+        };
+
+        assert_error(send(&mut svm, &guardian, ix), "ConfigChangeTimelocked");
+    }
+
+    #[test]
+    fn test_queue_and_execute_cpi_policy_change() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            payer,
+            bridge_pda,
+        } = setup_timelocked_bridge();
+        let salt = [6u8; 32];
+        let program = Pubkey::new_unique();
+
+        // Invalid policies are rejected when queued
+        let invalid = ConfigChange::CpiPolicy(CpiPolicyConfig {
+            mode: CpiPolicyMode::Denylist,
+            programs: vec![program, program],
+        });
+        assert_error(
+            send(
+                &mut svm,
+                &guardian,
+                queue_ix(&guardian, bridge_pda, salt, invalid),
+            ),
+            "DuplicateCpiPolicyProgram",
+        );
+
+        let cfg = CpiPolicyConfig {
+            mode: CpiPolicyMode::Allowlist,
+            programs: vec![program],
+        };
+        send(
+            &mut svm,
+            &guardian,
+            queue_ix(
+                &guardian,
+                bridge_pda,
+                salt,
+                ConfigChange::CpiPolicy(cfg.clone()),
+            ),
+        )
+        .expect("Failed to queue config change");
+        mock_clock(&mut svm, TEST_TIMESTAMP + DELAY as i64);
+
+        // Creating the policy account requires a payer and the system program
+        let without_payer = execute_ix_with_accounts(accounts::ExecuteConfigChange {
+            cpi_policy: Some(cpi_policy_pda()),
+            ..execute_accounts(bridge_pda, salt, guardian.pubkey())
+        });
+        assert_error(
+            send(&mut svm, &payer, without_payer),
+            "MissingConfigChangeAccount",
+        );
+
+        // The policy account cannot be substituted
+        let wrong_account = execute_ix_with_accounts(accounts::ExecuteConfigChange {
+            payer: Some(payer.pubkey()),
+            cpi_policy: Some(Pubkey::new_unique()),
+            system_program: Some(system_program::ID),
+            ..execute_accounts(bridge_pda, salt, guardian.pubkey())
+        });
+        assert_error(send(&mut svm, &payer, wrong_account), "ConstraintSeeds");
+
+        let ix = execute_ix_with_accounts(accounts::ExecuteConfigChange {
+            payer: Some(payer.pubkey()),
+            cpi_policy: Some(cpi_policy_pda()),
+            system_program: Some(system_program::ID),
+            ..execute_accounts(bridge_pda, salt, guardian.pubkey())
+        });
+        send(&mut svm, &payer, ix).expect("Failed to execute config change");
+
+        let account = svm.get_account(&cpi_policy_pda()).unwrap();
+        assert_eq!(account.owner, ID);
+        let policy = CpiPolicy::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(policy.config, cfg);
+    }
+
+    #[test]
+    fn test_set_cpi_policy_fails_when_timelocked() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_timelocked_bridge();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetCpiPolicy {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                cpi_policy: cpi_policy_pda(),
+                system_program: system_program::ID,
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: SetCpiPolicyIx {
+                cfg: CpiPolicyConfig::default(),
+            }
+            .data(),
+        };
+
+        assert_error(send(&mut svm, &guardian, ix), "ConfigChangeTimelocked");
+    }
+
+    #[test]
+    fn test_direct_setter_fails_when_timelocked() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_timelocked_bridge();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: SetGasPerCallIx { new_val: 42 }.data(),
+        };
+
+        let result = send(&mut svm, &guardian, ix);
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("ConfigChangeTimelocked"),
+            "Expected ConfigChangeTimelocked error, got: {}",
+            error_string
+        );
+    }
+}
@@ -1,16 +1,77 @@
 use anchor_lang::prelude::*;
 
-use crate::common::config::SetBridgeConfigFromGuardian;
+use crate::{
+    common::{bridge::Bridge, config::SetBridgeConfigFromGuardian, BRIDGE_SEED},
+    BridgeError, GuardianProposalCancelled, GuardianProposed, GuardianTransferred,
+};
 
-/// Transfer guardian authority to a new pubkey.
+/// Accounts struct for accepting a pending guardian handover
+/// Only the proposed guardian can accept
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptGuardian<'info> {
+    /// The bridge account containing the pending guardian
+    #[account(
+        mut,
+        constraint = bridge.pending_guardian == pending_guardian.key() @ BridgeError::UnauthorizedGuardianAcceptance,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The proposed guardian; must sign to prove control of the key
+    pub pending_guardian: Signer<'info>,
+}
+
+/// Propose a new guardian. The handover only takes effect once `new_guardian` accepts it.
 /// Only the current guardian can call this function.
 ///
-/// Note: No additional validation is performed on `new_guardian` (it may be any pubkey).
-pub fn transfer_guardian_handler(
+/// Note: Proposing again replaces any pending proposal.
+pub fn propose_guardian_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_guardian: Pubkey,
 ) -> Result<()> {
+    require_keys_neq!(new_guardian, Pubkey::default(), BridgeError::ZeroAddress);
+
+    ctx.accounts.bridge.pending_guardian = new_guardian;
+
+    emit_cpi!(GuardianProposed {
+        guardian: ctx.accounts.bridge.guardian,
+        pending_guardian: new_guardian,
+    });
+
+    Ok(())
+}
+
+/// Complete the handover to the pending guardian.
+pub fn accept_guardian_handler(ctx: Context<AcceptGuardian>) -> Result<()> {
+    let previous_guardian = ctx.accounts.bridge.guardian;
+    let new_guardian = ctx.accounts.pending_guardian.key();
+
     ctx.accounts.bridge.guardian = new_guardian;
+    ctx.accounts.bridge.pending_guardian = Pubkey::default();
+
+    emit_cpi!(GuardianTransferred {
+        previous_guardian,
+        new_guardian,
+    });
+
+    Ok(())
+}
+
+/// Discard the pending guardian proposal.
+/// Only the current guardian can call this function.
+pub fn cancel_guardian_proposal_handler(ctx: Context<SetBridgeConfigFromGuardian>) -> Result<()> {
+    let pending_guardian = ctx.accounts.bridge.pending_guardian;
+    require_keys_neq!(
+        pending_guardian,
+        Pubkey::default(),
+        BridgeError::NoPendingGuardian
+    );
+
+    ctx.accounts.bridge.pending_guardian = Pubkey::default();
+
+    emit_cpi!(GuardianProposalCancelled { pending_guardian });
 
     Ok(())
 }
@@ -27,14 +88,49 @@ mod tests {
 
     use crate::{
         accounts,
-        common::bridge::Bridge,
-        instruction::TransferGuardian as TransferGuardianIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        instruction::{
+            AcceptGuardian as AcceptGuardianIx, CancelGuardianProposal as CancelGuardianProposalIx,
+            ProposeGuardian as ProposeGuardianIx,
+        },
+        test_utils::{event_authority_pda, find_cpi_event, setup_bridge, SetupBridgeResult},
         ID,
     };
 
+    fn propose_ix(guardian: &Keypair, bridge_pda: Pubkey, new_guardian: Pubkey) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: ProposeGuardianIx { new_guardian }.data(),
+        }
+    }
+
+    fn accept_ix(pending_guardian: &Keypair, bridge_pda: Pubkey) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::AcceptGuardian {
+                bridge: bridge_pda,
+                pending_guardian: pending_guardian.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: AcceptGuardianIx {}.data(),
+        }
+    }
+
+    fn fetch_bridge(svm: &litesvm::LiteSVM, bridge_pda: Pubkey) -> Bridge {
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap()
+    }
+
     #[test]
-    fn test_transfer_guardian_success() {
+    fn test_propose_and_accept_guardian_success() {
         let SetupBridgeResult {
             mut svm,
             guardian,
@@ -42,49 +138,63 @@ mod tests {
             ..
         } = setup_bridge();
 
-        // Create a new guardian
         let new_guardian = Keypair::new();
+        svm.airdrop(&new_guardian.pubkey(), 1_000_000_000).unwrap();
 
-        // Build the instruction accounts
-        let accounts = accounts::SetBridgeConfigFromGuardian {
-            bridge: bridge_pda,
-            guardian: guardian.pubkey(),
-        }
-        .to_account_metas(None);
+        // Propose the new guardian
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(
+                &[propose_ix(&guardian, bridge_pda, new_guardian.pubkey())],
+                Some(&guardian.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Failed to send propose_guardian transaction");
 
-        // Build the instruction
-        let ix = Instruction {
-            program_id: ID,
-            accounts,
-            data: TransferGuardianIx {
-                new_guardian: new_guardian.pubkey(),
-            }
-            .data(),
-        };
+        let event = find_cpi_event::<GuardianProposed>(&meta)
+            .expect("GuardianProposed event should be emitted");
+        assert_eq!(event.guardian, guardian.pubkey());
+        assert_eq!(event.pending_guardian, new_guardian.pubkey());
+
+        // The current guardian stays in charge until the proposal is accepted
+        let bridge_data = fetch_bridge(&svm, bridge_pda);
+        assert_eq!(bridge_data.guardian, guardian.pubkey());
+        assert_eq!(bridge_data.pending_guardian, new_guardian.pubkey());
 
-        // Build and send the transaction
+        // Accept the handover
         let tx = Transaction::new(
-            &[&guardian],
-            Message::new(&[ix], Some(&guardian.pubkey())),
+            &[&new_guardian],
+            Message::new(
+                &[accept_ix(&new_guardian, bridge_pda)],
+                Some(&new_guardian.pubkey()),
+            ),
             svm.latest_blockhash(),
         );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Failed to send accept_guardian transaction");
 
-        svm.send_transaction(tx)
-            .expect("Failed to send transfer_guardian transaction");
+        // Verify the handover was logged
+        let event = find_cpi_event::<GuardianTransferred>(&meta)
+            .expect("GuardianTransferred event should be emitted");
+        assert_eq!(event.previous_guardian, guardian.pubkey());
+        assert_eq!(event.new_guardian, new_guardian.pubkey());
 
         // Verify the guardian was updated
-        let bridge_account = svm.get_account(&bridge_pda).unwrap();
-        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
-
+        let bridge_data = fetch_bridge(&svm, bridge_pda);
         assert_eq!(
             bridge_data.guardian,
             new_guardian.pubkey(),
             "Guardian should be updated to new guardian"
         );
+        assert_eq!(bridge_data.pending_guardian, Pubkey::default());
     }
 
     #[test]
-    fn test_transfer_guardian_unauthorized() {
+    fn test_propose_guardian_unauthorized() {
         let SetupBridgeResult {
             mut svm,
             bridge_pda,
@@ -95,30 +205,16 @@ mod tests {
         let fake_guardian = Keypair::new();
         svm.airdrop(&fake_guardian.pubkey(), 1_000_000_000).unwrap();
 
-        // Create a new guardian to transfer to
-        let new_guardian = Keypair::new();
-
-        // Build the instruction accounts with fake guardian
-        let accounts = accounts::SetBridgeConfigFromGuardian {
-            bridge: bridge_pda,
-            guardian: fake_guardian.pubkey(), // Wrong guardian
-        }
-        .to_account_metas(None);
-
-        // Build the instruction
-        let ix = Instruction {
-            program_id: ID,
-            accounts,
-            data: TransferGuardianIx {
-                new_guardian: new_guardian.pubkey(),
-            }
-            .data(),
-        };
-
-        // Build and send the transaction with fake guardian
         let tx = Transaction::new(
             &[&fake_guardian],
-            Message::new(&[ix], Some(&fake_guardian.pubkey())),
+            Message::new(
+                &[propose_ix(
+                    &fake_guardian,
+                    bridge_pda,
+                    fake_guardian.pubkey(),
+                )],
+                Some(&fake_guardian.pubkey()),
+            ),
             svm.latest_blockhash(),
         );
 
@@ -137,4 +233,107 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_accept_guardian_by_non_pending_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_guardian = Keypair::new();
+        let impostor = Keypair::new();
+        svm.airdrop(&impostor.pubkey(), 1_000_000_000).unwrap();
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(
+                &[propose_ix(&guardian, bridge_pda, new_guardian.pubkey())],
+                Some(&guardian.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to send propose_guardian transaction");
+
+        let tx = Transaction::new(
+            &[&impostor],
+            Message::new(
+                &[accept_ix(&impostor, bridge_pda)],
+                Some(&impostor.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedGuardianAcceptance"),
+            "Expected UnauthorizedGuardianAcceptance error, got: {}",
+            error_string
+        );
+        assert_eq!(fetch_bridge(&svm, bridge_pda).guardian, guardian.pubkey());
+    }
+
+    #[test]
+    fn test_cancel_guardian_proposal() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_guardian = Keypair::new();
+        svm.airdrop(&new_guardian.pubkey(), 1_000_000_000).unwrap();
+
+        let cancel_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                event_authority: event_authority_pda(),
+                program: ID,
+            }
+            .to_account_metas(None),
+            data: CancelGuardianProposalIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(
+                &[
+                    propose_ix(&guardian, bridge_pda, new_guardian.pubkey()),
+                    cancel_ix,
+                ],
+                Some(&guardian.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Failed to propose and cancel guardian");
+
+        let event = find_cpi_event::<GuardianProposalCancelled>(&meta)
+            .expect("GuardianProposalCancelled event should be emitted");
+        assert_eq!(event.pending_guardian, new_guardian.pubkey());
+        assert_eq!(
+            fetch_bridge(&svm, bridge_pda).pending_guardian,
+            Pubkey::default()
+        );
+
+        // The cancelled proposal can no longer be accepted
+        let tx = Transaction::new(
+            &[&new_guardian],
+            Message::new(
+                &[accept_ix(&new_guardian, bridge_pda)],
+                Some(&new_guardian.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        assert!(svm.send_transaction(tx).is_err());
+        assert_eq!(fetch_bridge(&svm, bridge_pda).guardian, guardian.pubkey());
+    }
 }
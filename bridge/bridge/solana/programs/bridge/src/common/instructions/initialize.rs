@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    base_to_solana::{constants::BASE_ORACLE_SIGNERS_SEED, state::BaseOracleSigners},
     common::{
-        bridge::{Bridge, Eip1559},
+        bridge::{Bridge, Eip1559, PauseConfig, RateLimitedMints},
         Config, BRIDGE_SEED, DISCRIMINATOR_LEN,
     },
     program::Bridge as BridgeProgram,
@@ -14,6 +15,7 @@ use crate::{
 /// Solana, using the provided configuration values and initializing counters/state to zero.
 /// Only the upgrade authority can initialize the bridge for security.
 #[derive(Accounts)]
+#[instruction(guardian: Pubkey, cfg: Config)]
 pub struct Initialize<'info> {
     /// The upgrade authority that is authorized to initialize the bridge.
     /// This ensures only the program deployer can set the initial configuration.
@@ -38,6 +40,18 @@ pub struct Initialize<'info> {
     )]
     pub bridge: Account<'info, Bridge>,
 
+    /// The first Base oracle signer epoch, initialized with `cfg.base_oracle_signers`.
+    /// - Uses PDA with BASE_ORACLE_SIGNERS_SEED and epoch 0 for deterministic address
+    /// - Sized for the initial signer set; reallocated as signers are added or removed
+    #[account(
+        init,
+        payer = payer,
+        seeds = [BASE_ORACLE_SIGNERS_SEED, &0u64.to_le_bytes()],
+        bump,
+        space = BaseOracleSigners::space(cfg.base_oracle_signers.signers.len())
+    )]
+    pub base_oracle_signers: Account<'info, BaseOracleSigners>,
+
     /// Program data account containing the upgrade authority.
     /// Validates that the signer is indeed the upgrade authority.
     #[account(
@@ -59,7 +73,8 @@ pub struct Initialize<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Initializes the `Bridge` state account with the provided configs, sets the guardian,
+/// Initializes the `Bridge` state account with the provided configs and the `BaseOracleSigners`
+/// registry with the initial Base oracle signer set, sets the guardian,
 /// starts unpaused, zeros counters, sets the EIP-1559 base fee to `eip1559_config.minimum_base_fee`,
 /// and records the current timestamp as the window start.
 pub fn initialize_handler(ctx: Context<Initialize>, guardian: Pubkey, cfg: Config) -> Result<()> {
@@ -72,7 +87,9 @@ pub fn initialize_handler(ctx: Context<Initialize>, guardian: Pubkey, cfg: Confi
         base_block_number: 0,
         nonce: 0,
         guardian,
-        paused: false, // Initialize bridge as unpaused
+        pending_guardian: Pubkey::default(),
+        roles: cfg.roles,
+        pause: PauseConfig::default(), // Initialize bridge as unpaused
         eip1559: Eip1559 {
             config: cfg.eip1559_config,
             current_base_fee: minimum_base_fee,
@@ -83,7 +100,14 @@ pub fn initialize_handler(ctx: Context<Initialize>, guardian: Pubkey, cfg: Confi
         protocol_config: cfg.protocol_config,
         buffer_config: cfg.buffer_config,
         partner_oracle_config: cfg.partner_oracle_config,
-        base_oracle_config: cfg.base_oracle_config,
+        rate_limited_mints: RateLimitedMints::default(),
+    };
+    // The first epoch covers every block number until the signer set is rotated
+    *ctx.accounts.base_oracle_signers = BaseOracleSigners {
+        epoch: 0,
+        activation_block_number: 0,
+        expiry_block_number: u64::MAX,
+        ..cfg.base_oracle_signers
     };
 
     Ok(())
@@ -103,12 +127,12 @@ mod tests {
 
     use crate::{
         accounts,
-        common::{
-            bridge::{BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig},
-            BaseOracleConfig,
+        base_to_solana::state::{BaseOracleSigner, OracleSignerKey},
+        common::bridge::{
+            BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig, Roles,
         },
         instruction::Initialize,
-        test_utils::{deploy_bridge, mock_clock, DeployBridgeResult},
+        test_utils::{base_oracle_signers_pda, deploy_bridge, mock_clock, DeployBridgeResult},
         ID,
     };
 
@@ -134,6 +158,7 @@ mod tests {
             upgrade_authority: payer_pk,
             payer: payer_pk,
             bridge: bridge_pda,
+            base_oracle_signers: base_oracle_signers_pda(0),
             program_data: program_data_pda,
             program: ID,
             system_program: system_program::ID,
@@ -148,12 +173,16 @@ mod tests {
             data: Initialize {
                 guardian: guardian_pk,
                 cfg: Config {
+                    roles: Roles {
+                        pauser: guardian_pk,
+                        signer_manager: payer_pk,
+                    },
                     eip1559_config: Eip1559Config::test_new(),
                     gas_config: GasConfig::test_new(gas_fee_receiver),
                     protocol_config: ProtocolConfig::test_new(),
                     buffer_config: BufferConfig::test_new(),
                     partner_oracle_config: PartnerOracleConfig::default(),
-                    base_oracle_config: BaseOracleConfig::test_new(),
+                    base_oracle_signers: BaseOracleSigners::test_new(),
                 },
             }
             .data(),
@@ -182,7 +211,12 @@ mod tests {
                 base_block_number: 0,
                 nonce: 0,
                 guardian: guardian_pk,
-                paused: false,
+                pending_guardian: Pubkey::default(),
+                roles: Roles {
+                    pauser: guardian_pk,
+                    signer_manager: payer_pk,
+                },
+                pause: PauseConfig::default(),
                 eip1559: Eip1559 {
                     config: Eip1559Config::test_new(),
                     current_base_fee: 1,
@@ -193,9 +227,18 @@ mod tests {
                 protocol_config: ProtocolConfig::test_new(),
                 buffer_config: BufferConfig::test_new(),
                 partner_oracle_config: PartnerOracleConfig::default(),
-                base_oracle_config: BaseOracleConfig::test_new(),
+                rate_limited_mints: RateLimitedMints::default(),
             }
         );
+
+        // Assert the Base oracle signer registry holds the initial signer set
+        let base_oracle_signers = svm.get_account(&base_oracle_signers_pda(0)).unwrap();
+        assert_eq!(base_oracle_signers.owner, ID);
+        assert_eq!(base_oracle_signers.data.len(), BaseOracleSigners::space(1));
+        assert_eq!(
+            BaseOracleSigners::try_deserialize(&mut &base_oracle_signers.data[..]).unwrap(),
+            BaseOracleSigners::test_new()
+        );
     }
 
     #[test]
@@ -215,6 +258,7 @@ mod tests {
             upgrade_authority: payer_pk,
             payer: payer_pk,
             bridge: bridge_pda,
+            base_oracle_signers: base_oracle_signers_pda(0),
             program_data: program_data_pda,
             program: ID,
             system_program: system_program::ID,
@@ -229,14 +273,16 @@ mod tests {
             data: Initialize {
                 guardian: guardian_pk,
                 cfg: Config {
+                    roles: Roles::default(),
                     eip1559_config: Eip1559Config::test_new(),
                     gas_config: GasConfig::test_new(gas_fee_receiver),
                     protocol_config: ProtocolConfig::test_new(),
                     buffer_config: BufferConfig::test_new(),
                     partner_oracle_config: PartnerOracleConfig {
                         required_threshold: 6,
+                        ..Default::default()
                     },
-                    base_oracle_config: BaseOracleConfig::test_new(),
+                    base_oracle_signers: BaseOracleSigners::test_new(),
                 },
             }
             .data(),
@@ -271,6 +317,7 @@ mod tests {
             upgrade_authority: payer_pk,
             payer: payer_pk,
             bridge: bridge_pda,
+            base_oracle_signers: base_oracle_signers_pda(0),
             program_data: program_data_pda,
             program: ID,
             system_program: system_program::ID,
@@ -279,8 +326,8 @@ mod tests {
 
         // Build the Initialize instruction with an invalid base oracle threshold (== 0)
         let gas_fee_receiver = Pubkey::new_unique();
-        let mut base_oracle_config = BaseOracleConfig::test_new();
-        base_oracle_config.threshold = 0;
+        let mut base_oracle_signers = BaseOracleSigners::test_new();
+        base_oracle_signers.threshold = 0;
 
         let ix = Instruction {
             program_id: ID,
@@ -288,12 +335,13 @@ mod tests {
             data: Initialize {
                 guardian: guardian_pk,
                 cfg: Config {
+                    roles: Roles::default(),
                     eip1559_config: Eip1559Config::test_new(),
                     gas_config: GasConfig::test_new(gas_fee_receiver),
                     protocol_config: ProtocolConfig::test_new(),
                     buffer_config: BufferConfig::test_new(),
                     partner_oracle_config: PartnerOracleConfig::default(),
-                    base_oracle_config,
+                    base_oracle_signers,
                 },
             }
             .data(),
@@ -312,7 +360,7 @@ mod tests {
     }
 
     #[test]
-    fn test_initialize_base_oracle_threshold_gt_signer_count_fails() {
+    fn test_initialize_base_oracle_threshold_gt_total_weight_fails() {
         let DeployBridgeResult {
             mut svm,
             payer,
@@ -328,6 +376,7 @@ mod tests {
             upgrade_authority: payer_pk,
             payer: payer_pk,
             bridge: bridge_pda,
+            base_oracle_signers: base_oracle_signers_pda(0),
             program_data: program_data_pda,
             program: ID,
             system_program: system_program::ID,
@@ -336,8 +385,8 @@ mod tests {
 
         // Build the Initialize instruction with threshold > signer_count
         let gas_fee_receiver = Pubkey::new_unique();
-        let mut base_oracle_config = BaseOracleConfig::test_new();
-        base_oracle_config.threshold = base_oracle_config.signer_count + 1; // 2 > 1
+        let mut base_oracle_signers = BaseOracleSigners::test_new();
+        base_oracle_signers.threshold = base_oracle_signers.total_weight() as u16 + 1; // 2 > 1
 
         let ix = Instruction {
             program_id: ID,
@@ -345,12 +394,13 @@ mod tests {
             data: Initialize {
                 guardian: guardian_pk,
                 cfg: Config {
+                    roles: Roles::default(),
                     eip1559_config: Eip1559Config::test_new(),
                     gas_config: GasConfig::test_new(gas_fee_receiver),
                     protocol_config: ProtocolConfig::test_new(),
                     buffer_config: BufferConfig::test_new(),
                     partner_oracle_config: PartnerOracleConfig::default(),
-                    base_oracle_config,
+                    base_oracle_signers,
                 },
             }
             .data(),
@@ -369,7 +419,7 @@ mod tests {
     }
 
     #[test]
-    fn test_initialize_base_oracle_signer_count_exceeds_array_len_fails() {
+    fn test_initialize_base_oracle_zero_weight_signer_fails() {
         let DeployBridgeResult {
             mut svm,
             payer,
@@ -385,17 +435,21 @@ mod tests {
             upgrade_authority: payer_pk,
             payer: payer_pk,
             bridge: bridge_pda,
+            base_oracle_signers: base_oracle_signers_pda(0),
             program_data: program_data_pda,
             program: ID,
             system_program: system_program::ID,
         }
         .to_account_metas(None);
 
-        // Build the Initialize instruction with signer_count > signers.len()
+        // Build the Initialize instruction with a signer that carries no weight
         let gas_fee_receiver = Pubkey::new_unique();
-        let mut base_oracle_config = BaseOracleConfig::test_new();
-        base_oracle_config.signer_count = (base_oracle_config.signers.len() + 1) as u8; // exceed fixed array length
-        base_oracle_config.threshold = 1; // keep valid threshold
+        let mut base_oracle_signers = BaseOracleSigners::test_new();
+        base_oracle_signers.signers.push(BaseOracleSigner {
+            key: OracleSignerKey::Evm([2u8; 20]),
+            weight: 0,
+        });
+        base_oracle_signers.threshold = 1; // keep valid threshold
 
         let ix = Instruction {
             program_id: ID,
@@ -403,12 +457,13 @@ mod tests {
             data: Initialize {
                 guardian: guardian_pk,
                 cfg: Config {
+                    roles: Roles::default(),
                     eip1559_config: Eip1559Config::test_new(),
                     gas_config: GasConfig::test_new(gas_fee_receiver),
                     protocol_config: ProtocolConfig::test_new(),
                     buffer_config: BufferConfig::test_new(),
                     partner_oracle_config: PartnerOracleConfig::default(),
-                    base_oracle_config,
+                    base_oracle_signers,
                 },
             }
             .data(),
@@ -443,6 +498,7 @@ mod tests {
             upgrade_authority: payer_pk,
             payer: payer_pk,
             bridge: bridge_pda,
+            base_oracle_signers: base_oracle_signers_pda(0),
             program_data: program_data_pda,
             program: ID,
             system_program: system_program::ID,
@@ -451,12 +507,13 @@ mod tests {
 
         // Build the Initialize instruction with duplicate signer addresses among the provided entries
         let gas_fee_receiver = Pubkey::new_unique();
-        let mut base_oracle_config = BaseOracleConfig::test_new();
-        base_oracle_config.signer_count = 2; // consider first two entries
-        base_oracle_config.threshold = 1; // keep valid threshold
+        let mut base_oracle_signers = BaseOracleSigners::test_new();
+        base_oracle_signers.threshold = 1; // keep valid threshold
 
-        // Force a duplicate among the first `signer_count` addresses
-        base_oracle_config.signers[1] = base_oracle_config.signers[0];
+        // Force a duplicate among the provided addresses
+        base_oracle_signers
+            .signers
+            .push(base_oracle_signers.signers[0]);
 
         let ix = Instruction {
             program_id: ID,
@@ -464,12 +521,13 @@ mod tests {
             data: Initialize {
                 guardian: guardian_pk,
                 cfg: Config {
+                    roles: Roles::default(),
                     eip1559_config: Eip1559Config::test_new(),
                     gas_config: GasConfig::test_new(gas_fee_receiver),
                     protocol_config: ProtocolConfig::test_new(),
                     buffer_config: BufferConfig::test_new(),
                     partner_oracle_config: PartnerOracleConfig::default(),
-                    base_oracle_config,
+                    base_oracle_signers,
                 },
             }
             .data(),
@@ -508,6 +566,7 @@ mod tests {
             upgrade_authority: unauthorized.pubkey(), // Wrong upgrade authority
             payer: unauthorized.pubkey(),
             bridge: bridge_pda,
+            base_oracle_signers: base_oracle_signers_pda(0),
             program_data: program_data_pda,
             program: ID,
             system_program: system_program::ID,
@@ -522,12 +581,13 @@ mod tests {
             data: Initialize {
                 guardian: guardian_pk,
                 cfg: Config {
+                    roles: Roles::default(),
                     eip1559_config: Eip1559Config::test_new(),
                     gas_config: GasConfig::test_new(gas_fee_receiver),
                     protocol_config: ProtocolConfig::test_new(),
                     buffer_config: BufferConfig::test_new(),
                     partner_oracle_config: PartnerOracleConfig::default(),
-                    base_oracle_config: BaseOracleConfig::test_new(),
+                    base_oracle_signers: BaseOracleSigners::test_new(),
                 },
             }
             .data(),
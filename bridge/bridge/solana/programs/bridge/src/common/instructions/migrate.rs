@@ -0,0 +1,343 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+    Discriminator,
+};
+
+use crate::{
+    base_to_solana::{
+        constants::BASE_ORACLE_SIGNERS_SEED,
+        state::{BaseOracleSigner, BaseOracleSigners, OracleSignerKey},
+    },
+    common::{
+        bridge::{
+            AttestationConfig, Bridge, BufferConfig, Eip1559, GasConfig, PartnerOracleConfig,
+            PauseConfig, ProtocolConfig, RateLimitedMints, Roles,
+        },
+        BRIDGE_SEED, DISCRIMINATOR_LEN, MAX_SIGNER_COUNT, MIGRATED_CONFIG_CHANGE_DELAY_SECONDS,
+        PAUSE_ALL,
+    },
+    program::Bridge as BridgeProgram,
+    BridgeError,
+};
+
+/// Layout of the `Bridge` account before the single `paused` flag was replaced by `PauseConfig`,
+/// and before the config change timelock, the two-step guardian handover, the role registry,
+/// the output root retention horizon, the output root challenge window, EIP-712 attestations
+/// and weighted oracle signers were introduced, and before the Base oracle signers moved to the
+/// `BaseOracleSigners` account.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyBridge {
+    pub base_block_number: u64,
+    pub nonce: u64,
+    pub guardian: Pubkey,
+    pub paused: bool,
+    pub eip1559: Eip1559,
+    pub gas_config: GasConfig,
+    pub protocol_config: LegacyProtocolConfig,
+    pub buffer_config: BufferConfig,
+    pub partner_oracle_config: LegacyPartnerOracleConfig,
+    pub base_oracle_config: LegacyBaseOracleConfig,
+}
+
+/// Layout of `ProtocolConfig` in `LegacyBridge`.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyProtocolConfig {
+    pub block_interval_requirement: u64,
+    pub remote_sol_address: [u8; 20],
+}
+
+/// Layout of `PartnerOracleConfig` in `LegacyBridge`.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyPartnerOracleConfig {
+    pub required_threshold: u8,
+}
+
+/// Layout of `BaseOracleConfig` in `LegacyBridge`.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct LegacyBaseOracleConfig {
+    pub threshold: u8,
+    pub signer_count: u8,
+    pub signers: [[u8; 20]; MAX_SIGNER_COUNT as usize],
+}
+
+impl From<LegacyBridge> for Bridge {
+    fn from(legacy: LegacyBridge) -> Self {
+        Self {
+            base_block_number: legacy.base_block_number,
+            nonce: legacy.nonce,
+            guardian: legacy.guardian,
+            pending_guardian: Pubkey::default(),
+            // The legacy guardian could pause; the signer manager must be assigned by the upgrade authority
+            roles: Roles {
+                pauser: legacy.guardian,
+                signer_manager: Pubkey::default(),
+            },
+            // A paused legacy bridge stays fully paused
+            pause: PauseConfig {
+                flags: if legacy.paused { PAUSE_ALL } else { 0 },
+                ..Default::default()
+            },
+            eip1559: legacy.eip1559,
+            gas_config: legacy.gas_config,
+            protocol_config: ProtocolConfig {
+                block_interval_requirement: legacy.protocol_config.block_interval_requirement,
+                remote_sol_address: legacy.protocol_config.remote_sol_address,
+                // Legacy bridges applied config changes immediately; migrated ones start timelocked
+                config_change_delay_seconds: MIGRATED_CONFIG_CHANGE_DELAY_SECONDS,
+                // Output roots are kept until the guardian opts into pruning
+                output_root_retention_blocks: 0,
+                // Legacy output roots were provable as soon as they were registered
+                root_finality_seconds: 0,
+                // Legacy oracles sign with the EIP-191 format
+                attestation: AttestationConfig::default(),
+                // Bouncing unrelayed messages is opt-in
+                bounce_delay_seconds: 0,
+            },
+            buffer_config: legacy.buffer_config,
+            // Legacy signers all had the same weight
+            partner_oracle_config: PartnerOracleConfig {
+                required_threshold: legacy.partner_oracle_config.required_threshold,
+                ..Default::default()
+            },
+            // Legacy bridges had no withdrawal rate limits
+            rate_limited_mints: RateLimitedMints::default(),
+        }
+    }
+}
+
+impl From<&LegacyBaseOracleConfig> for BaseOracleSigners {
+    fn from(legacy: &LegacyBaseOracleConfig) -> Self {
+        let signer_count = core::cmp::min(legacy.signer_count as usize, legacy.signers.len());
+        Self {
+            epoch: 0,
+            activation_block_number: 0,
+            expiry_block_number: u64::MAX,
+            threshold: legacy.threshold as u16,
+            // Legacy signers all had the same weight
+            signers: legacy.signers[..signer_count]
+                .iter()
+                .map(|evm_address| BaseOracleSigner {
+                    key: OracleSignerKey::Evm(*evm_address),
+                    weight: 1,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Accounts for migrating a `Bridge` account created with the legacy layout to the current one.
+/// Only the upgrade authority can migrate the bridge; it also funds the additional rent.
+#[derive(Accounts)]
+pub struct MigrateBridge<'info> {
+    /// The upgrade authority that is authorized to migrate the bridge.
+    /// Pays for the rent of the extra bytes allocated to the bridge account.
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+
+    /// The bridge state account, still serialized with the legacy layout.
+    /// CHECK: Deserialized manually in the handler as it does not match the current `Bridge` layout.
+    #[account(mut, seeds = [BRIDGE_SEED], bump, owner = crate::ID)]
+    pub bridge: UncheckedAccount<'info>,
+
+    /// The first Base oracle signer epoch, created from the signers held by the legacy bridge.
+    /// Legacy bridges held at most `MAX_SIGNER_COUNT` signers.
+    #[account(
+        init,
+        payer = upgrade_authority,
+        seeds = [BASE_ORACLE_SIGNERS_SEED, &0u64.to_le_bytes()],
+        bump,
+        space = BaseOracleSigners::space(MAX_SIGNER_COUNT as usize)
+    )]
+    pub base_oracle_signers: Account<'info, BaseOracleSigners>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key()) @ BridgeError::UnauthorizedConfigUpdate)]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ BridgeError::IncorrectBridgeProgram)]
+    pub program: Program<'info, BridgeProgram>,
+
+    /// System program required for transferring the additional rent and creating the registry.
+    pub system_program: Program<'info, System>,
+}
+
+/// Reallocates the bridge account to the current `Bridge` size and rewrites it in the new layout.
+/// The legacy `paused` flag maps to all instruction families being paused, and the legacy Base
+/// oracle signers are moved to the `BaseOracleSigners` registry.
+pub fn migrate_bridge_handler(ctx: Context<MigrateBridge>) -> Result<()> {
+    let bridge_info = ctx.accounts.bridge.to_account_info();
+    let new_len = DISCRIMINATOR_LEN + Bridge::INIT_SPACE;
+
+    require!(
+        bridge_info.data_len() < new_len,
+        BridgeError::BridgeAlreadyMigrated
+    );
+
+    let legacy = {
+        let data = bridge_info.try_borrow_data()?;
+        require!(
+            data.starts_with(Bridge::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        LegacyBridge::deserialize(&mut &data[DISCRIMINATOR_LEN..])?
+    };
+    *ctx.accounts.base_oracle_signers = (&legacy.base_oracle_config).into();
+    let bridge: Bridge = legacy.into();
+
+    // Top up the rent for the larger account
+    let rent_delta = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(bridge_info.lamports());
+    if rent_delta > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.upgrade_authority.to_account_info(),
+                to: bridge_info.clone(),
+            },
+        );
+        system_program::transfer(cpi_ctx, rent_delta)?;
+    }
+
+    bridge_info.realloc(new_len, true)?;
+    bridge.try_serialize(&mut &mut bridge_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::MigrateBridge as MigrateBridgeIx,
+        test_utils::{base_oracle_signers_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn send_migrate(svm: &mut litesvm::LiteSVM, payer: &solana_keypair::Keypair) -> bool {
+        let accounts = accounts::MigrateBridge {
+            upgrade_authority: payer.pubkey(),
+            bridge: Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0,
+            base_oracle_signers: base_oracle_signers_pda(0),
+            program_data: Pubkey::find_program_address(
+                &[ID.as_ref()],
+                &anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+            )
+            .0,
+            program: ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: MigrateBridgeIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).is_ok()
+    }
+
+    #[test]
+    fn test_migrate_bridge_from_legacy_layout() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Rewrite the bridge account with the legacy layout (paused)
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        let registry_account = svm.get_account(&base_oracle_signers_pda(0)).unwrap();
+        let registry = BaseOracleSigners::try_deserialize(&mut &registry_account.data[..]).unwrap();
+        let mut legacy_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        for (i, signer) in registry.signers.iter().enumerate() {
+            let OracleSignerKey::Evm(evm_address) = signer.key else {
+                panic!("legacy registry only holds EVM signers");
+            };
+            legacy_signers[i] = evm_address;
+        }
+        let legacy = LegacyBridge {
+            base_block_number: bridge.base_block_number,
+            nonce: bridge.nonce,
+            guardian: bridge.guardian,
+            paused: true,
+            eip1559: bridge.eip1559.clone(),
+            gas_config: bridge.gas_config.clone(),
+            protocol_config: LegacyProtocolConfig {
+                block_interval_requirement: bridge.protocol_config.block_interval_requirement,
+                remote_sol_address: bridge.protocol_config.remote_sol_address,
+            },
+            buffer_config: bridge.buffer_config.clone(),
+            partner_oracle_config: LegacyPartnerOracleConfig {
+                required_threshold: bridge.partner_oracle_config.required_threshold,
+            },
+            base_oracle_config: LegacyBaseOracleConfig {
+                threshold: registry.threshold as u8,
+                signer_count: registry.signers.len() as u8,
+                signers: legacy_signers,
+            },
+        };
+        let mut legacy_data = Bridge::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut legacy_data).unwrap();
+        bridge_account.lamports = svm.minimum_balance_for_rent_exemption(legacy_data.len());
+        bridge_account.data = legacy_data;
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+
+        // Legacy bridges had no signer registry
+        svm.set_account(base_oracle_signers_pda(0), Default::default())
+            .unwrap();
+
+        assert!(send_migrate(&mut svm, &payer), "migration should succeed");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        assert_eq!(
+            bridge_account.data.len(),
+            DISCRIMINATOR_LEN + Bridge::INIT_SPACE
+        );
+        let migrated = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(
+            migrated,
+            Bridge {
+                roles: Roles {
+                    pauser: bridge.guardian,
+                    signer_manager: Pubkey::default(),
+                },
+                pause: PauseConfig {
+                    flags: PAUSE_ALL,
+                    ..Default::default()
+                },
+                protocol_config: ProtocolConfig {
+                    config_change_delay_seconds: MIGRATED_CONFIG_CHANGE_DELAY_SECONDS,
+                    ..bridge.protocol_config.clone()
+                },
+                ..bridge
+            }
+        );
+
+        let registry_account = svm.get_account(&base_oracle_signers_pda(0)).unwrap();
+        assert_eq!(
+            BaseOracleSigners::try_deserialize(&mut &registry_account.data[..]).unwrap(),
+            registry
+        );
+
+        // A second migration is rejected
+        svm.expire_blockhash();
+        assert!(!send_migrate(&mut svm, &payer));
+    }
+}
@@ -2,6 +2,10 @@ pub mod initialize;
 pub use initialize::*;
 
 pub mod guardian;
+pub use guardian::*;
+
+pub mod migrate;
+pub use migrate::*;
 
 pub mod config;
 pub use config::*;
@@ -1,11 +1,14 @@
 use anchor_lang::prelude::*;
 
+use crate::base_to_solana::state::BaseOracleSigners;
 use crate::common::{
-    BaseOracleConfig, BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig,
+    BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig, Roles,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
 pub struct Config {
+    /// Keys holding the pauser and signer manager roles
+    pub roles: Roles,
     /// Configuration parameters for EIP-1559-inspired fee calculations
     pub eip1559_config: Eip1559Config,
     /// Configuration parameters for outgoing message pricing
@@ -16,8 +19,8 @@ pub struct Config {
     pub buffer_config: BufferConfig,
     /// Partner oracle configuration containing the required signature threshold
     pub partner_oracle_config: PartnerOracleConfig,
-    /// Configuration parameters for Base oracle signers
-    pub base_oracle_config: BaseOracleConfig,
+    /// Initial Base oracle signer set, stored in the `BaseOracleSigners` account
+    pub base_oracle_signers: BaseOracleSigners,
 }
 
 impl Config {
@@ -26,7 +29,7 @@ impl Config {
         self.gas_config.validate()?;
         self.protocol_config.validate()?;
         self.partner_oracle_config.validate()?;
-        self.base_oracle_config.validate()?;
+        self.base_oracle_signers.validate()?;
         Ok(())
     }
 }
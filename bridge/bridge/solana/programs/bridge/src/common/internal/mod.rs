@@ -1,6 +1,8 @@
 pub mod init_config;
 pub mod math;
 pub mod metadata;
+pub mod pda;
 
 pub use init_config::*;
 pub use metadata::*;
+pub use pda::*;
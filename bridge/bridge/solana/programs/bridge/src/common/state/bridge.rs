@@ -2,7 +2,9 @@ use anchor_lang::prelude::*;
 
 use crate::common::{
     internal::math::{fixed_pow, SCALE},
-    MAX_PARTNER_VALIDATOR_THRESHOLD, MAX_SIGNER_COUNT,
+    MAX_CONFIG_CHANGE_DELAY_SECONDS, MAX_PARTNER_SIGNER_WEIGHT_COUNT,
+    MAX_PARTNER_VALIDATOR_THRESHOLD, MAX_PAUSED_MINT_COUNT, MAX_RATE_LIMITED_MINT_COUNT,
+    MAX_ROOT_FINALITY_SECONDS, MIN_BOUNCE_DELAY_SECONDS, PAUSE_ALL,
 };
 use crate::BridgeError;
 
@@ -15,8 +17,13 @@ pub struct Bridge {
     pub nonce: u64,
     /// Guardian pubkey authorized to update bridge configuration parameters
     pub guardian: Pubkey,
-    /// Whether the bridge is paused (emergency stop mechanism)
-    pub paused: bool,
+    /// Guardian proposed by the current guardian; must accept before taking over.
+    /// `Pubkey::default()` when no handover is in progress.
+    pub pending_guardian: Pubkey,
+    /// Keys holding the narrower operational roles, assigned by the upgrade authority
+    pub roles: Roles,
+    /// Per instruction family and per mint pause state (emergency stop mechanism)
+    pub pause: PauseConfig,
     /// EIP-1559 state and configuration for dynamic pricing.
     pub eip1559: Eip1559,
     /// Configuration parameters for outgoing message pricing
@@ -27,8 +34,110 @@ pub struct Bridge {
     pub buffer_config: BufferConfig,
     /// Partner oracle configuration containing the required signature threshold
     pub partner_oracle_config: PartnerOracleConfig,
-    /// Configuration parameters for Base oracle signers
-    pub base_oracle_config: BaseOracleConfig,
+    /// Mints with a withdrawal rate limit; relaying their transfers requires the `MintRateLimit` account
+    pub rate_limited_mints: RateLimitedMints,
+}
+
+/// Operational roles split off from the guardian, which keeps authority over fee, protocol and
+/// buffer configuration. A role set to `Pubkey::default()` is unassigned.
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct Roles {
+    /// Key allowed to pause instruction families and mints. Lifting a pause requires the guardian.
+    pub pauser: Pubkey,
+    /// Key allowed to update the Base oracle signer set in the `BaseOracleSigners` account
+    pub signer_manager: Pubkey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct PauseConfig {
+    /// Bitmask of paused instruction families (see the `PAUSE_*` constants)
+    pub flags: u16,
+    /// Number of mints in `mints` array
+    pub mint_count: u8,
+    /// Mints for which both outgoing and incoming transfers are paused
+    pub mints: [Pubkey; MAX_PAUSED_MINT_COUNT as usize],
+}
+
+impl PauseConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.flags & !PAUSE_ALL == 0, BridgeError::InvalidPauseFlags);
+        Ok(())
+    }
+
+    /// Fail with `BridgePaused` if any of the given instruction families is paused
+    pub fn require_not_paused(&self, flags: u16) -> Result<()> {
+        require!(self.flags & flags == 0, BridgeError::BridgePaused);
+        Ok(())
+    }
+
+    /// Fail with `MintPaused` if transfers of `mint` are paused
+    pub fn require_mint_not_paused(&self, mint: &Pubkey) -> Result<()> {
+        require!(!self.is_mint_paused(mint), BridgeError::MintPaused);
+        Ok(())
+    }
+
+    pub fn is_mint_paused(&self, mint: &Pubkey) -> bool {
+        let active_len = core::cmp::min(self.mint_count as usize, self.mints.len());
+        self.mints[..active_len].iter().any(|m| m == mint)
+    }
+
+    /// Add or remove `mint` from the paused mints list
+    pub fn set_mint_paused(&mut self, mint: Pubkey, paused: bool) -> Result<()> {
+        let active_len = core::cmp::min(self.mint_count as usize, self.mints.len());
+        let position = self.mints[..active_len].iter().position(|m| *m == mint);
+
+        match (position, paused) {
+            (None, true) => {
+                require!(
+                    active_len < self.mints.len(),
+                    BridgeError::TooManyPausedMints
+                );
+                self.mints[active_len] = mint;
+                self.mint_count += 1;
+            }
+            (Some(index), false) => {
+                // Swap-remove to keep the active entries contiguous
+                self.mints[index] = self.mints[active_len - 1];
+                self.mints[active_len - 1] = Pubkey::default();
+                self.mint_count -= 1;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct RateLimitedMints {
+    /// Number of mints in `mints` array
+    pub mint_count: u8,
+    /// Mints for which a `MintRateLimit` account has been configured
+    pub mints: [Pubkey; MAX_RATE_LIMITED_MINT_COUNT as usize],
+}
+
+impl RateLimitedMints {
+    pub fn contains(&self, mint: &Pubkey) -> bool {
+        let active_len = core::cmp::min(self.mint_count as usize, self.mints.len());
+        self.mints[..active_len].iter().any(|m| m == mint)
+    }
+
+    /// Record that `mint` is rate limited. Limits are never removed, only reconfigured.
+    pub fn add(&mut self, mint: Pubkey) -> Result<()> {
+        if self.contains(&mint) {
+            return Ok(());
+        }
+
+        let active_len = core::cmp::min(self.mint_count as usize, self.mints.len());
+        require!(
+            active_len < self.mints.len(),
+            BridgeError::TooManyRateLimitedMints
+        );
+        self.mints[active_len] = mint;
+        self.mint_count += 1;
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
@@ -190,6 +299,27 @@ pub struct ProtocolConfig {
 
     /// The Base evm address of SOL
     pub remote_sol_address: [u8; 20],
+
+    /// Delay between queuing and executing a config change through the timelock. While non-zero,
+    /// config setters (other than pausing) are disabled and changes must be queued instead.
+    /// Zero disables the timelock: setters, including the oracle signer, epoch and CPI policy
+    /// ones, then apply immediately. Migrated bridges start at `MIGRATED_CONFIG_CHANGE_DELAY_SECONDS`.
+    pub config_change_delay_seconds: u64,
+
+    /// Number of Base blocks an output root is kept after the latest registered one before it can
+    /// be closed. Zero disables closing output roots.
+    pub output_root_retention_blocks: u64,
+
+    /// Delay after registration before messages can be proven against an output root. During this
+    /// challenge window the guardian or a quorum of partner signers can invalidate the root.
+    pub root_finality_seconds: u64,
+
+    /// Format of the oracle attestations accepted for output roots.
+    pub attestation: AttestationConfig,
+
+    /// Delay after a message is proven before it can be bounced back to its Base sender if it
+    /// has not been relayed. Zero disables bouncing.
+    pub bounce_delay_seconds: u64,
 }
 
 impl ProtocolConfig {
@@ -208,10 +338,92 @@ impl ProtocolConfig {
             self.remote_sol_address != [0u8; 20],
             BridgeError::ZeroAddress
         );
+
+        require!(
+            self.config_change_delay_seconds <= MAX_CONFIG_CHANGE_DELAY_SECONDS,
+            BridgeError::InvalidConfigChangeDelay
+        );
+
+        require!(
+            self.root_finality_seconds <= MAX_ROOT_FINALITY_SECONDS,
+            BridgeError::InvalidRootFinalityDelay
+        );
+
+        // A short delay would let anyone bounce messages before relayers get to execute them
+        require!(
+            self.bounce_delay_seconds == 0 || self.bounce_delay_seconds >= MIN_BOUNCE_DELAY_SECONDS,
+            BridgeError::InvalidBounceDelay
+        );
+
+        self.attestation.validate()?;
+        Ok(())
+    }
+
+    /// Whether the output root registered at `base_block_number` can be closed given the latest
+    /// registered Base block number. The latest root is never closable.
+    pub fn is_output_root_expired(&self, base_block_number: u64, latest_block_number: u64) -> bool {
+        self.output_root_retention_blocks > 0
+            && base_block_number < latest_block_number
+            && base_block_number.saturating_add(self.output_root_retention_blocks)
+                <= latest_block_number
+    }
+
+    /// Fail if config changes must go through the timelock
+    pub fn require_no_config_timelock(&self) -> Result<()> {
+        require!(
+            self.config_change_delay_seconds == 0,
+            BridgeError::ConfigChangeTimelocked
+        );
         Ok(())
     }
 }
 
+/// Message format oracles sign output root attestations with.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default,
+)]
+pub enum AttestationFormat {
+    /// EIP-191 `personal_sign` over `root || block_number || leaf_count`
+    #[default]
+    Legacy,
+    /// Both formats are accepted while the oracle signers migrate
+    LegacyAndEip712,
+    /// EIP-712 typed data bound to the Base chain, the Solana cluster and this program
+    Eip712,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct AttestationConfig {
+    /// Accepted attestation format(s)
+    pub format: AttestationFormat,
+
+    /// Base chain id included in the EIP-712 domain
+    pub base_chain_id: u64,
+
+    /// Genesis hash of the Solana cluster the bridge is deployed on, included in the EIP-712 domain
+    pub solana_genesis_hash: [u8; 32],
+}
+
+impl AttestationConfig {
+    pub fn validate(&self) -> Result<()> {
+        if self.accepts_eip712() {
+            require!(
+                self.base_chain_id != 0 && self.solana_genesis_hash != [0u8; 32],
+                BridgeError::InvalidAttestationDomain
+            );
+        }
+        Ok(())
+    }
+
+    pub fn accepts_legacy(&self) -> bool {
+        self.format != AttestationFormat::Eip712
+    }
+
+    pub fn accepts_eip712(&self) -> bool {
+        self.format != AttestationFormat::Legacy
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
 pub struct BufferConfig {
     /// Maximum call buffer size. This caps the max size of a Solana → Base message.
@@ -220,8 +432,22 @@ pub struct BufferConfig {
 
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
 pub struct PartnerOracleConfig {
-    /// Partner signatures required by our bridge to accept an output root
+    /// Total partner signer weight required by our bridge to accept an output root
     pub required_threshold: u8,
+    /// Number of entries in `weights` array
+    pub weight_count: u8,
+    /// Weights of partner signers that do not count as 1, keyed by their `evm_address`
+    pub weights: [PartnerSignerWeight; MAX_PARTNER_SIGNER_WEIGHT_COUNT as usize],
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default,
+)]
+pub struct PartnerSignerWeight {
+    /// Primary EVM address of the partner signer
+    pub evm_address: [u8; 20],
+    /// Weight of the signer's approval
+    pub weight: u8,
 }
 
 impl PartnerOracleConfig {
@@ -230,56 +456,33 @@ impl PartnerOracleConfig {
             self.required_threshold <= MAX_PARTNER_VALIDATOR_THRESHOLD,
             BridgeError::InvalidPartnerThreshold
         );
-        Ok(())
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
-pub struct BaseOracleConfig {
-    /// Number of required valid unique signatures
-    pub threshold: u8,
-    /// Number of signers in `signers` array
-    pub signer_count: u8,
-    /// Static list of authorized signer addresses
-    pub signers: [[u8; 20]; MAX_SIGNER_COUNT as usize],
-}
-
-impl BaseOracleConfig {
-    pub fn validate(&self) -> Result<()> {
         require!(
-            self.threshold > 0 && self.threshold <= self.signer_count,
-            BridgeError::InvalidThreshold
+            self.weight_count as usize <= self.weights.len(),
+            BridgeError::TooManySigners
         );
+
+        let weights = &self.weights[..self.weight_count as usize];
         require!(
-            self.signer_count as usize <= self.signers.len(),
-            BridgeError::TooManySigners
+            weights.iter().all(|w| w.weight > 0),
+            BridgeError::InvalidSignerWeight
         );
 
-        // Ensure uniqueness among the provided signer_count entries
-        {
-            let provided_count = self.signer_count as usize;
-            let mut addrs: Vec<[u8; 20]> = self.signers[..provided_count].to_vec();
-            addrs.sort();
-            addrs.dedup();
-            require!(addrs.len() == provided_count, BridgeError::DuplicateSigner);
-        }
+        // Ensure uniqueness among the provided weight_count entries
+        let mut addrs: Vec<[u8; 20]> = weights.iter().map(|w| w.evm_address).collect();
+        addrs.sort();
+        addrs.dedup();
+        require!(addrs.len() == weights.len(), BridgeError::DuplicateSigner);
 
         Ok(())
     }
 
-    pub fn contains(&self, evm_addr: &[u8; 20]) -> bool {
-        let active_len = core::cmp::min(self.signer_count as usize, self.signers.len());
-        self.signers[..active_len].iter().any(|s| s == evm_addr)
-    }
-
-    pub fn count_approvals(&self, signers: &[[u8; 20]]) -> u32 {
-        let mut count: u32 = 0;
-        for signer in signers.iter() {
-            if self.contains(signer) {
-                count += 1;
-            }
-        }
-        count
+    /// Weight of the partner signer with the given primary address (1 unless configured)
+    pub fn weight_of(&self, evm_addr: &[u8; 20]) -> u8 {
+        let active_len = core::cmp::min(self.weight_count as usize, self.weights.len());
+        self.weights[..active_len]
+            .iter()
+            .find(|w| w.evm_address == *evm_addr)
+            .map_or(1, |w| w.weight)
     }
 }
 
@@ -287,6 +490,72 @@ impl BaseOracleConfig {
 mod tests {
     use super::*;
 
+    use crate::common::{PAUSE_BRIDGE_SOL, PAUSE_RELAY_MESSAGE};
+
+    #[test]
+    fn test_pause_flags() {
+        let pause = PauseConfig {
+            flags: PAUSE_RELAY_MESSAGE,
+            ..Default::default()
+        };
+
+        assert!(pause.require_not_paused(PAUSE_BRIDGE_SOL).is_ok());
+        assert_eq!(
+            pause.require_not_paused(PAUSE_RELAY_MESSAGE).unwrap_err(),
+            BridgeError::BridgePaused.into()
+        );
+
+        assert!(pause.validate().is_ok());
+        let invalid = PauseConfig {
+            flags: PAUSE_ALL + 1,
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_set_mint_paused() {
+        let mut pause = PauseConfig::default();
+        let mint_a = Pubkey::new_unique();
+        let mint_b = Pubkey::new_unique();
+
+        pause.set_mint_paused(mint_a, true).unwrap();
+        pause.set_mint_paused(mint_b, true).unwrap();
+        // Pausing twice is a no-op
+        pause.set_mint_paused(mint_a, true).unwrap();
+        assert_eq!(pause.mint_count, 2);
+        assert!(pause.is_mint_paused(&mint_a));
+        assert_eq!(
+            pause.require_mint_not_paused(&mint_b).unwrap_err(),
+            BridgeError::MintPaused.into()
+        );
+
+        pause.set_mint_paused(mint_a, false).unwrap();
+        assert_eq!(pause.mint_count, 1);
+        assert!(!pause.is_mint_paused(&mint_a));
+        assert!(pause.is_mint_paused(&mint_b));
+        assert_eq!(pause.mints[1], Pubkey::default());
+
+        // Unpausing a mint that is not paused is a no-op
+        pause.set_mint_paused(mint_a, false).unwrap();
+        assert_eq!(pause.mint_count, 1);
+    }
+
+    #[test]
+    fn test_set_mint_paused_full() {
+        let mut pause = PauseConfig::default();
+        for _ in 0..MAX_PAUSED_MINT_COUNT {
+            pause.set_mint_paused(Pubkey::new_unique(), true).unwrap();
+        }
+
+        assert_eq!(
+            pause
+                .set_mint_paused(Pubkey::new_unique(), true)
+                .unwrap_err(),
+            BridgeError::TooManyPausedMints.into()
+        );
+    }
+
     #[test]
     fn test_new_state_creation() {
         let timestamp = 1234567890;
@@ -479,4 +748,36 @@ mod tests {
         assert_eq!(state.current_window_gas_used, 0);
         assert_eq!(state.window_start_time, new_time);
     }
+
+    #[test]
+    fn test_partner_oracle_weights() {
+        let mut cfg = PartnerOracleConfig {
+            required_threshold: 3,
+            ..Default::default()
+        };
+        cfg.weights[0] = PartnerSignerWeight {
+            evm_address: [1u8; 20],
+            weight: 2,
+        };
+        cfg.weight_count = 1;
+        assert!(cfg.validate().is_ok());
+        assert_eq!(cfg.weight_of(&[1u8; 20]), 2);
+        assert_eq!(cfg.weight_of(&[2u8; 20]), 1);
+
+        cfg.weights[1] = cfg.weights[0];
+        cfg.weight_count = 2;
+        assert_eq!(
+            cfg.validate().unwrap_err(),
+            BridgeError::DuplicateSigner.into()
+        );
+
+        cfg.weights[1] = PartnerSignerWeight {
+            evm_address: [2u8; 20],
+            weight: 0,
+        };
+        assert_eq!(
+            cfg.validate().unwrap_err(),
+            BridgeError::InvalidSignerWeight.into()
+        );
+    }
 }
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::state::{
+    BaseOracleSigner, BaseOracleSigners, CpiPolicyConfig, OracleSignerKey, RateLimitConfig,
+};
+use crate::common::bridge::{AttestationConfig, Bridge, PartnerOracleConfig};
+
+/// A configuration change scheduled through the timelock.
+///
+/// Created by `queue_config_change` (or its signer manager and upgrade authority counterparts)
+/// and applied by
+/// `execute_config_change` once `eta` has passed. The guardian can cancel it at any time before.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct PendingConfigChange {
+    /// The account that queued the change and funded this account; receives the rent back
+    pub proposer: Pubkey,
+    /// Unix timestamp after which the change can be executed
+    pub eta: i64,
+    /// The change to apply to the bridge configuration
+    pub change: ConfigChange,
+}
+
+/// Configuration parameters that can be changed through the timelock.
+///
+/// Each variant mirrors one of the direct setter instructions.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub enum ConfigChange {
+    MinimumBaseFee(u64),
+    WindowDuration(u64),
+    GasTarget(u64),
+    AdjustmentDenominator(u64),
+    GasCostScaler(u64),
+    GasCostScalerDp(u64),
+    GasFeeReceiver(Pubkey),
+    GasPerCall(u64),
+    BlockIntervalRequirement(u64),
+    TimelockDelay(u64),
+    OutputRootRetention(u64),
+    RootFinality(u64),
+    BounceDelay(u64),
+    MaxCallBufferSize(u64),
+    /// Requires the signer manager to be queued
+    BaseOracleThreshold(u16),
+    /// Requires the signer manager to be queued
+    AddBaseOracleSigner(BaseOracleSigner),
+    /// Requires the signer manager to be queued
+    RemoveBaseOracleSigner(OracleSignerKey),
+    /// Requires the signer manager to be queued
+    ReplaceBaseOracleSigner {
+        key: OracleSignerKey,
+        signer: BaseOracleSigner,
+    },
+    /// Requires the upgrade authority to be queued
+    PartnerOracleConfig(PartnerOracleConfig),
+    /// Requires the upgrade authority to be queued
+    AttestationConfig(AttestationConfig),
+    /// Applied to the withdrawal rate limit account of `mint`
+    MintRateLimit {
+        mint: Pubkey,
+        cfg: RateLimitConfig,
+    },
+    /// Requires the signer manager to be queued; creates the next Base oracle signer epoch
+    StartBaseOracleEpoch {
+        activation_block_number: u64,
+        overlap_blocks: u64,
+    },
+    /// Applied to the CPI policy account, which is created if the guardian never configured it
+    CpiPolicy(CpiPolicyConfig),
+}
+
+/// The role allowed to queue a config change, matching the role of its direct setter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigChangeAuthority {
+    Guardian,
+    SignerManager,
+    UpgradeAuthority,
+}
+
+impl ConfigChange {
+    /// The role that must queue the change
+    pub fn authority(&self) -> ConfigChangeAuthority {
+        match self {
+            ConfigChange::BaseOracleThreshold(_)
+            | ConfigChange::AddBaseOracleSigner(_)
+            | ConfigChange::RemoveBaseOracleSigner(_)
+            | ConfigChange::ReplaceBaseOracleSigner { .. }
+            | ConfigChange::StartBaseOracleEpoch { .. } => ConfigChangeAuthority::SignerManager,
+            ConfigChange::PartnerOracleConfig(_) | ConfigChange::AttestationConfig(_) => {
+                ConfigChangeAuthority::UpgradeAuthority
+            }
+            _ => ConfigChangeAuthority::Guardian,
+        }
+    }
+
+    /// Whether the change applies to the `BaseOracleSigners` registry rather than the bridge
+    pub fn updates_base_oracle_signers(&self) -> bool {
+        matches!(
+            self,
+            ConfigChange::BaseOracleThreshold(_)
+                | ConfigChange::AddBaseOracleSigner(_)
+                | ConfigChange::RemoveBaseOracleSigner(_)
+                | ConfigChange::ReplaceBaseOracleSigner { .. }
+        )
+    }
+
+    /// Apply the change to the Base oracle signer registry, validating the resulting signer set
+    pub fn apply_to_base_oracle_signers(&self, signers: &mut BaseOracleSigners) -> Result<()> {
+        match self {
+            ConfigChange::BaseOracleThreshold(v) => signers.threshold = *v,
+            ConfigChange::AddBaseOracleSigner(signer) => signers.add(*signer)?,
+            ConfigChange::RemoveBaseOracleSigner(key) => signers.remove(key)?,
+            ConfigChange::ReplaceBaseOracleSigner { key, signer } => {
+                signers.replace(key, *signer)?
+            }
+            _ => {}
+        }
+
+        signers.validate()
+    }
+
+    /// Apply the change to the bridge, validating the resulting configuration
+    pub fn apply(&self, bridge: &mut Bridge) -> Result<()> {
+        match self {
+            ConfigChange::MinimumBaseFee(v) => bridge.eip1559.config.minimum_base_fee = *v,
+            ConfigChange::WindowDuration(v) => bridge.eip1559.config.window_duration_seconds = *v,
+            ConfigChange::GasTarget(v) => bridge.eip1559.config.target = *v,
+            ConfigChange::AdjustmentDenominator(v) => bridge.eip1559.config.denominator = *v,
+            ConfigChange::GasCostScaler(v) => bridge.gas_config.gas_cost_scaler = *v,
+            ConfigChange::GasCostScalerDp(v) => bridge.gas_config.gas_cost_scaler_dp = *v,
+            ConfigChange::GasFeeReceiver(v) => bridge.gas_config.gas_fee_receiver = *v,
+            ConfigChange::GasPerCall(v) => bridge.gas_config.gas_per_call = *v,
+            ConfigChange::BlockIntervalRequirement(v) => {
+                bridge.protocol_config.block_interval_requirement = *v
+            }
+            ConfigChange::TimelockDelay(v) => {
+                bridge.protocol_config.config_change_delay_seconds = *v
+            }
+            ConfigChange::OutputRootRetention(v) => {
+                bridge.protocol_config.output_root_retention_blocks = *v
+            }
+            ConfigChange::RootFinality(v) => bridge.protocol_config.root_finality_seconds = *v,
+            ConfigChange::BounceDelay(v) => bridge.protocol_config.bounce_delay_seconds = *v,
+            ConfigChange::MaxCallBufferSize(v) => bridge.buffer_config.max_call_buffer_size = *v,
+            ConfigChange::BaseOracleThreshold(_)
+            | ConfigChange::AddBaseOracleSigner(_)
+            | ConfigChange::RemoveBaseOracleSigner(_)
+            | ConfigChange::ReplaceBaseOracleSigner { .. } => {}
+            ConfigChange::PartnerOracleConfig(cfg) => bridge.partner_oracle_config = cfg.clone(),
+            ConfigChange::AttestationConfig(cfg) => {
+                bridge.protocol_config.attestation = cfg.clone()
+            }
+            // Applied to the rate limit account by `execute_config_change`
+            ConfigChange::MintRateLimit { cfg, .. } => cfg.validate()?,
+            // Applied to the Base oracle signer epochs by `execute_config_change`
+            ConfigChange::StartBaseOracleEpoch { .. } => {}
+            // Applied to the CPI policy account by `execute_config_change`
+            ConfigChange::CpiPolicy(cfg) => cfg.validate()?,
+        }
+
+        bridge.eip1559.config.validate()?;
+        bridge.gas_config.validate()?;
+        bridge.protocol_config.validate()?;
+        bridge.partner_oracle_config.validate()?;
+
+        Ok(())
+    }
+}
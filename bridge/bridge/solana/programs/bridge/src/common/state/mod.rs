@@ -1,3 +1,5 @@
 pub mod bridge;
+pub mod config_change;
 
 pub use bridge::*;
+pub use config_change::*;
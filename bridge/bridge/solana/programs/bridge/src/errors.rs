@@ -12,6 +12,9 @@ pub enum BridgeError {
     #[msg("Incorrect gas fee receiver")]
     IncorrectGasFeeReceiver,
 
+    #[msg("Transfers of this mint are currently paused")]
+    MintPaused,
+
     // Authorization & Access Control (6100-6199)
     #[msg("Only the upgrade authority can initialize the bridge")]
     UnauthorizedInitialization = 6100,
@@ -19,6 +22,15 @@ pub enum BridgeError {
     #[msg("Unauthorized to update configuration")]
     UnauthorizedConfigUpdate,
 
+    #[msg("Only the pending guardian can accept the guardian handover")]
+    UnauthorizedGuardianAcceptance,
+
+    #[msg("No guardian handover is pending")]
+    NoPendingGuardian,
+
+    #[msg("Only the guardian can lift a pause")]
+    UnauthorizedUnpause,
+
     // Buffer Management (6200-6299)
     #[msg("Only the owner can close this buffer")]
     BufferUnauthorizedClose = 6200,
@@ -77,6 +89,81 @@ pub enum BridgeError {
     #[msg("Incorrect block number")]
     IncorrectBlockNumber,
 
+    #[msg("Transfer exceeds the withdrawal rate limit for this mint")]
+    WithdrawalRateLimitExceeded,
+
+    #[msg("Incorrect rate limit account")]
+    IncorrectRateLimitAccount,
+
+    #[msg("Message has not been executed")]
+    MessageNotExecuted,
+
+    #[msg("Rent receiver does not match the message payer")]
+    IncorrectMessagePayer,
+
+    #[msg("Output root is still within the retention horizon")]
+    OutputRootRetained,
+
+    #[msg("Rent receiver does not match the output root payer")]
+    IncorrectOutputRootPayer,
+
+    #[msg("Output root has been invalidated")]
+    InvalidatedOutputRoot,
+
+    #[msg("Output root is still in its challenge window")]
+    OutputRootNotFinalized,
+
+    #[msg("Output root challenge window has elapsed")]
+    ChallengeWindowElapsed,
+
+    #[msg("Output root is already registered for this block number")]
+    OutputRootAlreadyRegistered,
+
+    #[msg("Only the latest output root can be rolled back")]
+    OutputRootNotLatest,
+
+    #[msg("Base oracle signer epoch does not cover the output root block number")]
+    BaseOracleEpochMismatch,
+
+    #[msg("Pending output root no longer accepts signatures")]
+    PendingOutputRootExpired,
+
+    #[msg("Pending output root has not expired")]
+    PendingOutputRootNotExpired,
+
+    #[msg("Rent receiver does not match the pending output root payer")]
+    IncorrectPendingOutputRootPayer,
+
+    #[msg("Message has already been proven")]
+    MessageAlreadyProven,
+
+    #[msg("Relay step must execute at least one instruction")]
+    EmptyRelayStep,
+
+    #[msg("Bouncing unrelayed messages is disabled")]
+    MessageBouncingDisabled,
+
+    #[msg("Message cannot be bounced before the bounce delay has elapsed")]
+    BounceDelayNotElapsed,
+
+    #[msg("Only unrelayed transfer messages can be bounced")]
+    MessageNotBounceable,
+
+    #[msg("Relayed instruction targets a program rejected by the CPI policy")]
+    CpiProgramNotAllowed,
+
+    #[msg("Incoming message is not in the legacy layout")]
+    IncomingMessageAlreadyMigrated,
+
+    #[msg("Incoming message must be migrated before the message can be relayed")]
+    IncomingMessageNotMigrated,
+
+    #[msg("Output root is not in the legacy layout")]
+    OutputRootAlreadyMigrated,
+
+    #[msg("Relayed instruction passes a bridge vault as signer or writable")]
+    BridgeVaultNotAllowed,
+
     // Token Validation (6600-6699)
     #[msg("Mint does not match local token")]
     MintDoesNotMatchLocalToken = 6600,
@@ -96,6 +183,12 @@ pub enum BridgeError {
     #[msg("Incorrect sol vault")]
     IncorrectSolVault,
 
+    #[msg("Relayer fee exceeds the transferred amount")]
+    RelayerFeeExceedsAmount,
+
+    #[msg("Relayer fee receiver is not owned by the relayer")]
+    IncorrectRelayerFeeReceiver,
+
     // Token Metadata (6700-6799)
     #[msg("Remote token not found")]
     RemoteTokenNotFound = 6700,
@@ -116,7 +209,7 @@ pub enum BridgeError {
     MintIsNotWrappedTokenPda,
 
     // Bridge Configuration (6800-6899)
-    #[msg("Threshold must be <= number of signers")]
+    #[msg("Threshold must be reachable by the total signer weight")]
     InvalidThreshold = 6800,
 
     #[msg("Too many signers (max 32)")]
@@ -140,6 +233,60 @@ pub enum BridgeError {
     #[msg("Invalid block interval requirement")]
     InvalidBlockIntervalRequirement,
 
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+
+    #[msg("Too many paused mints")]
+    TooManyPausedMints,
+
+    #[msg("Too many rate limited mints")]
+    TooManyRateLimitedMints,
+
+    #[msg("Bridge account is already migrated")]
+    BridgeAlreadyMigrated,
+
+    #[msg("Invalid config change delay")]
+    InvalidConfigChangeDelay,
+
+    #[msg("Config changes must be queued through the timelock")]
+    ConfigChangeTimelocked,
+
+    #[msg("Config change is not ready to be executed")]
+    ConfigChangeNotReady,
+
+    #[msg("Invalid root finality delay")]
+    InvalidRootFinalityDelay,
+
+    #[msg("EIP-712 attestations require a Base chain id and a Solana genesis hash")]
+    InvalidAttestationDomain,
+
+    #[msg("Signer weights must be non-zero")]
+    InvalidSignerWeight,
+
+    #[msg("Signer is not in the Base oracle signer set")]
+    SignerNotFound,
+
+    #[msg("Base oracle signer changes require the Base oracle signers account")]
+    MissingBaseOracleSigners,
+
+    #[msg("Only the latest Base oracle signer epoch can be updated")]
+    BaseOracleEpochSuperseded,
+
+    #[msg("Signer epoch activation must be past the latest output root and the current epoch")]
+    InvalidEpochActivation,
+
+    #[msg("Bounce delay must be zero or at least one day")]
+    InvalidBounceDelay,
+
+    #[msg("Too many programs in the CPI policy (max 32)")]
+    TooManyCpiPolicyPrograms,
+
+    #[msg("Duplicate program found in the CPI policy")]
+    DuplicateCpiPolicyProgram,
+
+    #[msg("An account required to execute the config change is missing")]
+    MissingConfigChangeAccount,
+
     // Call Type Validation (6900-6999)
     #[msg("Creation with non-zero target")]
     CreationWithNonZeroTarget = 6900,
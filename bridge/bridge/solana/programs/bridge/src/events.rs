@@ -0,0 +1,402 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{BaseOracleSigners, CpiPolicyConfig, RateLimitConfig};
+use crate::common::{
+    BufferConfig, ConfigChange, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig,
+    Roles,
+};
+
+// Solana -> Base
+
+/// Emitted when native SOL is locked in the SOL vault and an outgoing message is created.
+#[event]
+pub struct SolBridged {
+    /// The outgoing message account created for this transfer
+    pub outgoing_message: Pubkey,
+    /// Nonce assigned to the outgoing message
+    pub nonce: u64,
+    /// The Solana account that owned the bridged SOL
+    pub sender: Pubkey,
+    /// The 20-byte Base recipient
+    pub to: [u8; 20],
+    /// Amount of SOL locked, in lamports
+    pub amount: u64,
+    /// Whether a call to execute on Base is attached to the transfer
+    pub has_call: bool,
+}
+
+/// Emitted when SPL tokens are locked in a token vault and an outgoing message is created.
+#[event]
+pub struct SplBridged {
+    /// The outgoing message account created for this transfer
+    pub outgoing_message: Pubkey,
+    /// Nonce assigned to the outgoing message
+    pub nonce: u64,
+    /// The transfer authority that signed for the bridged tokens
+    pub sender: Pubkey,
+    /// The SPL mint being bridged
+    pub mint: Pubkey,
+    /// The ERC-20 contract address on Base
+    pub remote_token: [u8; 20],
+    /// The 20-byte Base recipient
+    pub to: [u8; 20],
+    /// Amount actually received by the vault (net of transfer fees)
+    pub amount: u64,
+    /// Whether a call to execute on Base is attached to the transfer
+    pub has_call: bool,
+}
+
+/// Emitted when wrapped tokens are burned and an outgoing message is created.
+#[event]
+pub struct WrappedTokenBridged {
+    /// The outgoing message account created for this transfer
+    pub outgoing_message: Pubkey,
+    /// Nonce assigned to the outgoing message
+    pub nonce: u64,
+    /// The token owner that burned the wrapped tokens
+    pub sender: Pubkey,
+    /// The wrapped token mint
+    pub mint: Pubkey,
+    /// The original token contract address on Base
+    pub remote_token: [u8; 20],
+    /// The 20-byte Base recipient
+    pub to: [u8; 20],
+    /// Amount of wrapped tokens burned
+    pub amount: u64,
+    /// Whether a call to execute on Base is attached to the transfer
+    pub has_call: bool,
+}
+
+/// Emitted when an outgoing call message (without a token transfer) is created.
+#[event]
+pub struct CallBridged {
+    /// The outgoing message account created for this call
+    pub outgoing_message: Pubkey,
+    /// Nonce assigned to the outgoing message
+    pub nonce: u64,
+    /// The Solana account initiating the call
+    pub sender: Pubkey,
+    /// The target contract address on Base
+    pub to: [u8; 20],
+    /// Amount of ETH to send with the call on Base, in wei
+    pub value: u128,
+    /// Length of the call data
+    pub data_len: u64,
+}
+
+/// Emitted when a wrapped mint is created and its registration message is sent to Base.
+#[event]
+pub struct TokenWrapped {
+    /// The outgoing message registering the wrapped token on Base
+    pub outgoing_message: Pubkey,
+    /// Nonce assigned to the outgoing message
+    pub nonce: u64,
+    /// The newly created wrapped mint
+    pub mint: Pubkey,
+    /// The Base token contract this mint represents
+    pub remote_token: [u8; 20],
+    /// Decimals of the wrapped mint
+    pub decimals: u8,
+    /// Decimal scaling exponent between the Base token and the wrapped mint
+    pub scaler_exponent: u8,
+}
+
+// Base -> Solana
+
+/// Emitted when an output root attested by the oracles is registered.
+#[event]
+pub struct OutputRootRegistered {
+    /// The output root account created for this checkpoint
+    pub output_root_account: Pubkey,
+    /// The 32-byte MMR root
+    pub output_root: [u8; 32],
+    /// The Base block number of the checkpoint
+    pub base_block_number: u64,
+    /// The total number of leaves in the MMR with this root
+    pub total_leaf_count: u64,
+}
+
+/// Emitted when oracle signatures are added to a pending output root.
+#[event]
+pub struct OutputRootSignaturesAdded {
+    /// The pending output root account collecting the signatures
+    pub pending_output_root: Pubkey,
+    /// The 32-byte MMR root being attested
+    pub output_root: [u8; 32],
+    /// The Base block number of the checkpoint
+    pub base_block_number: u64,
+    /// The number of unique signers collected so far
+    pub signer_count: u16,
+}
+
+/// Emitted when an expired pending output root is closed and its rent refunded.
+#[event]
+pub struct PendingOutputRootClosed {
+    /// The pending output root account that was closed
+    pub pending_output_root: Pubkey,
+    /// The Base block number of the abandoned checkpoint
+    pub base_block_number: u64,
+}
+
+/// Emitted when an output root is invalidated during its challenge window.
+#[event]
+pub struct OutputRootInvalidated {
+    /// The output root account that was invalidated
+    pub output_root_account: Pubkey,
+    /// The Base block number of the invalidated checkpoint
+    pub base_block_number: u64,
+    /// The guardian, or the default key when invalidated by a quorum of partner signers
+    pub invalidated_by: Pubkey,
+}
+
+/// Emitted when the guardian revokes an output root.
+#[event]
+pub struct OutputRootRevoked {
+    /// The output root account that was revoked
+    pub output_root_account: Pubkey,
+    /// The Base block number of the revoked checkpoint
+    pub base_block_number: u64,
+    /// The latest registered Base block number after the revocation
+    pub latest_base_block_number: u64,
+}
+
+/// Emitted when an output root outside the retention horizon is closed and its rent refunded.
+#[event]
+pub struct OutputRootClosed {
+    /// The output root account that was closed
+    pub output_root_account: Pubkey,
+    /// The Base block number of the closed checkpoint
+    pub base_block_number: u64,
+}
+
+/// Emitted when a Base message is proven against a registered output root.
+#[event]
+pub struct MessageProven {
+    /// The incoming message account created for the proven message
+    pub incoming_message: Pubkey,
+    /// The output root account the proof was verified against
+    pub output_root: Pubkey,
+    /// The hash of the proven message
+    pub message_hash: [u8; 32],
+    /// The Base nonce of the message
+    pub nonce: u64,
+    /// The 20-byte sender of the message on Base
+    pub sender: [u8; 20],
+}
+
+/// Emitted when a Base message hash is proven without its payload.
+#[event]
+pub struct MessageHashProven {
+    /// The incoming message hash account created for the proven message
+    pub incoming_message: Pubkey,
+    /// The output root account the proof was verified against
+    pub output_root: Pubkey,
+    /// The hash of the proven message
+    pub message_hash: [u8; 32],
+    /// The Base nonce of the message
+    pub nonce: u64,
+}
+
+/// Emitted when a proven message is relayed on Solana.
+#[event]
+pub struct MessageRelayed {
+    /// The incoming message account that was relayed
+    pub incoming_message: Pubkey,
+    /// The 20-byte sender of the message on Base
+    pub sender: [u8; 20],
+    /// The Base nonce of the message
+    pub nonce: u64,
+    /// The hash of the message on Base
+    pub message_hash: [u8; 32],
+    /// Whether the message is now marked as executed
+    pub executed: bool,
+}
+
+/// Emitted when a Base message is proven and executed in a single instruction.
+#[event]
+pub struct MessageProvenAndRelayed {
+    /// The output root account the proof was verified against
+    pub output_root: Pubkey,
+    /// The hash of the executed message
+    pub message_hash: [u8; 32],
+    /// The Base nonce of the message, now consumed in the nonce bitmap
+    pub nonce: u64,
+    /// The 20-byte sender of the message on Base
+    pub sender: [u8; 20],
+}
+
+/// Emitted for every relay step of a resumable message.
+#[event]
+pub struct MessageStepRelayed {
+    /// The incoming message account being relayed
+    pub incoming_message: Pubkey,
+    /// Index of the first instruction executed by this step
+    pub first_ix: u32,
+    /// Index of the next instruction to execute; equals `ix_count` once the message is executed
+    pub next_ix: u32,
+    /// Total number of instructions in the message
+    pub ix_count: u32,
+}
+
+/// Emitted when an unrelayed transfer message is bounced back to its Base sender.
+#[event]
+pub struct MessageBounced {
+    /// The incoming message account that was bounced and closed
+    pub incoming_message: Pubkey,
+    /// The Base nonce of the message, now consumed in the nonce bitmap
+    pub nonce: u64,
+    /// The 20-byte Base sender receiving the bridged amount back
+    pub sender: [u8; 20],
+    /// The outgoing message account returning the amount to Base
+    pub outgoing_message: Pubkey,
+    /// Nonce assigned to the outgoing message
+    pub outgoing_nonce: u64,
+    /// The Solana mint of the bounced transfer (`NATIVE_SOL_PUBKEY` for native SOL)
+    pub local_token: Pubkey,
+    /// Amount returned to Base, in base units of the mint
+    pub amount: u64,
+}
+
+/// Emitted when an executed incoming message account is closed and its rent refunded.
+#[event]
+pub struct IncomingMessageClosed {
+    /// The incoming message account that was closed
+    pub incoming_message: Pubkey,
+    /// The Base nonce of the message, which stays consumed in the nonce bitmap
+    pub nonce: u64,
+}
+
+// Configuration
+
+/// Emitted when the guardian proposes a new guardian.
+#[event]
+pub struct GuardianProposed {
+    pub guardian: Pubkey,
+    pub pending_guardian: Pubkey,
+}
+
+/// Emitted when the guardian withdraws a pending guardian proposal.
+#[event]
+pub struct GuardianProposalCancelled {
+    pub pending_guardian: Pubkey,
+}
+
+/// Emitted when the pending guardian accepts and guardian authority is handed over.
+#[event]
+pub struct GuardianTransferred {
+    pub previous_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+}
+
+/// Emitted when the upgrade authority reassigns the pauser or signer manager role.
+#[event]
+pub struct RolesUpdated {
+    pub before: Roles,
+    pub after: Roles,
+}
+
+/// Emitted when the paused instruction families of the bridge change.
+#[event]
+pub struct PauseFlagsUpdated {
+    pub before: u16,
+    pub after: u16,
+}
+
+/// Emitted when a mint is added to or removed from the paused mints list.
+#[event]
+pub struct MintPauseUpdated {
+    pub mint: Pubkey,
+    pub paused: bool,
+}
+
+/// Emitted when any EIP-1559 configuration parameter changes.
+#[event]
+pub struct Eip1559ConfigUpdated {
+    pub before: Eip1559Config,
+    pub after: Eip1559Config,
+}
+
+/// Emitted when any gas configuration parameter changes.
+#[event]
+pub struct GasConfigUpdated {
+    pub before: GasConfig,
+    pub after: GasConfig,
+}
+
+/// Emitted when any protocol configuration parameter changes.
+#[event]
+pub struct ProtocolConfigUpdated {
+    pub before: ProtocolConfig,
+    pub after: ProtocolConfig,
+}
+
+/// Emitted when any buffer configuration parameter changes.
+#[event]
+pub struct BufferConfigUpdated {
+    pub before: BufferConfig,
+    pub after: BufferConfig,
+}
+
+/// Emitted when the partner oracle configuration changes.
+#[event]
+pub struct PartnerOracleConfigUpdated {
+    pub before: PartnerOracleConfig,
+    pub after: PartnerOracleConfig,
+}
+
+/// Emitted when the Base oracle signer registry changes.
+#[event]
+pub struct BaseOracleSignersUpdated {
+    pub before: BaseOracleSigners,
+    pub after: BaseOracleSigners,
+}
+
+/// Emitted when a new Base oracle signer epoch is started.
+#[event]
+pub struct BaseOracleEpochStarted {
+    pub epoch: u64,
+    /// First Base block number covered by the new epoch
+    pub activation_block_number: u64,
+    /// Base block number from which the previous epoch is no longer accepted
+    pub previous_epoch_expiry_block_number: u64,
+}
+
+/// Emitted when the withdrawal rate limit of a mint is created or updated.
+#[event]
+pub struct MintRateLimitUpdated {
+    pub mint: Pubkey,
+    /// `None` when the rate limit account was just created
+    pub before: Option<RateLimitConfig>,
+    pub after: RateLimitConfig,
+}
+
+/// Emitted when the guardian updates the policy restricting the programs relayed messages can call.
+#[event]
+pub struct CpiPolicyUpdated {
+    pub before: CpiPolicyConfig,
+    pub after: CpiPolicyConfig,
+}
+
+/// Emitted when a config change is queued through the timelock.
+#[event]
+pub struct ConfigChangeQueued {
+    pub pending_change: Pubkey,
+    pub proposer: Pubkey,
+    pub change: ConfigChange,
+    /// Unix timestamp after which the change can be executed
+    pub eta: i64,
+}
+
+/// Emitted when a queued config change is applied.
+#[event]
+pub struct ConfigChangeExecuted {
+    pub pending_change: Pubkey,
+    pub change: ConfigChange,
+}
+
+/// Emitted when the guardian cancels a queued config change.
+#[event]
+pub struct ConfigChangeCancelled {
+    pub pending_change: Pubkey,
+    pub change: ConfigChange,
+}
@@ -5,21 +5,32 @@ use anchor_lang::prelude::*;
 mod base_to_solana;
 mod common;
 mod errors;
+mod events;
 mod solana_to_base;
 
 use base_to_solana::*;
 use common::*;
 pub use errors::*;
+pub use events::*;
 
 use common::{
     config::{
-        set_adjustment_denominator_handler, set_block_interval_requirement_handler,
+        cancel_config_change_handler, execute_config_change_handler,
+        queue_config_change_from_signer_manager_handler,
+        queue_config_change_from_upgrade_authority_handler, queue_config_change_handler,
+        set_adjustment_denominator_handler, set_attestation_config_handler,
+        set_block_interval_requirement_handler, set_bounce_delay_seconds_handler,
         set_gas_cost_scaler_dp_handler, set_gas_cost_scaler_handler, set_gas_fee_receiver_handler,
         set_gas_target_handler, set_max_call_buffer_size_handler, set_minimum_base_fee_handler,
-        set_pause_status_handler, set_window_duration_handler,
+        set_mint_pause_handler, set_mint_rate_limit_handler, set_output_root_retention_handler,
+        set_pause_flags_handler, set_pause_status_handler, set_roles_handler,
+        set_root_finality_seconds_handler, set_window_duration_handler,
+    },
+    guardian::{
+        accept_guardian_handler, cancel_guardian_proposal_handler, propose_guardian_handler,
     },
-    guardian::transfer_guardian_handler,
     initialize::initialize_handler,
+    migrate::migrate_bridge_handler,
 };
 use solana_to_base::*;
 
@@ -37,6 +48,8 @@ pub mod bridge {
 
     /// Initializes the bridge program with required state accounts.
     /// This function sets up the initial bridge configuration and must be called once during deployment.
+    /// Config changes are only timelocked if `cfg.protocol_config.config_change_delay_seconds` is
+    /// non-zero; with a zero delay the guardian's setters take effect immediately.
     ///
     /// # Arguments
     /// * `ctx`      - The context containing all accounts needed for initialization, including the guardian signer
@@ -46,6 +59,17 @@ pub mod bridge {
         initialize_handler(ctx, guardian, cfg)
     }
 
+    /// Migrates a bridge account created with the legacy layout (single `paused` flag) to the
+    /// current layout. The account is reallocated and a paused legacy bridge stays fully paused.
+    /// The config change timelock is enabled with a `MIGRATED_CONFIG_CHANGE_DELAY_SECONDS` delay.
+    /// Only the upgrade authority can call this function.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account, upgrade authority and program data
+    pub fn migrate_bridge(ctx: Context<MigrateBridge>) -> Result<()> {
+        migrate_bridge_handler(ctx)
+    }
+
     // Base -> Solana
 
     /// Registers an output root from Base to enable message verification.
@@ -59,7 +83,9 @@ pub mod bridge {
     /// * `output_root`       - The 32-byte MMR root of Base messages for the given block
     /// * `base_block_number` - The Base block number this output root corresponds to
     /// * `total_leaf_count`  - The total number of leaves in the MMR with this root
-    /// * `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the output root
+    /// * `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the output root;
+    ///                         signatures checked by Secp256k1 or Ed25519 program instructions in the same
+    ///                         transaction are read from the instructions sysvar instead
     pub fn register_output_root(
         ctx: Context<RegisterOutputRoot>,
         output_root: [u8; 32],
@@ -76,6 +102,54 @@ pub mod bridge {
         )
     }
 
+    /// Adds oracle attestations to a pending output root, for committees whose signatures do not
+    /// fit in a single `register_output_root` call. The signers are recovered and deduplicated
+    /// against those already collected; only Base oracle and partner signers are kept.
+    /// Anyone can call this function.
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the pending output root, the bridge and the signer sets
+    /// * `output_root`       - The 32-byte MMR root of Base messages for the given block
+    /// * `base_block_number` - The Base block number this output root corresponds to
+    /// * `total_leaf_count`  - The total number of leaves in the MMR with this root
+    /// * `signatures`        - ECDSA signatures from oracles attesting to the output root; signatures
+    ///                         checked by Secp256k1 or Ed25519 program instructions in the same
+    ///                         transaction are read from the instructions sysvar instead
+    pub fn add_output_root_signatures(
+        ctx: Context<AddOutputRootSignatures>,
+        output_root: [u8; 32],
+        base_block_number: u64,
+        total_leaf_count: u64,
+        signatures: Vec<[u8; 65]>,
+    ) -> Result<()> {
+        add_output_root_signatures_handler(
+            ctx,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            signatures,
+        )
+    }
+
+    /// Registers a pending output root once its collected attestations meet both the Base oracle
+    /// and partner thresholds, and refunds the pending account's rent to its creator.
+    /// Anyone can call this function.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the pending output root, the output root account and the bridge
+    pub fn finalize_output_root(ctx: Context<FinalizeOutputRoot>) -> Result<()> {
+        finalize_output_root_handler(ctx)
+    }
+
+    /// Closes a pending output root whose attestation window expired before it was finalized,
+    /// refunding its rent to the account that created it. Anyone can call this function.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the pending output root and its payer
+    pub fn close_pending_output_root(ctx: Context<ClosePendingOutputRoot>) -> Result<()> {
+        close_pending_output_root_handler(ctx)
+    }
+
     /// Proves that a cross-chain message exists in the Base Bridge contract using an MMR proof.
     /// This function verifies the message was included in a previously registered output root
     /// and stores the proven message state for later relay execution.
@@ -98,6 +172,41 @@ pub mod bridge {
         prove_message_handler(ctx, nonce, sender, data, proof, message_hash)
     }
 
+    /// Proves several cross-chain messages against a single output root with one MMR multiproof,
+    /// creating all their incoming message accounts in one go. Shared sibling hashes are only
+    /// provided once, which keeps bursts of Base -> Solana messages cheap to prove.
+    ///
+    /// # Arguments
+    /// * `ctx`      - The transaction context; the incoming message accounts are passed as remaining
+    ///                accounts in the same order as `messages`
+    /// * `messages` - The message leaves to prove, sorted by nonce
+    /// * `proof`    - MMR multiproof demonstrating inclusion of all messages in the output root
+    pub fn prove_messages<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProveMessages<'info>>,
+        messages: Vec<MessageLeaf>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        prove_messages_handler(ctx, messages, proof)
+    }
+
+    /// Proves that a cross-chain message exists on Base while only storing its hash.
+    /// The account rent does not depend on the payload size: the payload is provided again
+    /// when relaying with `relay_message_with_payload` and checked against the stored hash.
+    ///
+    /// # Arguments
+    /// * `ctx`          - The transaction context
+    /// * `nonce`        - Unique identifier for the cross-chain message
+    /// * `message_hash` - The 32-byte hash of the message, which is the proven MMR leaf
+    /// * `proof`        - MMR proof demonstrating message inclusion in the output root
+    pub fn prove_message_hash(
+        ctx: Context<ProveMessageHash>,
+        nonce: u64,
+        message_hash: [u8; 32],
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        prove_message_hash_handler(ctx, nonce, message_hash, proof)
+    }
+
     /// Initializes a prove buffer account that can store large prove inputs.
     /// This account can be used to build up serialized message data and MMR proof nodes
     /// over multiple transactions before calling `prove_message_buffered`.
@@ -173,12 +282,214 @@ pub mod bridge {
     /// This function takes a message that has been proven via `prove_message` and executes
     /// its payload using a bridge CPI authority derived from the message sender.
     ///
+    /// `Message::Resumable` payloads can be executed over several calls: each call runs up to
+    /// `max_ixs` instructions from the message's execution cursor, the first one also finalizing
+    /// the transfer.
+    ///
+    /// For a `Message::TransferWithRelayerFee`, the `relayer_fee` part of the amount is paid to the
+    /// `payer` executing the message (or a token account it owns) and the remainder to `to`.
+    ///
+    /// Every instruction of the message must target a program allowed by the guardian-managed
+    /// CPI policy (see `set_cpi_policy`), which also applies to the other relay instructions.
+    ///
     /// # Arguments
-    /// * `ctx` - The transaction context
+    /// * `ctx`     - The transaction context
+    /// * `max_ixs` - Maximum number of instructions of a resumable message to run in this call;
+    ///               `None` runs all remaining ones. Ignored for atomic messages.
     pub fn relay_message<'a, 'info>(
         ctx: Context<'a, '_, 'info, 'info, RelayMessage<'info>>,
+        max_ixs: Option<u32>,
+    ) -> Result<()> {
+        relay_message_handler(ctx, max_ixs)
+    }
+
+    /// Executes a cross-chain message proven with `prove_message_hash`.
+    /// The payload and its sender are checked against the stored message hash before executing.
+    ///
+    /// # Arguments
+    /// * `ctx`    - The transaction context
+    /// * `sender` - The 20-byte Ethereum address that sent the message on Base
+    /// * `data`   - The serialized `Message` payload that was proven
+    pub fn relay_message_with_payload<'a, 'info>(
+        ctx: Context<'a, '_, 'info, 'info, RelayMessageWithPayload<'info>>,
+        sender: [u8; 20],
+        data: Vec<u8>,
+    ) -> Result<()> {
+        relay_message_with_payload_handler(ctx, sender, data)
+    }
+
+    /// Executes a cross-chain message proven with `prove_message_hash`, reading its payload from
+    /// a `ProveBuffer`. The prove buffer is closed on success.
+    ///
+    /// # Arguments
+    /// * `ctx`    - The transaction context, including the prove buffer and its owner
+    /// * `sender` - The 20-byte Ethereum address that sent the message on Base
+    pub fn relay_message_with_payload_buffered<'a, 'info>(
+        ctx: Context<'a, '_, 'info, 'info, RelayMessageWithPayloadBuffered<'info>>,
+        sender: [u8; 20],
+    ) -> Result<()> {
+        relay_message_with_payload_buffered_handler(ctx, sender)
+    }
+
+    /// Proves a cross-chain message against a registered output root and executes it in the
+    /// same instruction, without creating an incoming message account. Meant for small messages
+    /// whose payload and proof fit in a single transaction. Messages already proven with
+    /// `prove_message` are rejected and must be relayed with `relay_message`.
+    ///
+    /// # Arguments
+    /// * `ctx`    - The transaction context; the accounts needed by the message are passed as
+    ///              remaining accounts
+    /// * `nonce`  - Unique identifier for the cross-chain message
+    /// * `sender` - The 20-byte Ethereum address that sent the message on Base
+    /// * `data`   - The serialized `Message` payload to execute
+    /// * `proof`  - MMR proof demonstrating message inclusion in the output root
+    pub fn prove_and_relay_message<'a, 'info>(
+        ctx: Context<'a, '_, 'info, 'info, ProveAndRelayMessage<'info>>,
+        nonce: u64,
+        sender: [u8; 20],
+        data: Vec<u8>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        prove_and_relay_message_handler(ctx, nonce, sender, data, proof)
+    }
+
+    /// Bounces a proven transfer message that could not be relayed back to its Base sender.
+    /// Once `protocol_config.bounce_delay_seconds` have elapsed since the message was proven,
+    /// anyone can consume its nonce and create a Solana -> Base outgoing message returning the
+    /// bridged amount to the sender. Only this deadline path is supported: failed relay attempts
+    /// revert their whole transaction and cannot be counted on chain. The incoming message is
+    /// closed and its rent refunded to its payer.
+    ///
+    /// # Arguments
+    /// * `ctx`                   - The context containing the incoming message and the outgoing
+    ///                             message accounts
+    /// * `outgoing_message_salt` - Salt used to derive the outgoing message PDA
+    pub fn bounce_message(
+        ctx: Context<BounceMessage>,
+        outgoing_message_salt: [u8; 32],
+    ) -> Result<()> {
+        bounce_message_handler(ctx, outgoing_message_salt)
+    }
+
+    /// Bounces a transfer message proven with `prove_message_hash` back to its Base sender, under
+    /// the same conditions as `bounce_message`. The payload and its sender are checked against
+    /// the stored message hash. The message hash account is closed and its rent refunded to its
+    /// payer.
+    ///
+    /// # Arguments
+    /// * `ctx`                   - The context containing the message hash and the outgoing
+    ///                             message accounts
+    /// * `outgoing_message_salt` - Salt used to derive the outgoing message PDA
+    /// * `sender`                - The 20-byte Ethereum address that sent the message on Base
+    /// * `data`                  - The serialized `Message` payload that was proven
+    pub fn bounce_message_with_payload(
+        ctx: Context<BounceMessageWithPayload>,
+        outgoing_message_salt: [u8; 32],
+        sender: [u8; 20],
+        data: Vec<u8>,
+    ) -> Result<()> {
+        bounce_message_with_payload_handler(ctx, outgoing_message_salt, sender, data)
+    }
+
+    /// Migrates an incoming message proven with the legacy layout (sender, message and executed
+    /// flag only) to the current layout so it can be relayed, bounced and closed. The guardian pays
+    /// for the extra bytes and records the original payer of the message, which receives its rent
+    /// once closed or bounced. A legacy message that was already executed has its nonce marked in
+    /// the nonce bitmap. Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`            - The context containing the legacy incoming message, its nonce bitmap page and the guardian
+    /// * `nonce`          - The Base nonce of the message, checked against the message address
+    /// * `original_payer` - The account that paid for the legacy message, recorded as its rent payer
+    pub fn migrate_incoming_message(
+        ctx: Context<MigrateIncomingMessage>,
+        nonce: u64,
+        original_payer: Pubkey,
+    ) -> Result<()> {
+        migrate_incoming_message_handler(ctx, nonce, original_payer)
+    }
+
+    /// Closes an incoming message account whose nonce is consumed, either by executing it or by
+    /// relaying the message with `prove_and_relay_message`, and refunds its rent to the account
+    /// that paid for it when proving the message. Replay protection is kept by the nonce bitmap.
+    /// Anyone can call this function.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the incoming message, its nonce bitmap page and the payer
+    pub fn close_incoming_message(ctx: Context<CloseIncomingMessage>) -> Result<()> {
+        close_incoming_message_handler(ctx)
+    }
+
+    /// Closes an executed incoming message hash account and refunds its rent to the account that
+    /// paid for it when proving the message. Anyone can call this function.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the message hash account, its nonce bitmap page and the payer
+    pub fn close_incoming_message_hash(ctx: Context<CloseIncomingMessageHash>) -> Result<()> {
+        close_incoming_message_hash_handler(ctx)
+    }
+
+    /// Migrates an output root registered with the legacy layout (root and leaf count only) to the
+    /// current layout so it can be invalidated, revoked and closed. Legacy roots were provable as
+    /// soon as they were registered, so the migrated root is already past its challenge window.
+    /// The caller pays for the extra bytes and becomes the rent payer of the root. Anyone can call
+    /// this function.
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the legacy output root and the payer
+    /// * `base_block_number` - The Base block number the root was registered for
+    pub fn migrate_output_root(
+        ctx: Context<MigrateOutputRoot>,
+        base_block_number: u64,
+    ) -> Result<()> {
+        migrate_output_root_handler(ctx, base_block_number)
+    }
+
+    /// Closes an output root that is older than the retention horizon configured by the guardian
+    /// and refunds its rent to the account that registered it. The latest registered output root
+    /// can never be closed. Anyone can call this function.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the output root, the bridge account and the payer
+    pub fn close_output_root(ctx: Context<CloseOutputRoot>) -> Result<()> {
+        close_output_root_handler(ctx)
+    }
+
+    /// Invalidates an output root during its challenge window so that no message can be proven
+    /// against it. Only the guardian can call this function.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the output root, the bridge account and the guardian
+    pub fn invalidate_output_root(ctx: Context<InvalidateOutputRoot>) -> Result<()> {
+        invalidate_output_root_handler(ctx)
+    }
+
+    /// Invalidates an output root during its challenge window on the attestation of a quorum of
+    /// partner signers. Anyone can submit the signatures.
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the output root, the bridge account and the partner signers
+    /// * `signatures` - ECDSA signatures from partner signers over the invalidation attestation
+    pub fn invalidate_output_root_with_partner_signatures(
+        ctx: Context<InvalidateOutputRootWithPartnerSignatures>,
+        signatures: Vec<[u8; 65]>,
     ) -> Result<()> {
-        relay_message_handler(ctx)
+        invalidate_output_root_with_partner_signatures_handler(ctx, signatures)
+    }
+
+    /// Revokes an output root at any time so that no message can be proven against it anymore.
+    /// When the revoked root is the latest one, the guardian can roll `base_block_number` back so a
+    /// corrected root can be registered for the same checkpoint.
+    /// Only the guardian can call this function.
+    ///
+    /// # Arguments
+    /// * `ctx`          - The context containing the output root, the bridge account and the guardian
+    /// * `roll_back_to` - Optional Base block number to reset the latest registered block number to
+    pub fn revoke_output_root(
+        ctx: Context<RevokeOutputRoot>,
+        roll_back_to: Option<u64>,
+    ) -> Result<()> {
+        revoke_output_root_handler(ctx, roll_back_to)
     }
 
     // Solana -> Base
@@ -392,31 +703,108 @@ pub mod bridge {
         close_call_buffer_handler(ctx)
     }
 
-    /// Transfer guardian authority to a new pubkey
+    /// Propose a new guardian. The handover only takes effect once the proposed guardian
+    /// accepts it, so a mistyped key cannot lock the guardian out.
     /// Only the current guardian can call this function
     ///
     /// # Arguments
-    /// * `ctx` - The context containing the bridge account and current guardian
-    /// * `new_guardian` - The pubkey of the new guardian
-    pub fn transfer_guardian(
+    /// * `ctx`          - The context containing the bridge account and current guardian
+    /// * `new_guardian` - The pubkey of the proposed guardian
+    pub fn propose_guardian(
         ctx: Context<SetBridgeConfigFromGuardian>,
         new_guardian: Pubkey,
     ) -> Result<()> {
-        transfer_guardian_handler(ctx, new_guardian)
+        propose_guardian_handler(ctx, new_guardian)
     }
 
-    /// Sets the authorized oracle EVM signer addresses and the signature threshold used
-    /// when registering output roots. This function updates the `OracleSigners` account
-    /// and can only be called by the guardian.
+    /// Accept a pending guardian handover
+    /// Only the proposed guardian can call this function
     ///
     /// # Arguments
-    /// * `ctx` - The context containing the bridge, guardian signer, and oracle signers accounts
-    /// * `cfg` - Configuration parameters for Base oracle signers
-    pub fn set_oracle_signers(
-        ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
-        cfg: BaseOracleConfig,
+    /// * `ctx` - The context containing the bridge account and the pending guardian signer
+    pub fn accept_guardian(ctx: Context<AcceptGuardian>) -> Result<()> {
+        accept_guardian_handler(ctx)
+    }
+
+    /// Cancel a pending guardian handover
+    /// Only the current guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and current guardian
+    pub fn cancel_guardian_proposal(ctx: Context<SetBridgeConfigFromGuardian>) -> Result<()> {
+        cancel_guardian_proposal_handler(ctx)
+    }
+
+    /// Starts a new Base oracle signer epoch, a copy of the current signer set that covers roots
+    /// from `activation_block_number` onwards. The current epoch stays accepted for
+    /// `overlap_blocks` past the activation. Can only be called by the signer manager, and only
+    /// while the config change timelock is disabled; otherwise queue a `ConfigChange::StartBaseOracleEpoch`.
+    ///
+    /// # Arguments
+    /// * `ctx`                     - The context containing the bridge, the current and next epochs
+    ///   and the signer manager
+    /// * `activation_block_number` - First Base block number covered by the new epoch
+    /// * `overlap_blocks`          - Number of blocks during which both epochs are accepted
+    pub fn start_base_oracle_epoch(
+        ctx: Context<StartBaseOracleEpoch>,
+        activation_block_number: u64,
+        overlap_blocks: u64,
+    ) -> Result<()> {
+        start_base_oracle_epoch_handler(ctx, activation_block_number, overlap_blocks)
+    }
+
+    /// Adds an oracle EVM signer to the latest `BaseOracleSigners` epoch used when registering
+    /// output roots, growing the registry account. Can only be called by the signer manager.
+    ///
+    /// # Arguments
+    /// * `ctx`    - The context containing the bridge, the signer registry and the signer manager
+    /// * `signer` - The EVM address and approval weight of the new signer
+    pub fn add_base_oracle_signer(
+        ctx: Context<AddBaseOracleSigner>,
+        signer: BaseOracleSigner,
+    ) -> Result<()> {
+        add_base_oracle_signer_handler(ctx, signer)
+    }
+
+    /// Removes an oracle signer from the `BaseOracleSigners` registry, shrinking the
+    /// registry account. Can only be called by the signer manager.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge, the signer registry and the signer manager
+    /// * `key` - The EVM address or Ed25519 public key of the signer to remove
+    pub fn remove_base_oracle_signer(
+        ctx: Context<RemoveBaseOracleSigner>,
+        key: OracleSignerKey,
+    ) -> Result<()> {
+        remove_base_oracle_signer_handler(ctx, key)
+    }
+
+    /// Replaces an oracle signer in the `BaseOracleSigners` registry, e.g. to rotate its key.
+    /// Can only be called by the signer manager.
+    ///
+    /// # Arguments
+    /// * `ctx`    - The context containing the bridge, the signer registry and the signer manager
+    /// * `key`    - The EVM address or Ed25519 public key of the signer to replace
+    /// * `signer` - The key and approval weight of the replacement signer
+    pub fn replace_base_oracle_signer(
+        ctx: Context<SetBridgeConfigFromSignerManager>,
+        key: OracleSignerKey,
+        signer: BaseOracleSigner,
+    ) -> Result<()> {
+        replace_base_oracle_signer_handler(ctx, key, signer)
+    }
+
+    /// Sets the total Base oracle signer weight required to register an output root.
+    /// Can only be called by the signer manager.
+    ///
+    /// # Arguments
+    /// * `ctx`       - The context containing the bridge, the signer registry and the signer manager
+    /// * `threshold` - The new approval weight threshold
+    pub fn set_base_oracle_threshold(
+        ctx: Context<SetBridgeConfigFromSignerManager>,
+        threshold: u16,
     ) -> Result<()> {
-        set_oracle_signers_handler(ctx, cfg)
+        set_base_oracle_threshold_handler(ctx, threshold)
     }
 
     // EIP-1559 Configuration Management
@@ -535,6 +923,45 @@ pub mod bridge {
         set_block_interval_requirement_handler(ctx, new_interval)
     }
 
+    /// Set the output root retention horizon for Protocol Config
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_retention_blocks` - Number of Base blocks an output root is kept after the latest one (0 disables closing)
+    pub fn set_output_root_retention(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_retention_blocks: u64,
+    ) -> Result<()> {
+        set_output_root_retention_handler(ctx, new_retention_blocks)
+    }
+
+    /// Set the output root challenge window for Protocol Config
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_finality_seconds` - Delay after registration before an output root can be proven against (0 disables the window)
+    pub fn set_root_finality_seconds(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_finality_seconds: u64,
+    ) -> Result<()> {
+        set_root_finality_seconds_handler(ctx, new_finality_seconds)
+    }
+
+    /// Set the delay after which unrelayed messages can be bounced back to Base for Protocol Config
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_delay_seconds` - Delay after proving before an unrelayed message can be bounced (0 disables bouncing)
+    pub fn set_bounce_delay_seconds(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_delay_seconds: u64,
+    ) -> Result<()> {
+        set_bounce_delay_seconds_handler(ctx, new_delay_seconds)
+    }
+
     /// Set the max call buffer size for Buffer Config
     /// Only the guardian can call this function
     ///
@@ -548,19 +975,70 @@ pub mod bridge {
         set_max_call_buffer_size_handler(ctx, new_size)
     }
 
-    /// Set the pause status for the bridge
-    /// Only the guardian can call this function
+    /// Pause or unpause every instruction family of the bridge at once
+    /// The pauser or the guardian can pause; only the guardian can lift a pause
     ///
     /// # Arguments
-    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `ctx` - The context containing the bridge account and pauser or guardian
     /// * `new_paused` - The new pause status (true for paused, false for unpaused)
     pub fn set_pause_status(
-        ctx: Context<SetBridgeConfigFromGuardian>,
+        ctx: Context<SetBridgeConfigFromPauser>,
         new_paused: bool,
     ) -> Result<()> {
         set_pause_status_handler(ctx, new_paused)
     }
 
+    /// Set the bitmask of paused instruction families
+    /// The pauser or the guardian can pause; only the guardian can lift a pause
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and pauser or guardian
+    /// * `new_flags` - The new pause bitmask, built from the `PAUSE_*` constants
+    pub fn set_pause_flags(ctx: Context<SetBridgeConfigFromPauser>, new_flags: u16) -> Result<()> {
+        set_pause_flags_handler(ctx, new_flags)
+    }
+
+    /// Pause or unpause outgoing and incoming transfers of a single mint
+    /// The pauser or the guardian can pause; only the guardian can lift a pause
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and pauser or guardian
+    /// * `mint` - The mint to (un)pause (`NATIVE_SOL_PUBKEY` for native SOL)
+    /// * `paused` - Whether transfers of the mint should be paused
+    pub fn set_mint_pause(
+        ctx: Context<SetBridgeConfigFromPauser>,
+        mint: Pubkey,
+        paused: bool,
+    ) -> Result<()> {
+        set_mint_pause_handler(ctx, mint, paused)
+    }
+
+    /// Assign the pauser and signer manager roles
+    /// Only the upgrade authority can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`   - The context containing the bridge account and upgrade authority
+    /// * `roles` - The new role assignments
+    pub fn set_roles(
+        ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+        roles: Roles,
+    ) -> Result<()> {
+        set_roles_handler(ctx, roles)
+    }
+
+    /// Set the format of the oracle attestations accepted for output roots
+    /// Only the upgrade authority can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and upgrade authority
+    /// * `cfg` - The accepted attestation format(s) and the EIP-712 domain parameters
+    pub fn set_attestation_config(
+        ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+        cfg: AttestationConfig,
+    ) -> Result<()> {
+        set_attestation_config_handler(ctx, cfg)
+    }
+
     /// Update the partner oracle configuration containing the required signature threshold
     ///
     /// # Arguments
@@ -572,4 +1050,95 @@ pub mod bridge {
     ) -> Result<()> {
         set_partner_config_handler(ctx, new_config)
     }
+
+    /// Create or update the withdrawal rate limit applied to Base -> Solana transfers of a mint
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`  - The context containing the bridge account, guardian and the mint's rate limit account
+    /// * `mint` - The Solana mint to rate limit (`NATIVE_SOL_PUBKEY` for native SOL)
+    /// * `cfg`  - The capacity, refill amount and window duration of the limit
+    pub fn set_mint_rate_limit(
+        ctx: Context<SetMintRateLimit>,
+        mint: Pubkey,
+        cfg: RateLimitConfig,
+    ) -> Result<()> {
+        set_mint_rate_limit_handler(ctx, mint, cfg)
+    }
+
+    /// Replace the policy restricting the programs relayed Base -> Solana messages can call,
+    /// either as an allowlist or a denylist. The bridge program is always denied, as are instructions
+    /// passing a bridge vault as signer or writable.
+    /// Only the guardian can call this function, and only while the config change timelock is
+    /// disabled; otherwise queue a `ConfigChange::CpiPolicy`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account, guardian and the CPI policy account
+    /// * `cfg` - The policy mode and the programs it lists
+    pub fn set_cpi_policy(ctx: Context<SetCpiPolicy>, cfg: CpiPolicyConfig) -> Result<()> {
+        set_cpi_policy_handler(ctx, cfg)
+    }
+
+    /// Queue a config change that can be executed once `protocol_config.config_change_delay_seconds`
+    /// has elapsed. Base oracle signer changes must be queued by the signer manager and oracle
+    /// configuration changes by the upgrade authority instead.
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`                 - The context containing the bridge account, guardian and pending change account
+    /// * `pending_change_salt` - The salt for the pending change account
+    /// * `change`              - The config change to apply once the delay has elapsed
+    pub fn queue_config_change(
+        ctx: Context<QueueConfigChange>,
+        pending_change_salt: [u8; 32],
+        change: ConfigChange,
+    ) -> Result<()> {
+        queue_config_change_handler(ctx, pending_change_salt, change)
+    }
+
+    /// Queue a Base oracle signer change from the signer manager
+    ///
+    /// # Arguments
+    /// * `ctx`                 - The context containing the bridge account, signer manager and pending change account
+    /// * `pending_change_salt` - The salt for the pending change account
+    /// * `change`              - The config change to apply once the delay has elapsed
+    pub fn queue_config_change_from_signer_manager(
+        ctx: Context<QueueConfigChangeFromSignerManager>,
+        pending_change_salt: [u8; 32],
+        change: ConfigChange,
+    ) -> Result<()> {
+        queue_config_change_from_signer_manager_handler(ctx, pending_change_salt, change)
+    }
+
+    /// Queue an oracle configuration change from the upgrade authority
+    ///
+    /// # Arguments
+    /// * `ctx`                 - The context containing the bridge account, upgrade authority and pending change account
+    /// * `pending_change_salt` - The salt for the pending change account
+    /// * `change`              - The config change to apply once the delay has elapsed
+    pub fn queue_config_change_from_upgrade_authority(
+        ctx: Context<QueueConfigChangeFromUpgradeAuthority>,
+        pending_change_salt: [u8; 32],
+        change: ConfigChange,
+    ) -> Result<()> {
+        queue_config_change_from_upgrade_authority_handler(ctx, pending_change_salt, change)
+    }
+
+    /// Execute a queued config change once its eta has passed
+    /// Anyone can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and the pending change account
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        execute_config_change_handler(ctx)
+    }
+
+    /// Cancel a queued config change
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account, guardian and the pending change account
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        cancel_config_change_handler(ctx)
+    }
 }
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_CALL},
     solana_to_base::{
         internal::bridge_call::bridge_call_internal, Call, OutgoingMessage, OUTGOING_MESSAGE_SEED,
     },
@@ -13,6 +13,7 @@ use crate::{
 /// - Creates an `OutgoingMessage` containing the call data
 /// - Validates call semantics (e.g. creation calls require zero target)
 /// - Charges gas according to the bridge's EIP-1559 configuration and updates its state
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(outgoing_message_salt: [u8; 32], call: Call)]
 pub struct BridgeCall<'info> {
@@ -62,14 +63,18 @@ pub struct BridgeCall<'info> {
 /// - Validates the call
 /// - Charges gas and updates EIP-1559 state
 /// - Persists the `OutgoingMessage` and increments the nonce
+/// - Emits a `CallBridged` event
 pub fn bridge_call_handler(
     ctx: Context<BridgeCall>,
     _outgoing_message_salt: [u8; 32],
     call: Call,
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
-    bridge_call_internal(
+    // Check if this instruction family is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_BRIDGE_CALL)?;
+    let event = bridge_call_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
@@ -77,7 +82,11 @@ pub fn bridge_call_handler(
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.system_program,
         call,
-    )
+    )?;
+
+    emit_cpi!(event);
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -99,7 +108,8 @@ mod tests {
         instruction::BridgeCall as BridgeCallIx,
         solana_to_base::CallType,
         test_utils::{
-            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_outgoing_message, event_authority_pda, setup_bridge, SetupBridgeResult,
+            TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -140,6 +150,8 @@ mod tests {
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -230,6 +242,8 @@ mod tests {
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -279,7 +293,7 @@ mod tests {
         // Pause the bridge first
         let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
         let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
-        bridge.paused = true;
+        bridge.pause.flags = PAUSE_BRIDGE_CALL;
         let mut new_data = Vec::new();
         bridge.try_serialize(&mut new_data).unwrap();
         bridge_account.data = new_data;
@@ -308,6 +322,8 @@ mod tests {
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
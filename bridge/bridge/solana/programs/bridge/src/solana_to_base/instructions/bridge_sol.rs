@@ -1,10 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, SOL_VAULT_SEED},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_SOL, SOL_VAULT_SEED},
     solana_to_base::{
         internal::bridge_sol::bridge_sol_internal, Call, OutgoingMessage, Transfer,
-        OUTGOING_MESSAGE_SEED,
+        NATIVE_SOL_PUBKEY, OUTGOING_MESSAGE_SEED,
     },
     BridgeError,
 };
@@ -14,6 +14,7 @@ use crate::{
 ///
 /// The bridged SOLs are locked in a vault on Solana and an outgoing message is created to mint
 /// the corresponding tokens and execute the optional call on Base.
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(outgoing_message_salt: [u8; 32], _to: [u8; 20], _amount: u64, call: Option<Call>)]
 pub struct BridgeSol<'info> {
@@ -72,10 +73,17 @@ pub fn bridge_sol_handler(
     amount: u64,
     call: Option<Call>,
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
-
-    bridge_sol_internal(
+    // Check if this instruction family or the bridged mint is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_BRIDGE_SOL)?;
+    ctx.accounts
+        .bridge
+        .pause
+        .require_mint_not_paused(&NATIVE_SOL_PUBKEY)?;
+
+    let event = bridge_sol_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
@@ -86,7 +94,11 @@ pub fn bridge_sol_handler(
         to,
         amount,
         call,
-    )
+    )?;
+
+    emit_cpi!(event);
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -108,7 +120,8 @@ mod tests {
         instruction::BridgeSol as BridgeSolIx,
         solana_to_base::{Call, CallType, NATIVE_SOL_PUBKEY},
         test_utils::{
-            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_outgoing_message, event_authority_pda, setup_bridge, SetupBridgeResult,
+            TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -145,6 +158,8 @@ mod tests {
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -261,6 +276,8 @@ mod tests {
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -354,6 +371,8 @@ mod tests {
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -405,7 +424,7 @@ mod tests {
         // Pause the bridge first
         let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
         let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
-        bridge.paused = true;
+        bridge.pause.flags = PAUSE_BRIDGE_SOL;
         let mut new_data = Vec::new();
         bridge.try_serialize(&mut new_data).unwrap();
         bridge_account.data = new_data;
@@ -434,6 +453,8 @@ mod tests {
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -472,4 +493,89 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_bridge_sol_fails_when_mint_paused() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Pause native SOL transfers only
+        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        bridge
+            .pause
+            .set_mint_paused(NATIVE_SOL_PUBKEY, true)
+            .unwrap();
+        let mut new_data = Vec::new();
+        bridge.try_serialize(&mut new_data).unwrap();
+        bridge_account.data = new_data;
+        svm.set_account(bridge_pda, bridge_account).unwrap();
+
+        // Create from account
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        // Create outgoing message account
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+
+        // Test parameters
+        let to = [1u8; 20];
+        let amount = LAMPORTS_PER_SOL;
+
+        // Find SOL vault PDA
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        // Build the BridgeSol instruction accounts
+        let accounts = accounts::BridgeSol {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            sol_vault,
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
+        }
+        .to_account_metas(None);
+
+        // Build the BridgeSol instruction
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to,
+                amount,
+                call: None,
+            }
+            .data(),
+        };
+
+        // Build the transaction
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        // Send the transaction - should fail
+        let result = svm.send_transaction(tx);
+        assert!(
+            result.is_err(),
+            "Expected transaction to fail when SOL is paused"
+        );
+
+        // Check that the error contains the expected error message
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("MintPaused"),
+            "Expected MintPaused error, got: {}",
+            error_string
+        );
+    }
 }
@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, TOKEN_VAULT_SEED},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_SPL, TOKEN_VAULT_SEED},
     solana_to_base::{
         internal::bridge_spl::bridge_spl_internal, Call, OutgoingMessage, Transfer,
         OUTGOING_MESSAGE_SEED,
@@ -16,6 +16,7 @@ use crate::{
 /// This instruction locks SPL tokens in a vault on Solana and creates an outgoing message
 /// to mint corresponding tokens and execute the optional call on Base. If the token charges
 /// transfer fees, the outgoing message records the net amount actually received by the vault.
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(outgoing_message_salt: [u8; 32], _to: [u8; 20], remote_token: [u8; 20], _amount: u64, call: Option<Call>)]
 pub struct BridgeSpl<'info> {
@@ -99,10 +100,17 @@ pub fn bridge_spl_handler(
     amount: u64,
     call: Option<Call>,
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
-
-    bridge_spl_internal(
+    // Check if this instruction family or the bridged mint is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_BRIDGE_SPL)?;
+    ctx.accounts
+        .bridge
+        .pause
+        .require_mint_not_paused(&ctx.accounts.mint.key())?;
+
+    let event = bridge_spl_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
@@ -117,7 +125,11 @@ pub fn bridge_spl_handler(
         remote_token,
         amount,
         call,
-    )
+    )?;
+
+    emit_cpi!(event);
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -140,8 +152,8 @@ mod tests {
         instruction::BridgeSpl as BridgeSplIx,
         solana_to_base::{Call, CallType},
         test_utils::{
-            create_mock_mint, create_mock_token_account, create_outgoing_message, setup_bridge,
-            SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_mock_mint, create_mock_token_account, create_outgoing_message,
+            event_authority_pda, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -206,6 +218,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -344,6 +358,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -458,6 +474,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -510,7 +528,7 @@ mod tests {
         // Pause the bridge first
         let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
         let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
-        bridge.paused = true;
+        bridge.pause.flags = PAUSE_BRIDGE_SPL;
         let mut new_data = Vec::new();
         bridge.try_serialize(&mut new_data).unwrap();
         bridge_account.data = new_data;
@@ -567,6 +585,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_interface::spl_token_2022::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
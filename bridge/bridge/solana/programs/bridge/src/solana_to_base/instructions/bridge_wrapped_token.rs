@@ -5,7 +5,7 @@ use anchor_spl::{
 };
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_WRAPPED_TOKEN},
     solana_to_base::{
         internal::bridge_wrapped_token::bridge_wrapped_token_internal, Call, OutgoingMessage,
         Transfer, OUTGOING_MESSAGE_SEED,
@@ -18,6 +18,7 @@ use crate::{
 ///
 /// This instruction burns wrapped tokens on Solana and creates an outgoing message to transfer equivalent
 /// tokens and execute the optional call on Base.
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(outgoing_message_salt: [u8; 32], _to: [u8; 20], _amount: u64, call: Option<Call>)]
 pub struct BridgeWrappedToken<'info> {
@@ -82,10 +83,17 @@ pub fn bridge_wrapped_token_handler(
     amount: u64,
     call: Option<Call>,
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
-
-    bridge_wrapped_token_internal(
+    // Check if this instruction family or the bridged mint is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_BRIDGE_WRAPPED_TOKEN)?;
+    ctx.accounts
+        .bridge
+        .pause
+        .require_mint_not_paused(&ctx.accounts.mint.key())?;
+
+    let event = bridge_wrapped_token_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
@@ -98,7 +106,11 @@ pub fn bridge_wrapped_token_handler(
         to,
         amount,
         call,
-    )
+    )?;
+
+    emit_cpi!(event);
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -122,7 +134,7 @@ mod tests {
         solana_to_base::{Call, CallType},
         test_utils::{
             create_mock_token_account, create_mock_wrapped_mint, create_outgoing_message,
-            setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            event_authority_pda, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -181,6 +193,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -306,6 +320,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -413,6 +429,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -464,7 +482,7 @@ mod tests {
         // Pause the bridge first
         let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
         let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
-        bridge.paused = true;
+        bridge.pause.flags = PAUSE_BRIDGE_WRAPPED_TOKEN;
         let mut new_data = Vec::new();
         bridge.try_serialize(&mut new_data).unwrap();
         bridge_account.data = new_data;
@@ -515,6 +533,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_CALL},
     solana_to_base::{
         internal::bridge_call::bridge_call_internal, Call, CallBuffer, OutgoingMessage,
         OUTGOING_MESSAGE_SEED,
@@ -13,6 +13,7 @@ use crate::{
 /// from Solana to Base. This delegates to the same internal logic as `bridge_call`, but reads
 /// the call data from a `CallBuffer` account (which is consumed and closed) instead of from
 /// instruction data.
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(outgoing_message_salt: [u8; 32])]
 pub struct BridgeCallBuffered<'info> {
@@ -77,8 +78,11 @@ pub fn bridge_call_buffered_handler<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, BridgeCallBuffered<'info>>,
     _outgoing_message_salt: [u8; 32],
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    // Check if this instruction family is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_BRIDGE_CALL)?;
 
     let call_buffer = &ctx.accounts.call_buffer;
     let call = Call {
@@ -88,7 +92,7 @@ pub fn bridge_call_buffered_handler<'a, 'b, 'c, 'info>(
         data: call_buffer.data.clone(),
     };
 
-    bridge_call_internal(
+    let event = bridge_call_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
@@ -96,7 +100,11 @@ pub fn bridge_call_buffered_handler<'a, 'b, 'c, 'info>(
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.system_program,
         call,
-    )
+    )?;
+
+    emit_cpi!(event);
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -118,7 +126,8 @@ mod tests {
         instruction::{BridgeCallBuffered as BridgeCallBufferedIx, InitializeCallBuffer},
         solana_to_base::CallType,
         test_utils::{
-            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_outgoing_message, event_authority_pda, setup_bridge, SetupBridgeResult,
+            TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -193,6 +202,8 @@ mod tests {
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -330,6 +341,8 @@ mod tests {
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -432,6 +445,8 @@ mod tests {
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
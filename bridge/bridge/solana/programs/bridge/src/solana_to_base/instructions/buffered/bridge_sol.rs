@@ -1,10 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, SOL_VAULT_SEED},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_SOL, SOL_VAULT_SEED},
     solana_to_base::{
         internal::bridge_sol::bridge_sol_internal, Call, CallBuffer, OutgoingMessage, Transfer,
-        OUTGOING_MESSAGE_SEED,
+        NATIVE_SOL_PUBKEY, OUTGOING_MESSAGE_SEED,
     },
     BridgeError,
 };
@@ -15,6 +15,7 @@ use crate::{
 /// The bridged SOL is locked in a vault on Solana and an outgoing message is created to mint/credit
 /// the corresponding tokens and execute the call on Base. The `CallBuffer` account is consumed and
 /// closed (rent refunded to its `owner`).
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(outgoing_message_salt: [u8; 32], _to: [u8; 20])]
 pub struct BridgeSolWithBufferedCall<'info> {
@@ -85,8 +86,15 @@ pub fn bridge_sol_with_buffered_call_handler<'a, 'b, 'c, 'info>(
     to: [u8; 20],
     amount: u64,
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    // Check if this instruction family or the bridged mint is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_BRIDGE_SOL)?;
+    ctx.accounts
+        .bridge
+        .pause
+        .require_mint_not_paused(&NATIVE_SOL_PUBKEY)?;
 
     let call_buffer = &ctx.accounts.call_buffer;
     let call = Some(Call {
@@ -96,7 +104,7 @@ pub fn bridge_sol_with_buffered_call_handler<'a, 'b, 'c, 'info>(
         data: call_buffer.data.clone(),
     });
 
-    bridge_sol_internal(
+    let event = bridge_sol_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
@@ -107,7 +115,11 @@ pub fn bridge_sol_with_buffered_call_handler<'a, 'b, 'c, 'info>(
         to,
         amount,
         call,
-    )
+    )?;
+
+    emit_cpi!(event);
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -131,7 +143,8 @@ mod tests {
         },
         solana_to_base::{CallType, NATIVE_SOL_PUBKEY},
         test_utils::{
-            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_outgoing_message, event_authority_pda, setup_bridge, SetupBridgeResult,
+            TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -215,6 +228,8 @@ mod tests {
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -388,6 +403,8 @@ mod tests {
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -500,6 +517,8 @@ mod tests {
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
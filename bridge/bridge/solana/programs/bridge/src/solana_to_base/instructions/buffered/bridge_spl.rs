@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, TOKEN_VAULT_SEED},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_SPL, TOKEN_VAULT_SEED},
     solana_to_base::{
         internal::bridge_spl::bridge_spl_internal, Call, CallBuffer, OutgoingMessage, Transfer,
         OUTGOING_MESSAGE_SEED,
@@ -17,6 +17,7 @@ use crate::{
 /// the corresponding tokens and execute the call on Base. If the token charges transfer fees, the
 /// outgoing message records the net amount actually received by the vault. The call buffer account
 /// is closed and rent returned to the owner.
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(outgoing_message_salt: [u8; 32], _to: [u8; 20], remote_token: [u8; 20])]
 pub struct BridgeSplWithBufferedCall<'info> {
@@ -108,8 +109,15 @@ pub fn bridge_spl_with_buffered_call_handler<'a, 'b, 'c, 'info>(
     remote_token: [u8; 20],
     amount: u64,
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    // Check if this instruction family or the bridged mint is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_BRIDGE_SPL)?;
+    ctx.accounts
+        .bridge
+        .pause
+        .require_mint_not_paused(&ctx.accounts.mint.key())?;
 
     let call_buffer = &ctx.accounts.call_buffer;
     let call = Some(Call {
@@ -119,7 +127,7 @@ pub fn bridge_spl_with_buffered_call_handler<'a, 'b, 'c, 'info>(
         data: call_buffer.data.clone(),
     });
 
-    bridge_spl_internal(
+    let event = bridge_spl_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
@@ -134,7 +142,11 @@ pub fn bridge_spl_with_buffered_call_handler<'a, 'b, 'c, 'info>(
         remote_token,
         amount,
         call,
-    )
+    )?;
+
+    emit_cpi!(event);
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -159,8 +171,8 @@ mod tests {
         },
         solana_to_base::CallType,
         test_utils::{
-            create_mock_mint, create_mock_token_account, create_outgoing_message, setup_bridge,
-            SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_mock_mint, create_mock_token_account, create_outgoing_message,
+            event_authority_pda, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -272,6 +284,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -467,6 +481,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -608,6 +624,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
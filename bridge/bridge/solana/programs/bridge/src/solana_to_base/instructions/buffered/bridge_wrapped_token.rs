@@ -5,7 +5,7 @@ use anchor_spl::{
 };
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_WRAPPED_TOKEN},
     solana_to_base::{
         internal::bridge_wrapped_token::bridge_wrapped_token_internal, Call, CallBuffer,
         OutgoingMessage, Transfer, OUTGOING_MESSAGE_SEED,
@@ -18,6 +18,7 @@ use crate::{
 /// The wrapped tokens are burned from the user's token account on Solana and an outgoing
 /// message is created to transfer the equivalent tokens and execute the call on Base. The
 /// call buffer account is consumed (closed) and its rent is returned to the owner.
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(outgoing_message_salt: [u8; 32])]
 pub struct BridgeWrappedTokenWithBufferedCall<'info> {
@@ -95,8 +96,15 @@ pub fn bridge_wrapped_token_with_buffered_call_handler<'a, 'b, 'c, 'info>(
     to: [u8; 20],
     amount: u64,
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    // Check if this instruction family or the bridged mint is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_BRIDGE_WRAPPED_TOKEN)?;
+    ctx.accounts
+        .bridge
+        .pause
+        .require_mint_not_paused(&ctx.accounts.mint.key())?;
 
     let call_buffer = &ctx.accounts.call_buffer;
     let call = Some(Call {
@@ -106,7 +114,7 @@ pub fn bridge_wrapped_token_with_buffered_call_handler<'a, 'b, 'c, 'info>(
         data: call_buffer.data.clone(),
     });
 
-    bridge_wrapped_token_internal(
+    let event = bridge_wrapped_token_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
@@ -119,7 +127,11 @@ pub fn bridge_wrapped_token_with_buffered_call_handler<'a, 'b, 'c, 'info>(
         to,
         amount,
         call,
-    )
+    )?;
+
+    emit_cpi!(event);
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -146,7 +158,7 @@ mod tests {
         solana_to_base::CallType,
         test_utils::{
             create_mock_token_account, create_mock_wrapped_mint, create_outgoing_message,
-            setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            event_authority_pda, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -252,6 +264,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -434,6 +448,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -568,6 +584,8 @@ mod tests {
             outgoing_message,
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            event_authority: event_authority_pda(),
+            program: ID,
         }
         .to_account_metas(None);
 
@@ -15,11 +15,13 @@ use anchor_spl::token_interface::{
 use spl_type_length_value::variable_len_pack::VariableLenPack;
 
 use crate::common::DISCRIMINATOR_LEN;
-use crate::common::{bridge::Bridge, PartialTokenMetadata, BRIDGE_SEED, WRAPPED_TOKEN_SEED};
+use crate::common::{
+    bridge::Bridge, PartialTokenMetadata, BRIDGE_SEED, PAUSE_WRAP_TOKEN, WRAPPED_TOKEN_SEED,
+};
 use crate::solana_to_base::{pay_for_gas, Call, CallType, OutgoingMessage, OUTGOING_MESSAGE_SEED};
 use crate::solana_to_base::{REMOTE_TOKEN_METADATA_KEY, SCALER_EXPONENT_METADATA_KEY};
-use crate::BridgeError;
 use crate::ID;
+use crate::{BridgeError, TokenWrapped};
 
 const REGISTER_REMOTE_TOKEN_DATA_LEN: usize = {
     32 + 32 + 32 // abi.encode(address, bytes32, uint8) = 96 bytes
@@ -29,6 +31,7 @@ const REGISTER_REMOTE_TOKEN_DATA_LEN: usize = {
 /// of a Base token on Solana. This instruction initializes a new SPL token
 /// with Token-2022 extensions and registers it with Base for cross-chain
 /// token transfers. The wrapped token maintains metadata linking it to its Base counterpart.
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(outgoing_message_salt: [u8; 32], decimals: u8, metadata: PartialTokenMetadata)]
 pub struct WrapToken<'info> {
@@ -95,13 +98,17 @@ pub fn wrap_token_handler(
     decimals: u8,
     partial_token_metadata: PartialTokenMetadata,
 ) -> Result<()> {
-    // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    // Check if this instruction family is paused
+    ctx.accounts
+        .bridge
+        .pause
+        .require_not_paused(PAUSE_WRAP_TOKEN)?;
 
     initialize_metadata(&ctx, decimals, &partial_token_metadata)?;
 
     register_remote_token(
         ctx,
+        decimals,
         &partial_token_metadata.remote_token,
         partial_token_metadata.scaler_exponent,
     )?;
@@ -197,9 +204,19 @@ fn initialize_metadata(
 
 fn register_remote_token(
     ctx: Context<WrapToken>,
+    decimals: u8,
     remote_token: &[u8; 20],
     scaler_exponent: u8,
 ) -> Result<()> {
+    let event = TokenWrapped {
+        outgoing_message: ctx.accounts.outgoing_message.key(),
+        nonce: ctx.accounts.bridge.nonce,
+        mint: ctx.accounts.mint.key(),
+        remote_token: *remote_token,
+        decimals,
+        scaler_exponent,
+    };
+
     let address = Address::from(remote_token);
     let local_token = FixedBytes::from(ctx.accounts.mint.key().to_bytes());
     let scaler_exponent = U256::from(scaler_exponent);
@@ -223,6 +240,8 @@ fn register_remote_token(
     *ctx.accounts.outgoing_message = message;
     ctx.accounts.bridge.nonce += 1;
 
+    emit_cpi!(event);
+
     Ok(())
 }
 
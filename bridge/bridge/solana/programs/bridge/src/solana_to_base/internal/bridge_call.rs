@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use crate::{
     common::bridge::Bridge,
     solana_to_base::{check_call, pay_for_gas, Call, OutgoingMessage},
+    CallBridged,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -14,9 +15,18 @@ pub fn bridge_call_internal<'info>(
     outgoing_message: &mut Account<'info, OutgoingMessage>,
     system_program: &Program<'info, System>,
     call: Call,
-) -> Result<()> {
+) -> Result<CallBridged> {
     check_call(&call)?;
 
+    let event = CallBridged {
+        outgoing_message: outgoing_message.key(),
+        nonce: bridge.nonce,
+        sender: from.key(),
+        to: call.to,
+        value: call.value,
+        data_len: call.data.len() as u64,
+    };
+
     let message = OutgoingMessage::new_call(bridge.nonce, from.key(), call);
 
     pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
@@ -24,5 +34,5 @@ pub fn bridge_call_internal<'info>(
     **outgoing_message = message;
     bridge.nonce += 1;
 
-    Ok(())
+    Ok(event)
 }
@@ -6,8 +6,10 @@ use anchor_lang::{
 use crate::{
     common::bridge::Bridge,
     solana_to_base::{
-        check_call, pay_for_gas, Call, OutgoingMessage, Transfer as TransferOp, NATIVE_SOL_PUBKEY,
+        check_call, internal::record_outgoing_transfer, Call, OutgoingMessage,
+        Transfer as TransferOp, NATIVE_SOL_PUBKEY,
     },
+    SolBridged,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -22,24 +24,29 @@ pub fn bridge_sol_internal<'info>(
     to: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+) -> Result<SolBridged> {
     if let Some(call) = &call {
         check_call(call)?;
     }
 
-    let message = OutgoingMessage::new_transfer(
-        bridge.nonce,
+    let has_call = call.is_some();
+
+    let remote_token = bridge.protocol_config.remote_sol_address;
+    let nonce = record_outgoing_transfer(
+        payer,
+        gas_fee_receiver,
+        bridge,
+        outgoing_message,
+        system_program,
         from.key(),
         TransferOp {
             to,
             local_token: NATIVE_SOL_PUBKEY,
-            remote_token: bridge.protocol_config.remote_sol_address,
+            remote_token,
             amount,
             call,
         },
-    );
-
-    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
+    )?;
 
     // Lock the sol from the user into the SOL vault.
     let cpi_ctx = CpiContext::new(
@@ -51,8 +58,12 @@ pub fn bridge_sol_internal<'info>(
     );
     system_program::transfer(cpi_ctx, amount)?;
 
-    **outgoing_message = message;
-    bridge.nonce += 1;
-
-    Ok(())
+    Ok(SolBridged {
+        outgoing_message: outgoing_message.key(),
+        nonce,
+        sender: from.key(),
+        to,
+        amount,
+        has_call,
+    })
 }
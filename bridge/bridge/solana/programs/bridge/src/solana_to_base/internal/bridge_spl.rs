@@ -5,8 +5,11 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::common::PartialTokenMetadata;
 use crate::{
     common::bridge::Bridge,
-    solana_to_base::{check_call, pay_for_gas, Call, OutgoingMessage, Transfer as TransferOp},
-    BridgeError,
+    solana_to_base::{
+        check_call, internal::record_outgoing_transfer, Call, OutgoingMessage,
+        Transfer as TransferOp,
+    },
+    BridgeError, SplBridged,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -25,11 +28,13 @@ pub fn bridge_spl_internal<'info>(
     remote_token: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+) -> Result<SplBridged> {
     if let Some(call) = &call {
         check_call(call)?;
     }
 
+    let has_call = call.is_some();
+
     // Check that the provided mint is not a wrapped token.
     // Wrapped tokens should be handled by the wrapped_token_transfer_operation branch which burns the token from the user.
     require!(
@@ -59,8 +64,12 @@ pub fn bridge_spl_internal<'info>(
     // Compute the real received amount in case the token has transfer fees.
     let received_amount = token_vault_balance_after - token_vault_balance;
 
-    let message = OutgoingMessage::new_transfer(
-        bridge.nonce,
+    let nonce = record_outgoing_transfer(
+        payer,
+        gas_fee_receiver,
+        bridge,
+        outgoing_message,
+        system_program,
         from.key(),
         TransferOp {
             to,
@@ -69,12 +78,16 @@ pub fn bridge_spl_internal<'info>(
             amount: received_amount,
             call,
         },
-    );
-
-    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
-
-    **outgoing_message = message;
-    bridge.nonce += 1;
+    )?;
 
-    Ok(())
+    Ok(SplBridged {
+        outgoing_message: outgoing_message.key(),
+        nonce,
+        sender: from.key(),
+        mint: mint.key(),
+        remote_token,
+        to,
+        amount: received_amount,
+        has_call,
+    })
 }
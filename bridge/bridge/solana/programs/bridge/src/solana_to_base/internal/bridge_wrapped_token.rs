@@ -4,10 +4,11 @@ use anchor_spl::{
     token_interface::{self, BurnChecked, Mint, TokenAccount},
 };
 
-use crate::solana_to_base::{check_call, pay_for_gas};
+use crate::solana_to_base::{check_call, internal::record_outgoing_transfer};
 use crate::{
     common::{bridge::Bridge, PartialTokenMetadata},
     solana_to_base::{Call, OutgoingMessage, Transfer as TransferOp},
+    WrappedTokenBridged,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -24,16 +25,22 @@ pub fn bridge_wrapped_token_internal<'info>(
     to: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+) -> Result<WrappedTokenBridged> {
     if let Some(call) = &call {
         check_call(call)?;
     }
 
+    let has_call = call.is_some();
+
     // Get the token metadata from the mint.
     let partial_token_metadata = PartialTokenMetadata::try_from(&mint.to_account_info())?;
 
-    let message = OutgoingMessage::new_transfer(
-        bridge.nonce,
+    let nonce = record_outgoing_transfer(
+        payer,
+        gas_fee_receiver,
+        bridge,
+        outgoing_message,
+        system_program,
         from.key(),
         TransferOp {
             to,
@@ -42,9 +49,7 @@ pub fn bridge_wrapped_token_internal<'info>(
             amount,
             call,
         },
-    );
-
-    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
+    )?;
 
     // Burn the token from the user.
     let cpi_ctx = CpiContext::new(
@@ -57,8 +62,14 @@ pub fn bridge_wrapped_token_internal<'info>(
     );
     token_interface::burn_checked(cpi_ctx, amount, mint.decimals)?;
 
-    **outgoing_message = message;
-    bridge.nonce += 1;
-
-    Ok(())
+    Ok(WrappedTokenBridged {
+        outgoing_message: outgoing_message.key(),
+        nonce,
+        sender: from.key(),
+        mint: mint.key(),
+        remote_token: partial_token_metadata.remote_token,
+        to,
+        amount,
+        has_call,
+    })
 }
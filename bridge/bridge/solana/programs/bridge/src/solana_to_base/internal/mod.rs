@@ -1,4 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::bridge::Bridge,
+    solana_to_base::{pay_for_gas, OutgoingMessage, Transfer},
+};
+
 pub mod bridge_call;
 pub mod bridge_sol;
 pub mod bridge_spl;
 pub mod bridge_wrapped_token;
+
+/// Stores an outgoing transfer message under the next bridge nonce and charges the Base gas fee
+/// for relaying it. Returns the nonce assigned to the message.
+pub(crate) fn record_outgoing_transfer<'info>(
+    payer: &Signer<'info>,
+    gas_fee_receiver: &AccountInfo<'info>,
+    bridge: &mut Account<'info, Bridge>,
+    outgoing_message: &mut Account<'info, OutgoingMessage>,
+    system_program: &Program<'info, System>,
+    sender: Pubkey,
+    transfer: Transfer,
+) -> Result<u64> {
+    let nonce = bridge.nonce;
+
+    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
+
+    **outgoing_message = OutgoingMessage::new_transfer(nonce, sender, transfer);
+    bridge.nonce += 1;
+
+    Ok(nonce)
+}
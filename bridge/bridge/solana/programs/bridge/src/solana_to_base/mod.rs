@@ -1,4 +1,4 @@
-mod internal;
+pub(crate) mod internal;
 
 pub mod constants;
 pub mod instructions;
@@ -1,7 +1,8 @@
 use anchor_lang::{
     prelude::*,
     solana_program::{
-        bpf_loader_upgradeable, instruction::Instruction, native_token::LAMPORTS_PER_SOL,
+        bpf_loader_upgradeable, ed25519_program, instruction::Instruction,
+        native_token::LAMPORTS_PER_SOL,
     },
     system_program, InstructionData,
 };
@@ -22,7 +23,10 @@ use anchor_spl::{
     },
 };
 use hex_literal::hex;
-use litesvm::LiteSVM;
+use litesvm::{
+    types::{FailedTransactionMetadata, TransactionMetadata},
+    LiteSVM,
+};
 use solana_account::Account;
 use solana_keypair::Keypair;
 use solana_loader_v3_interface::state::UpgradeableLoaderState;
@@ -32,11 +36,18 @@ use solana_transaction::Transaction;
 
 use crate::{
     accounts,
-    base_to_solana::signers::PartnerSigner,
+    base_to_solana::{
+        constants::{BASE_ORACLE_SIGNERS_SEED, CPI_POLICY_SEED},
+        internal::mmr::{commutative_keccak256, ordered_keccak256},
+        signers::PartnerSigner,
+        state::{BaseOracleSigner, BaseOracleSigners, OracleSignerKey, OutputRoot},
+    },
     common::{
-        bridge::{BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig},
-        BaseOracleConfig, Config, PartialTokenMetadata, BRIDGE_SEED, MAX_SIGNER_COUNT,
-        WRAPPED_TOKEN_SEED,
+        bridge::{
+            AttestationConfig, BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig,
+            ProtocolConfig, Roles,
+        },
+        Config, PartialTokenMetadata, BRIDGE_SEED, WRAPPED_TOKEN_SEED,
     },
     instruction::Initialize,
     solana_to_base::OUTGOING_MESSAGE_SEED,
@@ -71,6 +82,11 @@ impl ProtocolConfig {
         Self {
             block_interval_requirement: 300,
             remote_sol_address: hex!("C5b9112382f3c87AFE8e1A28fa52452aF81085AD"),
+            config_change_delay_seconds: 0,
+            output_root_retention_blocks: 0,
+            root_finality_seconds: 0,
+            attestation: AttestationConfig::default(),
+            bounce_delay_seconds: 0,
         }
     }
 }
@@ -83,15 +99,17 @@ impl BufferConfig {
     }
 }
 
-impl BaseOracleConfig {
+impl BaseOracleSigners {
     pub fn test_new() -> Self {
-        let mut signer_addrs = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        signer_addrs[0] = [1u8; 20];
-
         Self {
+            epoch: 0,
+            activation_block_number: 0,
+            expiry_block_number: u64::MAX,
             threshold: 1,
-            signer_count: 1,
-            signers: signer_addrs,
+            signers: vec![BaseOracleSigner {
+                key: OracleSignerKey::Evm([1u8; 20]),
+                weight: 1,
+            }],
         }
     }
 }
@@ -235,6 +253,7 @@ pub fn setup_bridge() -> SetupBridgeResult {
         upgrade_authority: payer_pk,
         payer: payer_pk,
         bridge: bridge_pda,
+        base_oracle_signers: base_oracle_signers_pda(0),
         program_data: program_data_pda,
         program: ID,
         system_program: system_program::ID,
@@ -247,12 +266,17 @@ pub fn setup_bridge() -> SetupBridgeResult {
         data: Initialize {
             guardian: guardian_pk,
             cfg: Config {
+                // The guardian doubles as pauser and the upgrade authority as signer manager
+                roles: Roles {
+                    pauser: guardian_pk,
+                    signer_manager: payer_pk,
+                },
                 eip1559_config: Eip1559Config::test_new(),
                 gas_config: GasConfig::test_new(TEST_GAS_FEE_RECEIVER),
                 protocol_config: ProtocolConfig::test_new(),
                 buffer_config: BufferConfig::test_new(),
                 partner_oracle_config: PartnerOracleConfig::default(),
-                base_oracle_config: BaseOracleConfig::test_new(),
+                base_oracle_signers: BaseOracleSigners::test_new(),
             },
         }
         .data(),
@@ -274,6 +298,51 @@ pub fn setup_bridge() -> SetupBridgeResult {
     }
 }
 
+/// Sends `ix` in a fresh transaction paid and signed by `signer`.
+pub fn send(
+    svm: &mut LiteSVM,
+    signer: &Keypair,
+    ix: Instruction,
+) -> std::result::Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+    svm.expire_blockhash();
+    let tx = Transaction::new(
+        &[signer],
+        Message::new(&[ix], Some(&signer.pubkey())),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).map_err(Box::new)
+}
+
+pub fn assert_error<T: std::fmt::Debug>(
+    result: std::result::Result<T, impl std::fmt::Debug>,
+    expected: &str,
+) {
+    let error_string = format!("{:?}", result.unwrap_err());
+    assert!(
+        error_string.contains(expected),
+        "Expected {} error, got: {}",
+        expected,
+        error_string
+    );
+}
+
+/// Writes `output_root` directly into the account at `address`.
+pub fn write_output_root(svm: &mut LiteSVM, address: Pubkey, output_root: &OutputRoot) {
+    let mut data = Vec::new();
+    output_root.try_serialize(&mut data).unwrap();
+    svm.set_account(
+        address,
+        Account {
+            lamports: 1_000_000,
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
 pub fn create_outgoing_message() -> ([u8; 32], Pubkey) {
     let outgoing_message_salt = [42u8; 32];
     (
@@ -286,6 +355,102 @@ pub fn create_outgoing_message() -> ([u8; 32], Pubkey) {
     )
 }
 
+/// Returns the PDA of the Base oracle signer set for `epoch`.
+pub fn base_oracle_signers_pda(epoch: u64) -> Pubkey {
+    Pubkey::find_program_address(&[BASE_ORACLE_SIGNERS_SEED, &epoch.to_le_bytes()], &ID).0
+}
+
+/// Returns the PDA of the guardian-managed CPI policy.
+pub fn cpi_policy_pda() -> Pubkey {
+    Pubkey::find_program_address(&[CPI_POLICY_SEED], &ID).0
+}
+
+/// Overwrites the first Base oracle signer epoch with `signers`, each weighted 1.
+pub fn set_base_oracle_signers(svm: &mut LiteSVM, threshold: u16, signers: &[[u8; 20]]) {
+    set_base_oracle_epoch(
+        svm,
+        &BaseOracleSigners {
+            threshold,
+            signers: signers
+                .iter()
+                .map(|evm_address| BaseOracleSigner {
+                    key: OracleSignerKey::Evm(*evm_address),
+                    weight: 1,
+                })
+                .collect(),
+            ..BaseOracleSigners::test_new()
+        },
+    );
+}
+
+/// Writes `registry` to the PDA of its epoch, creating the account if needed.
+pub fn set_base_oracle_epoch(svm: &mut LiteSVM, registry: &BaseOracleSigners) {
+    let mut data = Vec::new();
+    registry.try_serialize(&mut data).unwrap();
+
+    svm.set_account(
+        base_oracle_signers_pda(registry.epoch),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+/// Builds an Ed25519 program instruction verifying `signer`'s signature over `message`, with
+/// the public key, signature and message all inlined in the instruction data.
+pub fn ed25519_verify_ix(signer: &Keypair, message: &[u8]) -> Instruction {
+    const HEADER_LEN: u16 = 2 + 14;
+    let public_key_offset = HEADER_LEN;
+    let signature_offset = public_key_offset + 32;
+    let message_offset = signature_offset + 64;
+
+    let mut data = vec![1u8, 0];
+    for field in [
+        signature_offset,
+        u16::MAX,
+        public_key_offset,
+        u16::MAX,
+        message_offset,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(signer.pubkey().as_ref());
+    data.extend_from_slice(signer.sign_message(message).as_ref());
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Returns the PDA that signs the self-CPI used to log Anchor events.
+pub fn event_authority_pda() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &ID).0
+}
+
+/// Decodes the first event of type `T` logged through the event self-CPI in a transaction.
+pub fn find_cpi_event<T: anchor_lang::Event + AnchorDeserialize>(
+    meta: &TransactionMetadata,
+) -> Option<T> {
+    meta.inner_instructions.iter().flatten().find_map(|ix| {
+        let data = ix
+            .instruction
+            .data
+            .strip_prefix(anchor_lang::event::EVENT_IX_TAG_LE)?
+            .strip_prefix(T::DISCRIMINATOR)?;
+        T::try_from_slice(data).ok()
+    })
+}
+
 pub fn mock_clock(svm: &mut LiteSVM, timestamp: i64) {
     let mut clock = svm.get_sysvar::<Clock>();
     clock.unix_timestamp = timestamp;
@@ -416,3 +581,97 @@ pub fn create_mock_wrapped_mint(
 
     wrapped_mint
 }
+
+/// Returns the levels of each MMR mountain over `leaves`, from left to right and leaves first.
+fn mmr_mountains(leaves: &[[u8; 32]]) -> Vec<Vec<Vec<[u8; 32]>>> {
+    let mut mountains = Vec::new();
+    let mut offset = 0;
+    for height in (0..u64::BITS).rev() {
+        let size = 1usize << height;
+        if (leaves.len() >> height) & 1 == 0 {
+            continue;
+        }
+
+        let mut levels = vec![leaves[offset..offset + size].to_vec()];
+        while levels.last().unwrap().len() > 1 {
+            let parents = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| commutative_keccak256(pair[0], pair[1]))
+                .collect();
+            levels.push(parents);
+        }
+        mountains.push(levels);
+        offset += size;
+    }
+    mountains
+}
+
+/// Computes the root of the MMR over `leaves`.
+pub fn mmr_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    mmr_mountains(leaves)
+        .iter()
+        .map(|levels| levels.last().unwrap()[0])
+        .reduce(ordered_keccak256)
+        .unwrap_or([0u8; 32])
+}
+
+/// Builds the single-leaf proof of the leaf at `index`, in the layout expected by
+/// `mmr::verify_proof`: siblings up to its mountain's peak, then the other peaks left to right.
+pub fn mmr_proof(leaves: &[[u8; 32]], index: u64) -> Vec<[u8; 32]> {
+    let mut siblings = Vec::new();
+    let mut peaks = Vec::new();
+    let mut offset = 0;
+    for levels in mmr_mountains(leaves) {
+        let size = levels[0].len();
+        if (offset..offset + size).contains(&(index as usize)) {
+            let mut position = index as usize - offset;
+            for level in &levels[..levels.len() - 1] {
+                siblings.push(level[position ^ 1]);
+                position >>= 1;
+            }
+        } else {
+            peaks.push(levels.last().unwrap()[0]);
+        }
+        offset += size;
+    }
+    siblings.extend(peaks);
+    siblings
+}
+
+/// Builds the multiproof of the leaves at the sorted `indices`, in the layout expected by
+/// `mmr::verify_multiproof`.
+pub fn mmr_multiproof(leaves: &[[u8; 32]], indices: &[u64]) -> Vec<[u8; 32]> {
+    let mut proof = Vec::new();
+    let mut offset = 0;
+    for levels in mmr_mountains(leaves) {
+        let size = levels[0].len();
+        let mut known: Vec<usize> = indices
+            .iter()
+            .map(|i| *i as usize)
+            .filter(|i| (offset..offset + size).contains(i))
+            .map(|i| i - offset)
+            .collect();
+
+        if known.is_empty() {
+            proof.push(levels.last().unwrap()[0]);
+        } else {
+            for level in &levels[..levels.len() - 1] {
+                let mut i = 0;
+                while i < known.len() {
+                    if known.get(i + 1) == Some(&(known[i] ^ 1)) {
+                        i += 1;
+                    } else {
+                        proof.push(level[known[i] ^ 1]);
+                    }
+                    i += 1;
+                }
+                known = known.iter().map(|position| position >> 1).collect();
+                known.dedup();
+            }
+        }
+        offset += size;
+    }
+    proof
+}